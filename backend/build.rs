@@ -6,6 +6,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .out_dir("src/protogen")
         .file_descriptor_set_path("src/protogen/descriptor.bin") 
         .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
+        // schemas for the HTTP API's OpenAPI document
+        .type_attribute(".", "#[derive(utoipa::ToSchema)]")
+        // let JSON bodies omit fields, matching proto3 default semantics
+        .message_attribute(".", "#[serde(default)]")
         .extern_path(".google.protobuf.Timestamp", "crate::types::SerdeTimestamp")
         // .field_attribute(
         //     ".google.protobuf.Timestamp",
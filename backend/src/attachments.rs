@@ -0,0 +1,181 @@
+// src/attachments.rs
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::config::Config;
+
+/// How old an unreferenced file must be before `remove_orphans` deletes it:
+/// an upload writes its file before the task records it
+const ORPHAN_GRACE: Duration = Duration::from_secs(3600);
+
+/// Where attachment bytes live on disk and the URL prefix they're served
+/// under, which may point at a CDN rather than this server.
+#[derive(Debug, Clone)]
+pub struct AttachmentStore {
+    dir: PathBuf,
+    url_base: String,
+    orphan_grace: Duration,
+}
+
+impl AttachmentStore {
+    pub fn new(dir: impl Into<PathBuf>, url_base: &str) -> Self {
+        Self {
+            dir: dir.into(),
+            url_base: url_base.trim_end_matches('/').to_string(),
+            orphan_grace: ORPHAN_GRACE,
+        }
+    }
+
+    /// Override how long unreferenced files are spared.
+    pub fn with_orphan_grace(mut self, grace: Duration) -> Self {
+        self.orphan_grace = grace;
+        self
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(&config.attachments_dir, &config.attachments_url_base)
+    }
+
+    /// Attachment ids are server-generated UUIDs, so they're safe as file names.
+    pub fn path(&self, attachment_id: &str) -> PathBuf {
+        self.dir.join(attachment_id)
+    }
+
+    pub fn url(&self, attachment_id: &str) -> String {
+        format!("{}/{}", self.url_base, attachment_id)
+    }
+
+    /// Read `len` bytes starting at `start`.
+    pub async fn read_range(&self, attachment_id: &str, start: u64, len: u64) -> Result<Vec<u8>> {
+        let mut file = fs::File::open(self.path(attachment_id)).await
+            .context("Failed to open attachment")?;
+        file.seek(std::io::SeekFrom::Start(start)).await?;
+        let mut bytes = vec![0; len as usize];
+        file.read_exact(&mut bytes).await
+            .context("Failed to read attachment")?;
+        Ok(bytes)
+    }
+
+    pub async fn file_len(&self, attachment_id: &str) -> Result<u64> {
+        Ok(fs::metadata(self.path(attachment_id)).await?.len())
+    }
+
+    pub async fn save(&self, attachment_id: &str, bytes: &[u8]) -> Result<()> {
+        fs::create_dir_all(&self.dir).await
+            .context("Failed to create attachments directory")?;
+        fs::write(self.path(attachment_id), bytes).await
+            .context("Failed to write attachment")
+    }
+
+    /// Delete stored files whose id isn't in `referenced`, sparing recent
+    /// ones. Returns how many files went and how many bytes they held.
+    pub async fn remove_orphans(&self, referenced: &HashSet<String>) -> Result<(usize, u64)> {
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((0, 0)),
+            Err(e) => return Err(e).context("Failed to list attachments directory"),
+        };
+        let (mut removed, mut bytes) = (0, 0);
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name();
+            if name.to_str().is_some_and(|id| referenced.contains(id)) {
+                continue;
+            }
+            let meta = entry.metadata().await?;
+            let age = meta.modified().ok().and_then(|modified| modified.elapsed().ok()).unwrap_or_default();
+            if !meta.is_file() || age < self.orphan_grace {
+                continue;
+            }
+            fs::remove_file(entry.path()).await
+                .with_context(|| format!("Failed to remove orphaned attachment {}", entry.path().display()))?;
+            removed += 1;
+            bytes += meta.len();
+        }
+        Ok((removed, bytes))
+    }
+}
+
+/// What part of a file a request's `Range` header asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    Full,
+    /// Inclusive start and end offsets
+    Partial(u64, u64),
+    Unsatisfiable,
+}
+
+/// Interpret a `Range` header against a file of `len` bytes. Only single
+/// byte ranges are honoured; anything else is answered with the whole file,
+/// which the spec permits.
+pub fn parse_range(header: Option<&str>, len: u64) -> ByteRange {
+    let Some(spec) = header.and_then(|h| h.trim().strip_prefix("bytes=")) else {
+        return ByteRange::Full;
+    };
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+    let Some((start, end)) = spec.split_once('-') else {
+        return ByteRange::Full;
+    };
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        // Suffix form: the last N bytes
+        ("", suffix) => match suffix.parse::<u64>() {
+            Ok(0) => return ByteRange::Unsatisfiable,
+            Ok(n) => (len.saturating_sub(n), len.saturating_sub(1)),
+            Err(_) => return ByteRange::Full,
+        },
+        (start, "") => match start.parse::<u64>() {
+            Ok(start) => (start, len.saturating_sub(1)),
+            Err(_) => return ByteRange::Full,
+        },
+        (start, end) => match (start.parse::<u64>(), end.parse::<u64>()) {
+            (Ok(start), Ok(end)) if start <= end => (start, end.min(len.saturating_sub(1))),
+            _ => return ByteRange::Full,
+        },
+    };
+
+    if len == 0 || start >= len {
+        ByteRange::Unsatisfiable
+    } else {
+        ByteRange::Partial(start, end)
+    }
+}
+
+/// `Content-Disposition` value that downloads under the original name. The
+/// quoted name is an ASCII fallback; `filename*` carries the exact UTF-8 name.
+pub fn content_disposition(filename: &str) -> String {
+    let fallback: String = filename.chars()
+        .map(|c| if (c.is_ascii_graphic() || c == ' ') && c != '"' && c != '\\' { c } else { '_' })
+        .collect();
+    let encoded: String = filename.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    format!("attachment; filename=\"{}\"; filename*=UTF-8''{}", fallback, encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn files_live_in_the_configured_dir_and_urls_use_the_configured_base() {
+        let dir = std::env::temp_dir().join(format!("tasker-attachments-{}", uuid::Uuid::new_v4()));
+        let store = AttachmentStore::new(&dir, "https://cdn.example.com/files/");
+        assert_eq!(store.url("a1"), "https://cdn.example.com/files/a1");
+        assert_eq!(store.path("a1"), dir.join("a1"));
+
+        store.save("a1", b"hello world").await.unwrap();
+        assert_eq!(std::fs::read(dir.join("a1")).unwrap(), b"hello world");
+        assert_eq!(store.file_len("a1").await.unwrap(), 11);
+        assert_eq!(store.read_range("a1", 6, 5).await.unwrap(), b"world");
+    }
+}
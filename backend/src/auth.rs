@@ -0,0 +1,107 @@
+// src/auth.rs
+use tonic::metadata::MetadataMap;
+
+use crate::protogen::{User, UserRole};
+use crate::storage::Storage;
+
+/// Extract the token from an `Authorization: Bearer <token>` header value.
+pub fn bearer_token(header: &str) -> Option<&str> {
+    header.strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// Resolve an access token to its active user, rejecting expired sessions and inactive accounts.
+pub async fn authenticate(storage: &Storage, token: &str) -> Option<User> {
+    resolve_session(storage, token, false).await
+}
+
+/// Resolve a refresh token to its active user.
+pub async fn authenticate_refresh(storage: &Storage, token: &str) -> Option<User> {
+    resolve_session(storage, token, true).await
+}
+
+async fn resolve_session(storage: &Storage, token: &str, refresh: bool) -> Option<User> {
+    let session = storage.get_session(token).await
+        .filter(|session| session.refresh == refresh)?;
+
+    storage.get_user(&session.user_id).await
+        .filter(|user| user.is_active)
+}
+
+/// Resolve the caller of a gRPC request from its `authorization` metadata.
+pub async fn user_from_metadata(storage: &Storage, metadata: &MetadataMap) -> Option<User> {
+    let header = metadata.get("authorization")?.to_str().ok()?;
+    authenticate(storage, bearer_token(header)?).await
+}
+
+pub fn is_admin(user: &User) -> bool {
+    user.role == UserRole::Admin as i32
+}
+
+/// Complexity rules new passwords must meet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_mixed_case: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 10,
+            require_mixed_case: true,
+            require_digit: true,
+            require_symbol: false,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Check `password` against each rule, describing the first one it breaks.
+    pub fn check(&self, password: &str) -> Result<(), String> {
+        if password.chars().count() < self.min_length {
+            return Err(format!("Password must be at least {} characters long", self.min_length));
+        }
+        if self.require_mixed_case
+            && !(password.chars().any(char::is_lowercase) && password.chars().any(char::is_uppercase))
+        {
+            return Err("Password must contain both upper- and lowercase letters".to_string());
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            return Err("Password must contain a digit".to_string());
+        }
+        if self.require_symbol && !password.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            return Err("Password must contain a symbol".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+    use crate::config::Config;
+    use crate::storage::Session;
+
+    #[tokio::test]
+    async fn tokens_just_past_expiry_are_accepted_within_the_leeway() {
+        let config = Config { token_leeway_secs: 60, storage_auto_save: false, ..Default::default() };
+        let storage = Storage::from_config(&config).unwrap();
+        let user = User { id: "ann".to_string(), username: "ann".to_string(), is_active: true, ..Default::default() };
+        storage.create_user(user).await.unwrap();
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        for (token, expired_for) in [("recent", 30), ("stale", 120)] {
+            let session = Session { user_id: "ann".to_string(), expires_at: now - expired_for, refresh: false };
+            storage.create_session(token, session).await.unwrap();
+        }
+
+        assert_eq!(authenticate(&storage, "recent").await.map(|user| user.id).as_deref(), Some("ann"));
+        assert!(authenticate(&storage, "stale").await.is_none());
+    }
+}
@@ -0,0 +1,130 @@
+// src/calendar.rs
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, NaiveDate, Weekday};
+
+use crate::config::Config;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// Days a team works, minus holidays
+#[derive(Debug, Clone)]
+pub struct WorkingCalendar {
+    working_days: Vec<Weekday>,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl WorkingCalendar {
+    pub fn new(working_days: &[Weekday], holidays: &[NaiveDate]) -> Self {
+        Self {
+            working_days: working_days.to_vec(),
+            holidays: holidays.iter().copied().collect(),
+        }
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.working_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Working days after `from` up to and including `to`, so a Friday to
+    /// Monday span is one working day when weekends are off. Counting stops
+    /// once it passes `limit`, which keeps far-off dates cheap.
+    pub fn working_days_between(&self, from: NaiveDate, to: NaiveDate, limit: i64) -> i64 {
+        let mut count = 0;
+        let mut day = from;
+        while day < to && count <= limit {
+            let Some(next) = day.succ_opt() else {
+                break;
+            };
+            day = next;
+            if self.is_working_day(day) {
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+/// Decides whether an open task is "due soon": within a number of days of
+/// its due date, counted either as raw 24-hour periods or, with a calendar,
+/// as working days. Dates are taken in UTC.
+#[derive(Debug, Clone)]
+pub struct DueSoon {
+    days: i64,
+    calendar: Option<WorkingCalendar>,
+}
+
+impl Default for DueSoon {
+    fn default() -> Self {
+        Self { days: 2, calendar: None }
+    }
+}
+
+impl DueSoon {
+    pub fn new(days: i64, calendar: Option<WorkingCalendar>) -> Self {
+        Self { days, calendar }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        let calendar = config.due_soon_working_days_only
+            .then(|| WorkingCalendar::new(&config.working_days, &config.holidays));
+        Self::new(config.due_soon_days, calendar)
+    }
+
+    /// Whether something due at `due_secs` is due soon as of `now_secs`;
+    /// anything already past due is overdue instead
+    pub fn is_due_soon(&self, due_secs: i64, now_secs: i64) -> bool {
+        if due_secs < now_secs {
+            return false;
+        }
+        let Some(calendar) = &self.calendar else {
+            return due_secs - now_secs <= self.days * SECONDS_PER_DAY;
+        };
+        match (utc_date(now_secs), utc_date(due_secs)) {
+            (Some(today), Some(due)) => calendar.working_days_between(today, due, self.days) <= self.days,
+            _ => false,
+        }
+    }
+}
+
+fn utc_date(seconds: i64) -> Option<NaiveDate> {
+    DateTime::from_timestamp(seconds, 0).map(|at| at.date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEEKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+    fn date(text: &str) -> NaiveDate {
+        text.parse().unwrap()
+    }
+
+    fn noon(text: &str) -> i64 {
+        date(text).and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp()
+    }
+
+    #[test]
+    fn friday_to_monday_is_one_working_day() {
+        let calendar = WorkingCalendar::new(&WEEKDAYS, &[]);
+        // 2024-03-08 is a Friday
+        assert_eq!(calendar.working_days_between(date("2024-03-08"), date("2024-03-11"), 10), 1);
+        assert_eq!(calendar.working_days_between(date("2024-03-08"), date("2024-03-15"), 10), 5);
+
+        let with_holiday = WorkingCalendar::new(&WEEKDAYS, &[date("2024-03-11")]);
+        assert_eq!(with_holiday.working_days_between(date("2024-03-08"), date("2024-03-12"), 10), 1);
+    }
+
+    #[test]
+    fn due_soon_skips_the_weekend_only_with_a_calendar() {
+        let friday = noon("2024-03-08");
+        let tuesday = noon("2024-03-12");
+
+        // Four calendar days away, but only two working days
+        assert!(!DueSoon::new(2, None).is_due_soon(tuesday, friday));
+        assert!(DueSoon::new(2, Some(WorkingCalendar::new(&WEEKDAYS, &[]))).is_due_soon(tuesday, friday));
+        assert!(!DueSoon::new(1, Some(WorkingCalendar::new(&WEEKDAYS, &[]))).is_due_soon(tuesday, friday));
+        assert!(!DueSoon::default().is_due_soon(friday, tuesday));
+    }
+}
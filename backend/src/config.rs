@@ -0,0 +1,683 @@
+// src/config.rs
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use axum::http::{HeaderName, HeaderValue};
+use chrono::{NaiveDate, Weekday};
+
+use crate::auth::PasswordPolicy;
+use crate::encryption::{EncryptedField, FieldCipher};
+use crate::error::TaskerError;
+use crate::ids::IdFormat;
+use crate::moderation::CommentUrlPolicy;
+use crate::protogen::{SortDirection, TaskPriority, TaskSort, TaskSortField, TaskStatus};
+use crate::storage::StorageFormat;
+use crate::types::timestamp::TimestampFormat;
+use crate::unknown_method;
+use crate::validation;
+use crate::workflow;
+
+/// Runtime configuration, read from `TASKER_*` environment variables with
+/// sensible defaults for anything unset.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Storage file location; `None` keeps everything in memory
+    pub storage_path: Option<String>,
+    /// Save after every mutation; requires `storage_path`
+    pub storage_auto_save: bool,
+    /// Reload the storage file when another process changes it
+    pub storage_watch: bool,
+    /// Start with writes rejected; admins can toggle this at runtime
+    pub read_only: bool,
+    /// Explicit storage encoding; `None` infers it from the path extension
+    pub storage_format: Option<StorageFormat>,
+    /// Directory uploaded attachment files are written to
+    pub attachments_dir: String,
+    /// Prefix for attachment URLs handed to clients, e.g. a CDN origin
+    pub attachments_url_base: String,
+    /// Attachment uploads accepted at once; 0 = unlimited
+    pub max_concurrent_uploads: usize,
+    /// How long an upload beyond the limit waits for a slot before it's
+    /// refused; 0 refuses it straight away
+    pub upload_wait_secs: u64,
+    /// Assign tasks created without an assignee to the signed-in user creating them
+    pub auto_assign_to_creator: bool,
+    /// Mark a task Done once every one of its subtasks is Done
+    pub auto_complete_parents: bool,
+    /// Prefix task numbers may carry when looked up over HTTP, e.g. `T-` to
+    /// accept both `/api/tasks/number/T-42` and `/api/tasks/number/42`
+    pub task_number_prefix: String,
+    /// Treat usernames differing only in case as the same user for login,
+    /// lookups and duplicate checks; the stored casing is kept for display.
+    /// On by default; `TASKER_CASE_INSENSITIVE_USERNAMES=false` restores
+    /// exact matching for stores that already hold such usernames
+    pub case_insensitive_usernames: bool,
+    /// Priority for new tasks created without one (`TASKER_DEFAULT_PRIORITY`, e.g. `MEDIUM`)
+    pub default_priority: TaskPriority,
+    /// Status new tasks start in (`TASKER_INITIAL_STATUS`, e.g. `BACKLOG`); must not be a finished status
+    pub initial_status: TaskStatus,
+    /// Lowest priority whose tasks must have a due date
+    /// (`TASKER_DUE_DATE_REQUIRED_FROM`, e.g. `HIGH`); unset = never required
+    pub due_date_required_from: Option<TaskPriority>,
+    /// Refuse new tasks whose due date has already passed, unless the request
+    /// sets `allow_past_due`
+    pub reject_past_due_dates: bool,
+    /// Open tasks due within this many days count as due soon
+    pub due_soon_days: i64,
+    /// Count only working days towards the due-soon window instead of every 24 hours
+    pub due_soon_working_days_only: bool,
+    /// Days of the week the team works (`TASKER_WORKING_DAYS`, e.g. `mon,tue,wed,thu,fri`)
+    pub working_days: Vec<Weekday>,
+    /// Dates that aren't working days (`TASKER_HOLIDAYS`, e.g. `2026-12-25,2026-12-26`)
+    pub holidays: Vec<NaiveDate>,
+    /// Order for task lists that don't ask for one (`TASKER_DEFAULT_SORT`, e.g.
+    /// `created_at desc`). User lists follow it on creation time, their only sortable field.
+    pub default_sort: TaskSort,
+    /// Most tasks one user may be assigned; 0 = unlimited. Unassigned tasks
+    /// count against nobody, and admin reassignment ignores the limit.
+    pub max_tasks_per_user: u64,
+    /// Most tasks the server stores in total, a guardrail for small
+    /// deployments; 0 = unlimited. Soft-deleted tasks don't count.
+    pub max_total_tasks: u64,
+    /// Most users the server stores in total; 0 = unlimited
+    pub max_total_users: u64,
+    /// Longest a task title may be, in characters
+    pub max_title_length: usize,
+    /// Longest a task description may be, in characters
+    pub max_description_length: usize,
+    /// Longest a comment may be, in characters; 0 = unlimited
+    pub max_comment_length: usize,
+    /// What happens to links in comments (`TASKER_COMMENT_URLS`: `allow`, `strip` or `flag`)
+    pub comment_url_policy: CommentUrlPolicy,
+    /// Comments using any of these words are flagged for review (`TASKER_COMMENT_BLOCKED_WORDS`, comma-separated)
+    pub comment_blocked_words: Vec<String>,
+    /// Most tags a single task may carry
+    pub max_tags_per_task: usize,
+    /// How many times a task's due date may be snoozed; 0 allows any number
+    pub max_snoozes_per_task: u32,
+    /// Longest a tag may be, in characters
+    pub max_tag_length: usize,
+    /// Most custom fields a single task may carry
+    pub max_custom_fields: usize,
+    /// Longest a custom field's key or value may be, in characters
+    pub max_custom_field_length: usize,
+    /// Lowercase tags on write so `Backend` and `backend` are the same tag
+    pub lowercase_tags: bool,
+    /// Default maximum edit distance for fuzzy task search
+    pub fuzzy_max_distance: usize,
+    /// Task lists matching more than this many tasks carry a warning nudging
+    /// clients to filter; 0 turns the warning off
+    pub result_soft_limit: u64,
+    /// Move tasks that have been `Done` this long into the archive; 0 never archives
+    pub archive_done_after_secs: i64,
+    /// How often the archive job looks for done tasks to archive
+    pub archive_interval_secs: u64,
+    /// Priority levels an overdue open task gains per escalation run; 0 turns escalation off
+    pub escalate_overdue_levels: i32,
+    /// Escalation never raises a task past this priority (`TASKER_ESCALATION_MAX_PRIORITY`)
+    pub escalation_max_priority: TaskPriority,
+    /// How often the escalation job looks for overdue tasks
+    pub escalation_interval_secs: u64,
+    /// How often the scheduler fires reminders that have come due; 0 turns reminders off
+    pub reminder_interval_secs: u64,
+    /// How long soft-deleted tasks are kept before compaction purges them
+    pub tombstone_retention_secs: i64,
+    /// Whether `DELETE /api/tasks/:id` keeps a restorable tombstone (the
+    /// default, matching gRPC callers that leave `force` unset) or deletes the
+    /// task outright (`TASKER_HTTP_SOFT_DELETE`)
+    pub http_soft_delete: bool,
+    /// Upper bound on how long a cached analytics snapshot is served; mutations
+    /// invalidate it immediately, this only covers time-dependent figures like overdue counts
+    pub analytics_cache_ttl_secs: u64,
+    /// Count archived tasks in analytics when the request doesn't say
+    pub analytics_include_archived: bool,
+    /// PEM certificate and key for gRPC TLS; TLS is on only when both are set.
+    /// The HTTP API is served in plain text and expects a TLS-terminating proxy.
+    pub grpc_tls_cert_path: Option<String>,
+    pub grpc_tls_key_path: Option<String>,
+    /// Serve gRPC server reflection, which lets any client list the services and messages
+    pub grpc_reflection: bool,
+    /// Longest a unary request may run before it's abandoned with a timeout error
+    pub request_timeout_secs: u64,
+    /// Requests each server works on at once; beyond this they're refused with
+    /// 503 or `UNAVAILABLE` rather than queued. 0 = unlimited
+    pub max_in_flight_requests: usize,
+    /// Compress responses for clients that accept it, gzip/brotli over HTTP and
+    /// gzip over gRPC, and accept gzip-compressed gRPC requests
+    pub compression: bool,
+    /// How long shutdown waits for in-flight requests and streams to finish
+    pub shutdown_grace_secs: u64,
+    /// Messages each streaming RPC may queue for its client. A full buffer makes
+    /// the server wait for the client rather than drop messages; a stream whose
+    /// client stays slow long enough skips ahead past events it missed.
+    pub stream_buffer_size: usize,
+    /// Streams one client (user, or IP address when anonymous) may hold open at once; 0 = unlimited
+    pub max_streams_per_client: usize,
+    /// Recent task events kept for streams that reconnect with `since`, saved
+    /// beside the storage file so they survive a restart; 0 disables replay
+    pub event_history_size: usize,
+    /// Default rendering of timestamps in HTTP responses (`rfc3339`, `epoch_millis`
+    /// or a UTC offset like `+02:00`); clients can override it per request
+    pub timestamp_format: TimestampFormat,
+    /// Wrap successful JSON responses as `{"data": ..., "meta": ...}`; clients
+    /// can choose per request with an `Accept` profile of `envelope` or `raw`
+    pub response_envelope: bool,
+    /// Deleting a task that doesn't exist answers 204 rather than 404, so
+    /// retried deletes succeed; clients can choose per request with `x-idempotent-delete`
+    pub idempotent_deletes: bool,
+    /// Endpoints switched off (comma-separated in `TASKER_DISABLED_ENDPOINTS`),
+    /// named by RPC in snake_case, e.g. `create_user` or `get_task_analytics`.
+    /// The RPC answers `UNIMPLEMENTED` and every HTTP route calling it 404s.
+    pub disabled_endpoints: Vec<String>,
+    /// Serve `/api/routes` and list the routes in 404 responses; on by default
+    /// only in debug builds
+    pub expose_routes: bool,
+    /// Header carrying the request id that's read from requests (or generated),
+    /// logged with every line for the request, and echoed on the response
+    pub request_id_header: String,
+    /// Origins the HTTP API and gRPC-web answer cross-origin requests from
+    /// (comma-separated in `TASKER_CORS_ORIGINS`); `*` allows any origin
+    pub cors_origins: Vec<String>,
+    /// Let browsers send cookies and `Authorization` with cross-origin requests.
+    /// Browsers refuse credentials with a wildcard origin, so `*` is rejected.
+    pub cors_allow_credentials: bool,
+    /// How long browsers may cache a CORS preflight response
+    pub cors_max_age_secs: u64,
+    /// Human-readable or JSON log lines
+    pub log_format: LogFormat,
+    /// Format of new task, user and project ids; `ulid` makes them sort by creation time
+    pub id_format: IdFormat,
+    /// What deleting a project does to the tasks still in it
+    pub project_delete_policy: ProjectDeletePolicy,
+    /// What purging a user does to their tasks, unless the request names someone to reassign them to
+    pub user_purge_task_policy: UserPurgeTaskPolicy,
+    /// Fields `UpsertTask` matches existing tasks on (comma-separated in
+    /// `TASKER_UPSERT_NATURAL_KEY`, e.g. `title,assigned_to,custom.ticket`)
+    pub upsert_natural_key: Vec<NaturalKeyField>,
+    /// Base64 AES-256 key for encrypting user fields in the storage file; unset
+    /// stores them in plaintext
+    pub field_encryption_key: Option<String>,
+    /// Which user fields are encrypted when a key is set (`email`, `full_name`, `phone`)
+    pub encrypted_fields: Vec<EncryptedField>,
+    /// Avatar given to users whose profile doesn't set one
+    pub default_avatar_url: Option<String>,
+    /// Preferences new users start with unless they pick their own
+    pub default_theme: String,
+    pub default_language: String,
+    pub default_timezone: String,
+    /// Rules for new passwords (`TASKER_PASSWORD_MIN_LENGTH`, `TASKER_PASSWORD_REQUIRE_*`)
+    pub password_policy: PasswordPolicy,
+    /// Seconds past expiry a token is still accepted, to absorb clock skew
+    /// between client and server
+    pub token_leeway_secs: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log aggregators
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" | "plain" | "text" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(anyhow::anyhow!("unknown log format: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectDeletePolicy {
+    /// Refuse to delete a project that still has tasks
+    #[default]
+    Block,
+    /// Delete the project and leave its tasks without one
+    Unassign,
+    /// Soft-delete the project's tasks along with it
+    Cascade,
+}
+
+/// What purging a user does to the tasks assigned to them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserPurgeTaskPolicy {
+    /// Leave the tasks without an assignee
+    #[default]
+    Unassign,
+    /// Delete the tasks outright, without keeping tombstones
+    Delete,
+}
+
+impl FromStr for UserPurgeTaskPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "unassign" => Ok(Self::Unassign),
+            "delete" => Ok(Self::Delete),
+            other => Err(anyhow::anyhow!("unknown user purge task policy: {}", other)),
+        }
+    }
+}
+
+/// A task field making up part of the natural key `UpsertTask` matches on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NaturalKeyField {
+    Title,
+    AssignedTo,
+    ProjectId,
+    /// `custom.<key>`: one of the task's custom fields
+    CustomField(String),
+}
+
+impl FromStr for NaturalKeyField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        if let Some(key) = s.strip_prefix("custom.").filter(|key| !key.is_empty()) {
+            return Ok(Self::CustomField(key.to_string()));
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "title" => Ok(Self::Title),
+            "assigned_to" | "assignee" => Ok(Self::AssignedTo),
+            "project_id" | "project" => Ok(Self::ProjectId),
+            other => Err(anyhow::anyhow!("unknown natural key field: {}", other)),
+        }
+    }
+}
+
+impl FromStr for ProjectDeletePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "unassign" => Ok(Self::Unassign),
+            "cascade" => Ok(Self::Cascade),
+            other => Err(anyhow::anyhow!("unknown project delete policy: {}", other)),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            storage_path: Some("data/storage.json".to_string()),
+            storage_auto_save: true,
+            storage_watch: false,
+            read_only: false,
+            storage_format: None,
+            attachments_dir: "data/attachments".to_string(),
+            attachments_url_base: "/attachments".to_string(),
+            max_concurrent_uploads: 8,
+            upload_wait_secs: 0,
+            auto_assign_to_creator: false,
+            auto_complete_parents: false,
+            task_number_prefix: String::new(),
+            case_insensitive_usernames: true,
+            default_priority: TaskPriority::Medium,
+            initial_status: TaskStatus::Todo,
+            due_date_required_from: None,
+            reject_past_due_dates: true,
+            due_soon_days: 2,
+            due_soon_working_days_only: false,
+            working_days: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            holidays: Vec::new(),
+            default_sort: TaskSort {
+                field: TaskSortField::CreatedAt as i32,
+                direction: SortDirection::Desc as i32,
+            },
+            max_tasks_per_user: 0,
+            max_total_tasks: 0,
+            max_total_users: 0,
+            max_tags_per_task: 20,
+            max_snoozes_per_task: 0,
+            max_tag_length: 50,
+            max_custom_fields: 20,
+            max_custom_field_length: 500,
+            max_title_length: 200,
+            max_description_length: 10_000,
+            max_comment_length: 5_000,
+            comment_url_policy: CommentUrlPolicy::Allow,
+            comment_blocked_words: Vec::new(),
+            lowercase_tags: true,
+            fuzzy_max_distance: 2,
+            result_soft_limit: 1000,
+            archive_done_after_secs: 0,
+            archive_interval_secs: 3600,
+            escalate_overdue_levels: 0,
+            escalation_max_priority: TaskPriority::High,
+            escalation_interval_secs: 3600,
+            reminder_interval_secs: 60,
+            tombstone_retention_secs: 7 * 24 * 3600,
+            http_soft_delete: true,
+            analytics_cache_ttl_secs: 60,
+            analytics_include_archived: false,
+            grpc_tls_cert_path: None,
+            grpc_tls_key_path: None,
+            grpc_reflection: true,
+            request_timeout_secs: 30,
+            max_in_flight_requests: 1024,
+            compression: true,
+            shutdown_grace_secs: 10,
+            stream_buffer_size: 64,
+            max_streams_per_client: 10,
+            event_history_size: 1000,
+            timestamp_format: TimestampFormat::Rfc3339,
+            response_envelope: false,
+            idempotent_deletes: false,
+            disabled_endpoints: Vec::new(),
+            expose_routes: cfg!(debug_assertions),
+            request_id_header: "x-request-id".to_string(),
+            cors_origins: vec!["http://localhost:3000".to_string()],
+            cors_allow_credentials: false,
+            cors_max_age_secs: 600,
+            log_format: LogFormat::Pretty,
+            id_format: IdFormat::Uuid,
+            project_delete_policy: ProjectDeletePolicy::Block,
+            user_purge_task_policy: UserPurgeTaskPolicy::Unassign,
+            upsert_natural_key: vec![NaturalKeyField::Title, NaturalKeyField::AssignedTo],
+            field_encryption_key: None,
+            encrypted_fields: EncryptedField::ALL.to_vec(),
+            default_avatar_url: None,
+            default_theme: "light".to_string(),
+            default_language: "en".to_string(),
+            default_timezone: "UTC".to_string(),
+            password_policy: PasswordPolicy::default(),
+            token_leeway_secs: 60,
+        }
+    }
+}
+
+impl Config {
+    /// Settings from `TASKER_*` variables over the defaults; a variable that is
+    /// set but doesn't parse is an error rather than a silent fallback.
+    pub fn from_env() -> Result<Self, TaskerError> {
+        let defaults = Self::default();
+
+        Ok(Self {
+            // An explicitly empty path opts out of persistence
+            storage_path: match env::var("TASKER_STORAGE_PATH") {
+                Ok(path) if path.is_empty() => None,
+                Ok(path) => Some(path),
+                Err(_) => defaults.storage_path,
+            },
+            storage_auto_save: env_or("TASKER_STORAGE_AUTO_SAVE", defaults.storage_auto_save)?,
+            storage_watch: env_or("TASKER_STORAGE_WATCH", defaults.storage_watch)?,
+            read_only: env_or("TASKER_READ_ONLY", defaults.read_only)?,
+            storage_format: env_parse("TASKER_STORAGE_FORMAT")?.or(defaults.storage_format),
+            attachments_dir: env_or("TASKER_ATTACHMENTS_DIR", defaults.attachments_dir)?,
+            attachments_url_base: env_or("TASKER_ATTACHMENTS_URL_BASE", defaults.attachments_url_base)?,
+            max_concurrent_uploads: env_or("TASKER_MAX_CONCURRENT_UPLOADS", defaults.max_concurrent_uploads)?,
+            upload_wait_secs: env_or("TASKER_UPLOAD_WAIT_SECS", defaults.upload_wait_secs)?,
+            auto_assign_to_creator: env_or("TASKER_AUTO_ASSIGN_TO_CREATOR", defaults.auto_assign_to_creator)?,
+            auto_complete_parents: env_or("TASKER_AUTO_COMPLETE_PARENTS", defaults.auto_complete_parents)?,
+            task_number_prefix: env_or("TASKER_TASK_NUMBER_PREFIX", defaults.task_number_prefix)?,
+            case_insensitive_usernames: env_or("TASKER_CASE_INSENSITIVE_USERNAMES", defaults.case_insensitive_usernames)?,
+            default_priority: env_enum("TASKER_DEFAULT_PRIORITY", "TASK_PRIORITY_", TaskPriority::from_str_name)?
+                .unwrap_or(defaults.default_priority),
+            initial_status: env_enum("TASKER_INITIAL_STATUS", "TASK_STATUS_", TaskStatus::from_str_name)?
+                .unwrap_or(defaults.initial_status),
+            due_date_required_from: env_enum("TASKER_DUE_DATE_REQUIRED_FROM", "TASK_PRIORITY_", TaskPriority::from_str_name)?
+                .filter(|priority| *priority != TaskPriority::Unspecified),
+            reject_past_due_dates: env_or("TASKER_REJECT_PAST_DUE_DATES", defaults.reject_past_due_dates)?,
+            due_soon_days: env_or("TASKER_DUE_SOON_DAYS", defaults.due_soon_days)?,
+            due_soon_working_days_only: env_or("TASKER_DUE_SOON_WORKING_DAYS_ONLY", defaults.due_soon_working_days_only)?,
+            working_days: env_list("TASKER_WORKING_DAYS")?.unwrap_or(defaults.working_days),
+            holidays: env_list("TASKER_HOLIDAYS")?.unwrap_or(defaults.holidays),
+            default_sort: match env::var("TASKER_DEFAULT_SORT") {
+                Ok(value) => parse_sort(&value).ok_or_else(|| invalid_env("TASKER_DEFAULT_SORT", &value, "expected a field and optional direction"))?,
+                Err(_) => defaults.default_sort,
+            },
+            max_tasks_per_user: env_or("TASKER_MAX_TASKS_PER_USER", defaults.max_tasks_per_user)?,
+            max_total_tasks: env_or("TASKER_MAX_TOTAL_TASKS", defaults.max_total_tasks)?,
+            max_total_users: env_or("TASKER_MAX_TOTAL_USERS", defaults.max_total_users)?,
+            max_tags_per_task: env_or("TASKER_MAX_TAGS_PER_TASK", defaults.max_tags_per_task)?,
+            max_snoozes_per_task: env_or("TASKER_MAX_SNOOZES_PER_TASK", defaults.max_snoozes_per_task)?,
+            max_tag_length: env_or("TASKER_MAX_TAG_LENGTH", defaults.max_tag_length)?,
+            max_custom_fields: env_or("TASKER_MAX_CUSTOM_FIELDS", defaults.max_custom_fields)?,
+            max_custom_field_length: env_or("TASKER_MAX_CUSTOM_FIELD_LENGTH", defaults.max_custom_field_length)?,
+            max_title_length: env_or("TASKER_MAX_TITLE_LENGTH", defaults.max_title_length)?,
+            max_description_length: env_or("TASKER_MAX_DESCRIPTION_LENGTH", defaults.max_description_length)?,
+            max_comment_length: env_or("TASKER_MAX_COMMENT_LENGTH", defaults.max_comment_length)?,
+            comment_url_policy: env_or("TASKER_COMMENT_URLS", defaults.comment_url_policy)?,
+            comment_blocked_words: env_list("TASKER_COMMENT_BLOCKED_WORDS")?.unwrap_or(defaults.comment_blocked_words),
+            lowercase_tags: env_or("TASKER_LOWERCASE_TAGS", defaults.lowercase_tags)?,
+            fuzzy_max_distance: env_or("TASKER_FUZZY_MAX_DISTANCE", defaults.fuzzy_max_distance)?,
+            result_soft_limit: env_or("TASKER_RESULT_SOFT_LIMIT", defaults.result_soft_limit)?,
+            archive_done_after_secs: env_or("TASKER_ARCHIVE_DONE_AFTER_SECS", defaults.archive_done_after_secs)?,
+            archive_interval_secs: env_or("TASKER_ARCHIVE_INTERVAL_SECS", defaults.archive_interval_secs)?,
+            escalate_overdue_levels: env_or("TASKER_ESCALATE_OVERDUE_LEVELS", defaults.escalate_overdue_levels)?,
+            escalation_max_priority: env_enum("TASKER_ESCALATION_MAX_PRIORITY", "TASK_PRIORITY_", TaskPriority::from_str_name)?
+                .unwrap_or(defaults.escalation_max_priority),
+            escalation_interval_secs: env_or("TASKER_ESCALATION_INTERVAL_SECS", defaults.escalation_interval_secs)?,
+            reminder_interval_secs: env_or("TASKER_REMINDER_INTERVAL_SECS", defaults.reminder_interval_secs)?,
+            tombstone_retention_secs: env_or("TASKER_TOMBSTONE_RETENTION_SECS", defaults.tombstone_retention_secs)?,
+            http_soft_delete: env_or("TASKER_HTTP_SOFT_DELETE", defaults.http_soft_delete)?,
+            analytics_cache_ttl_secs: env_or("TASKER_ANALYTICS_CACHE_TTL_SECS", defaults.analytics_cache_ttl_secs)?,
+            analytics_include_archived: env_or("TASKER_ANALYTICS_INCLUDE_ARCHIVED", defaults.analytics_include_archived)?,
+            grpc_tls_cert_path: env_path("TASKER_GRPC_TLS_CERT"),
+            grpc_tls_key_path: env_path("TASKER_GRPC_TLS_KEY"),
+            grpc_reflection: env_or("TASKER_GRPC_REFLECTION", defaults.grpc_reflection)?,
+            request_timeout_secs: env_or("TASKER_REQUEST_TIMEOUT_SECS", defaults.request_timeout_secs)?,
+            max_in_flight_requests: env_or("TASKER_MAX_IN_FLIGHT_REQUESTS", defaults.max_in_flight_requests)?,
+            compression: env_or("TASKER_COMPRESSION", defaults.compression)?,
+            shutdown_grace_secs: env_or("TASKER_SHUTDOWN_GRACE_SECS", defaults.shutdown_grace_secs)?,
+            stream_buffer_size: env_or("TASKER_STREAM_BUFFER_SIZE", defaults.stream_buffer_size)?,
+            max_streams_per_client: env_or("TASKER_MAX_STREAMS_PER_CLIENT", defaults.max_streams_per_client)?,
+            event_history_size: env_or("TASKER_EVENT_HISTORY_SIZE", defaults.event_history_size)?,
+            timestamp_format: env_or("TASKER_TIMESTAMP_FORMAT", defaults.timestamp_format)?,
+            response_envelope: env_or("TASKER_RESPONSE_ENVELOPE", defaults.response_envelope)?,
+            idempotent_deletes: env_or("TASKER_IDEMPOTENT_DELETES", defaults.idempotent_deletes)?,
+            disabled_endpoints: env_list("TASKER_DISABLED_ENDPOINTS")?.unwrap_or(defaults.disabled_endpoints),
+            expose_routes: env_or("TASKER_EXPOSE_ROUTES", defaults.expose_routes)?,
+            request_id_header: env_or("TASKER_REQUEST_ID_HEADER", defaults.request_id_header)?,
+            cors_origins: env_list("TASKER_CORS_ORIGINS")?.unwrap_or(defaults.cors_origins),
+            cors_allow_credentials: env_or("TASKER_CORS_ALLOW_CREDENTIALS", defaults.cors_allow_credentials)?,
+            cors_max_age_secs: env_or("TASKER_CORS_MAX_AGE_SECS", defaults.cors_max_age_secs)?,
+            log_format: env_or("TASKER_LOG_FORMAT", defaults.log_format)?,
+            id_format: env_or("TASKER_ID_FORMAT", defaults.id_format)?,
+            project_delete_policy: env_or("TASKER_PROJECT_DELETE_POLICY", defaults.project_delete_policy)?,
+            user_purge_task_policy: env_or("TASKER_USER_PURGE_TASKS", defaults.user_purge_task_policy)?,
+            upsert_natural_key: env_list("TASKER_UPSERT_NATURAL_KEY")?.unwrap_or(defaults.upsert_natural_key),
+            field_encryption_key: env_path("TASKER_FIELD_ENCRYPTION_KEY"),
+            encrypted_fields: env_list("TASKER_ENCRYPTED_FIELDS")?.unwrap_or(defaults.encrypted_fields),
+            default_avatar_url: env_path("TASKER_DEFAULT_AVATAR_URL"),
+            default_theme: env_or("TASKER_DEFAULT_THEME", defaults.default_theme)?,
+            default_language: env_or("TASKER_DEFAULT_LANGUAGE", defaults.default_language)?,
+            default_timezone: env_or("TASKER_DEFAULT_TIMEZONE", defaults.default_timezone)?,
+            password_policy: PasswordPolicy {
+                min_length: env_or("TASKER_PASSWORD_MIN_LENGTH", defaults.password_policy.min_length)?,
+                require_mixed_case: env_or("TASKER_PASSWORD_REQUIRE_MIXED_CASE", defaults.password_policy.require_mixed_case)?,
+                require_digit: env_or("TASKER_PASSWORD_REQUIRE_DIGIT", defaults.password_policy.require_digit)?,
+                require_symbol: env_or("TASKER_PASSWORD_REQUIRE_SYMBOL", defaults.password_policy.require_symbol)?,
+            },
+            token_leeway_secs: env_or("TASKER_TOKEN_LEEWAY_SECS", defaults.token_leeway_secs)?,
+        })
+    }
+}
+
+impl Config {
+    /// The configured request id header, or `None` if it isn't a valid name
+    pub fn request_id_header(&self) -> Option<HeaderName> {
+        HeaderName::from_bytes(self.request_id_header.as_bytes()).ok()
+    }
+
+    /// Reject settings that parse but make no sense together.
+    pub fn validate(&self) -> Result<(), TaskerError> {
+        if self.default_priority == TaskPriority::Unspecified {
+            return Err(TaskerError::Config("default priority must be a concrete priority".to_string()));
+        }
+        if self.archive_done_after_secs > 0 && self.archive_interval_secs == 0 {
+            return Err(TaskerError::Config("archive interval must be at least 1 second".to_string()));
+        }
+        if self.due_soon_days < 0 {
+            return Err(TaskerError::Config("due-soon window can't be negative".to_string()));
+        }
+        if self.due_soon_working_days_only && self.working_days.is_empty() {
+            return Err(TaskerError::Config("counting working days needs at least one working day".to_string()));
+        }
+        if self.escalate_overdue_levels < 0 {
+            return Err(TaskerError::Config("escalation levels can't be negative".to_string()));
+        }
+        if self.escalate_overdue_levels > 0 {
+            if self.escalation_interval_secs == 0 {
+                return Err(TaskerError::Config("escalation interval must be at least 1 second".to_string()));
+            }
+            if self.escalation_max_priority == TaskPriority::Unspecified {
+                return Err(TaskerError::Config("escalation max priority must be a concrete priority".to_string()));
+            }
+        }
+        if self.stream_buffer_size == 0 {
+            return Err(TaskerError::Config("stream buffer size must be at least 1".to_string()));
+        }
+        if self.cors_origins.iter().any(|origin| origin == "*") {
+            if self.cors_origins.len() > 1 {
+                return Err(TaskerError::Config("CORS origin * can't be combined with other origins".to_string()));
+            }
+            if self.cors_allow_credentials {
+                return Err(TaskerError::Config(
+                    "CORS credentials need explicit origins; browsers reject them with origin *".to_string(),
+                ));
+            }
+        } else if let Some(origin) = self.cors_origins.iter().find(|origin| HeaderValue::from_str(origin).is_err()) {
+            return Err(TaskerError::Config(format!("CORS origin {:?} is not a valid header value", origin)));
+        }
+        if self.request_id_header().is_none() {
+            return Err(TaskerError::Config(format!(
+                "request id header {:?} is not a valid header name",
+                self.request_id_header
+            )));
+        }
+        if let Some(key) = &self.field_encryption_key {
+            FieldCipher::new(key, &self.encrypted_fields)
+                .map_err(|e| TaskerError::Config(format!("{:#}", e)))?;
+        }
+        if self.upsert_natural_key.is_empty() {
+            return Err(TaskerError::Config("upsert natural key needs at least one field".to_string()));
+        }
+        let known_endpoints = unknown_method::known_endpoints();
+        if let Some(name) = self.disabled_endpoints.iter().find(|name| !known_endpoints.contains(name)) {
+            return Err(TaskerError::Config(format!("can't disable unknown endpoint {:?}", name)));
+        }
+        if let Some(url) = self.default_avatar_url.as_deref().filter(|url| !validation::valid_url(url)) {
+            return Err(TaskerError::Config(format!("default avatar URL {} is not a valid URL", url)));
+        }
+        if !workflow::is_initial(self.initial_status) {
+            return Err(TaskerError::Config(format!(
+                "initial status {} is not a valid starting status",
+                self.initial_status.as_str_name()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A sort spec like `created_at desc` or `PRIORITY`; the direction defaults to ascending.
+fn parse_sort(value: &str) -> Option<TaskSort> {
+    let mut parts = value.split_whitespace();
+    let field = parts.next()?.to_ascii_uppercase();
+    let field = TaskSortField::from_str_name(&field)
+        .or_else(|| TaskSortField::from_str_name(&format!("TASK_SORT_FIELD_{}", field)))
+        .filter(|field| *field != TaskSortField::Unspecified)?;
+    let direction = match parts.next().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("asc") => SortDirection::Asc,
+        Some("desc") => SortDirection::Desc,
+        Some(_) => return None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(TaskSort { field: field as i32, direction: direction as i32 })
+}
+
+/// Parse a proto enum by name, with or without its prefix (`HIGH` or `TASK_PRIORITY_HIGH`).
+fn env_enum<T>(key: &str, prefix: &str, from_str_name: fn(&str) -> Option<T>) -> Result<Option<T>, TaskerError> {
+    let Ok(value) = env::var(key) else {
+        return Ok(None);
+    };
+    let name = value.to_ascii_uppercase();
+    from_str_name(&name)
+        .or_else(|| from_str_name(&format!("{}{}", prefix, name)))
+        .map(Some)
+        .ok_or_else(|| invalid_env(key, &value, "unknown name"))
+}
+
+/// A comma-separated list; `None` when unset, an error when any item doesn't parse
+fn env_list<T: FromStr>(key: &str) -> Result<Option<Vec<T>>, TaskerError>
+where
+    T::Err: Display,
+{
+    let Ok(value) = env::var(key) else {
+        return Ok(None);
+    };
+    value.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse().map_err(|e| invalid_env(key, item, e)))
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+fn env_parse<T: FromStr>(key: &str) -> Result<Option<T>, TaskerError>
+where
+    T::Err: Display,
+{
+    match env::var(key) {
+        Ok(value) => value.parse().map(Some).map_err(|e| invalid_env(key, &value, e)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn invalid_env(key: &str, value: &str, reason: impl Display) -> TaskerError {
+    TaskerError::Config(format!("{}={:?}: {}", key, value, reason))
+}
+
+fn env_path(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|path| !path.is_empty())
+}
+
+fn env_or<T: FromStr>(key: &str, default: T) -> Result<T, TaskerError>
+where
+    T::Err: Display,
+{
+    Ok(env_parse(key)?.unwrap_or(default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_format_accepts_plain_and_json() {
+        assert_eq!("plain".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+        assert_eq!("JSON".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn unparseable_env_values_are_errors_not_defaults() {
+        // Keys of their own so other tests reading the environment aren't affected
+        env::set_var("TASKER_TEST_LOG_FORMAT", "jsno");
+        let error = env_or("TASKER_TEST_LOG_FORMAT", LogFormat::Pretty).unwrap_err();
+        assert!(error.to_string().contains("TASKER_TEST_LOG_FORMAT"), "{}", error);
+        assert_eq!(env_or("TASKER_TEST_UNSET", LogFormat::Json).unwrap(), LogFormat::Json);
+
+        env::set_var("TASKER_TEST_HOLIDAYS", "2026-12-25, 2026-12-32");
+        let error = env_list::<NaiveDate>("TASKER_TEST_HOLIDAYS").unwrap_err();
+        assert!(error.to_string().contains("2026-12-32"), "{}", error);
+        env::set_var("TASKER_TEST_HOLIDAYS", "2026-12-25, 2026-12-26,");
+        assert_eq!(env_list::<NaiveDate>("TASKER_TEST_HOLIDAYS").unwrap().unwrap().len(), 2);
+
+        env::set_var("TASKER_TEST_PRIORITY", "urgent");
+        assert!(env_enum("TASKER_TEST_PRIORITY", "TASK_PRIORITY_", TaskPriority::from_str_name).is_err());
+        env::set_var("TASKER_TEST_PRIORITY", "high");
+        assert_eq!(env_enum("TASKER_TEST_PRIORITY", "TASK_PRIORITY_", TaskPriority::from_str_name).unwrap(), Some(TaskPriority::High));
+    }
+}
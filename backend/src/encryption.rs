@@ -0,0 +1,133 @@
+// src/encryption.rs
+use std::str::FromStr;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::protogen::User;
+
+/// Format version of a sealed value. Which values are ciphertext is recorded
+/// next to them in the storage file, never guessed from this prefix: a user
+/// may well pick a name that starts with it.
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+const NONCE_LEN: usize = 12;
+
+/// A user field that can be encrypted at rest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedField {
+    Email,
+    FullName,
+    Phone,
+}
+
+impl EncryptedField {
+    pub const ALL: [EncryptedField; 3] = [Self::Email, Self::FullName, Self::Phone];
+
+    fn value_mut(self, user: &mut User) -> Option<&mut String> {
+        match self {
+            Self::Email => Some(&mut user.email),
+            Self::FullName => Some(&mut user.full_name),
+            Self::Phone => user.profile.as_mut().map(|profile| &mut profile.phone),
+        }
+    }
+}
+
+impl FromStr for EncryptedField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "email" => Ok(Self::Email),
+            "full_name" => Ok(Self::FullName),
+            "phone" => Ok(Self::Phone),
+            other => Err(anyhow::anyhow!("unknown encrypted field: {}", other)),
+        }
+    }
+}
+
+/// AES-256-GCM over selected user fields, applied as the storage file is
+/// written and reversed as it's read. Data in memory stays in plaintext, so
+/// the email and username indices are rebuilt from decrypted values on load
+/// and lookups work unchanged; only the file holds ciphertext.
+pub struct FieldCipher {
+    cipher: Aes256Gcm,
+    fields: Vec<EncryptedField>,
+}
+
+impl std::fmt::Debug for FieldCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldCipher").field("fields", &self.fields).finish_non_exhaustive()
+    }
+}
+
+impl FieldCipher {
+    /// `key` is 32 bytes, base64-encoded
+    pub fn new(key: &str, fields: &[EncryptedField]) -> Result<Self> {
+        let key = BASE64.decode(key.trim()).context("encryption key is not valid base64")?;
+        if key.len() != 32 {
+            anyhow::bail!("encryption key must be 32 bytes, got {}", key.len());
+        }
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+            fields: fields.to_vec(),
+        })
+    }
+
+    /// Encrypt the configured fields of a plaintext user, returning the
+    /// fields that now hold ciphertext.
+    pub fn encrypt_user(&self, user: &mut User) -> Result<Vec<EncryptedField>> {
+        let mut encrypted = Vec::new();
+        for field in &self.fields {
+            if let Some(value) = field.value_mut(user) {
+                if !value.is_empty() {
+                    *value = self.encrypt(value)?;
+                    encrypted.push(*field);
+                }
+            }
+        }
+        Ok(encrypted)
+    }
+
+    /// Decrypt the fields `encrypt_user` reported, whether or not they are
+    /// still configured, so narrowing the field list doesn't strand data
+    /// that's already encrypted.
+    pub fn decrypt_user(&self, user: &mut User, fields: &[EncryptedField]) -> Result<()> {
+        let user_id = user.id.clone();
+        for field in fields {
+            if let Some(value) = field.value_mut(user) {
+                *value = self.decrypt(value)
+                    .with_context(|| format!("Failed to decrypt {:?} of user {}", field, user_id))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self.cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow::anyhow!("encryption failed"))?;
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(format!("{}{}", ENCRYPTED_PREFIX, BASE64.encode(sealed)))
+    }
+
+    fn decrypt(&self, value: &str) -> Result<String> {
+        let sealed = value.strip_prefix(ENCRYPTED_PREFIX).context("value is not sealed")?;
+        let sealed = BASE64.decode(sealed)?;
+        if sealed.len() < NONCE_LEN {
+            anyhow::bail!("ciphertext is truncated");
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("wrong key or corrupted ciphertext"))?;
+        Ok(String::from_utf8(plaintext)?)
+    }
+}
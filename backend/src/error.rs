@@ -0,0 +1,158 @@
+// src/error.rs
+use std::fmt;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use tonic::{Code, Status};
+
+/// Errors the crate surfaces to callers, mapped once here onto gRPC codes and
+/// HTTP statuses instead of at every call site.
+#[derive(thiserror::Error)]
+pub enum TaskerError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Unauthenticated(String),
+    #[error("{0}")]
+    PermissionDenied(String),
+    #[error("{0}")]
+    AlreadyExists(String),
+    /// The request is well-formed but the current state doesn't allow it
+    #[error("{0}")]
+    Conflict(String),
+    /// A quota or limit is used up
+    #[error("{0}")]
+    ResourceExhausted(String),
+    #[error("Storage error: {0:#}")]
+    Storage(anyhow::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+    #[error("Server error: {0}")]
+    Server(String),
+    /// A gRPC status with no closer equivalent, passed through unchanged
+    #[error("gRPC error: {0}")]
+    Rpc(Box<Status>),
+}
+
+// `main` reports its error with `Debug`, so keep that readable
+impl fmt::Debug for TaskerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl From<Status> for TaskerError {
+    fn from(status: Status) -> Self {
+        let message = status.message().to_string();
+        match status.code() {
+            Code::NotFound => TaskerError::NotFound(message),
+            Code::InvalidArgument => TaskerError::Validation(message),
+            Code::Unauthenticated => TaskerError::Unauthenticated(message),
+            Code::PermissionDenied => TaskerError::PermissionDenied(message),
+            Code::AlreadyExists => TaskerError::AlreadyExists(message),
+            Code::FailedPrecondition => TaskerError::Conflict(message),
+            Code::ResourceExhausted => TaskerError::ResourceExhausted(message),
+            _ => TaskerError::Rpc(Box::new(status)),
+        }
+    }
+}
+
+impl From<TaskerError> for Status {
+    fn from(error: TaskerError) -> Self {
+        match error {
+            TaskerError::NotFound(message) => Status::not_found(message),
+            TaskerError::Validation(message) => Status::invalid_argument(message),
+            TaskerError::Unauthenticated(message) => Status::unauthenticated(message),
+            TaskerError::PermissionDenied(message) => Status::permission_denied(message),
+            TaskerError::AlreadyExists(message) => Status::already_exists(message),
+            TaskerError::Conflict(message) => Status::failed_precondition(message),
+            TaskerError::ResourceExhausted(message) => Status::resource_exhausted(message),
+            TaskerError::Rpc(status) => *status,
+            other => Status::internal(other.to_string()),
+        }
+    }
+}
+
+impl TaskerError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            TaskerError::NotFound(_) => StatusCode::NOT_FOUND,
+            TaskerError::Validation(_) => StatusCode::BAD_REQUEST,
+            TaskerError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            TaskerError::PermissionDenied(_) => StatusCode::FORBIDDEN,
+            TaskerError::AlreadyExists(_) | TaskerError::Conflict(_) => StatusCode::CONFLICT,
+            TaskerError::ResourceExhausted(_) => StatusCode::TOO_MANY_REQUESTS,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for TaskerError {
+    fn into_response(self) -> Response {
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+// Storage reports some failures, such as a full store, as a `TaskerError`
+// inside `anyhow`; keep their kind rather than calling them storage errors
+impl From<anyhow::Error> for TaskerError {
+    fn from(error: anyhow::Error) -> Self {
+        error.downcast().unwrap_or_else(TaskerError::Storage)
+    }
+}
+
+impl From<tonic::transport::Error> for TaskerError {
+    fn from(error: tonic::transport::Error) -> Self {
+        TaskerError::Server(error.to_string())
+    }
+}
+
+impl From<std::net::AddrParseError> for TaskerError {
+    fn from(error: std::net::AddrParseError) -> Self {
+        TaskerError::Server(error.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for TaskerError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        TaskerError::Server(error.to_string())
+    }
+}
+
+impl From<tonic_reflection::server::Error> for TaskerError {
+    fn from(error: tonic_reflection::server::Error) -> Self {
+        TaskerError::Server(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_codes_survive_the_round_trip_through_tasker_error() {
+        for code in [Code::NotFound, Code::InvalidArgument, Code::PermissionDenied, Code::AlreadyExists, Code::ResourceExhausted, Code::Unavailable] {
+            let status = Status::from(TaskerError::from(Status::new(code, "nope")));
+            assert_eq!(status.code(), code);
+            assert_eq!(status.message(), "nope");
+        }
+        assert_eq!(TaskerError::Conflict("busy".to_string()).status_code(), StatusCode::CONFLICT);
+        assert_eq!(Status::from(TaskerError::Config("bad".to_string())).code(), Code::Internal);
+    }
+
+    #[test]
+    fn storage_errors_keep_their_kind_through_anyhow_context() {
+        let full = anyhow::Error::new(TaskerError::ResourceExhausted("store is full".to_string()))
+            .context("Failed to create task");
+        assert!(matches!(TaskerError::from(full), TaskerError::ResourceExhausted(_)));
+
+        let other = TaskerError::from(anyhow::anyhow!("disk on fire").context("Failed to save"));
+        assert!(matches!(other, TaskerError::Storage(_)));
+        assert_eq!(other.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(other.to_string(), "Storage error: Failed to save: disk on fire");
+    }
+}
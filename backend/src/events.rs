@@ -0,0 +1,303 @@
+// src/events.rs
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tokio::sync::{broadcast, mpsc};
+use uuid::Uuid;
+
+use crate::protogen::{Task, TaskEvent, TaskEventType};
+use crate::types::timestamp::SerdeTimestamp;
+
+/// How far a subscriber may fall behind before it starts missing events
+const EVENT_CAPACITY: usize = 256;
+
+/// Recent events kept for replay when no size is configured
+const DEFAULT_HISTORY: usize = 1000;
+
+/// Fans task events out to every live stream, keeping the most recent ones in
+/// memory so reconnecting clients can catch up. `Storage` saves the history
+/// beside its file and restores it on load, so it survives a restart.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<TaskEvent>,
+    saturation: Arc<Saturation>,
+    // Held while publishing so a subscriber sees each event either in its
+    // replay or live, never both and never neither
+    history: Arc<Mutex<History>>,
+    streams: Arc<Mutex<StreamRegistry>>,
+}
+
+#[derive(Debug, Default)]
+struct StreamRegistry {
+    next_id: u64,
+    active: HashMap<u64, StreamInfo>,
+}
+
+/// A connected streaming RPC, as listed for admins
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamInfo {
+    pub id: u64,
+    /// `task_events`, `collaborate` or `tasks`
+    pub kind: &'static str,
+    /// The authenticated caller, empty for anonymous streams
+    pub user_id: String,
+    /// Client IP address, when the transport knows it
+    pub peer_ip: String,
+    pub filters: StreamFilters,
+    pub connected_at: String,
+}
+
+/// What a stream subscribed to; empty means everything
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StreamFilters {
+    pub task_ids: Vec<String>,
+    pub event_types: Vec<String>,
+    pub user_id: String,
+}
+
+/// Keeps a stream listed until dropped, which happens however its task ends.
+#[derive(Debug)]
+pub struct StreamRegistration {
+    id: u64,
+    streams: Arc<Mutex<StreamRegistry>>,
+}
+
+impl Drop for StreamRegistration {
+    fn drop(&mut self) {
+        let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        streams.active.remove(&self.id);
+    }
+}
+
+#[derive(Debug)]
+struct History {
+    events: VecDeque<TaskEvent>,
+    capacity: usize,
+}
+
+#[derive(Debug, Default)]
+struct Saturation {
+    buffer_full: AtomicU64,
+    lagged: AtomicU64,
+}
+
+/// How often streams have struggled to keep up, for the admin stats
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct StreamStats {
+    /// Sends that found a stream's buffer full and had to wait for the client
+    pub buffer_full_waits: u64,
+    /// Events skipped by streams that fell more than the bus capacity behind
+    pub lagged_events: u64,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::with_history(DEFAULT_HISTORY)
+    }
+
+    /// A bus retaining the last `capacity` events for replay; 0 disables replay.
+    pub fn with_history(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(EVENT_CAPACITY);
+        let history = History {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        };
+        Self {
+            sender,
+            saturation: Arc::default(),
+            history: Arc::new(Mutex::new(history)),
+            streams: Arc::default(),
+        }
+    }
+
+    pub fn publish(
+        &self,
+        event_type: TaskEventType,
+        task: &Task,
+        user_id: &str,
+        metadata: HashMap<String, String>,
+    ) {
+        let event = TaskEvent {
+            event_id: Uuid::new_v4().to_string(),
+            event_type: event_type as i32,
+            task: Some(task.clone()),
+            user_id: user_id.to_string(),
+            timestamp: Some(SerdeTimestamp::now()),
+            metadata,
+        };
+
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        if history.capacity > 0 {
+            if history.events.len() == history.capacity {
+                history.events.pop_front();
+            }
+            history.events.push_back(event.clone());
+        }
+        // Only fails when there are no subscribers
+        let _ = self.sender.send(event);
+    }
+
+    /// Tell every stream the server is going away; streams forward this
+    /// regardless of their filters and then close.
+    pub fn publish_shutdown(&self) {
+        let event = TaskEvent {
+            event_id: Uuid::new_v4().to_string(),
+            event_type: TaskEventType::ServerShutdown as i32,
+            task: None,
+            user_id: String::new(),
+            timestamp: Some(SerdeTimestamp::now()),
+            metadata: HashMap::from([("reason".to_string(), "server shutting down".to_string())]),
+        };
+        let _ = self.sender.send(event);
+    }
+
+    /// Every retained event, oldest first
+    pub fn history(&self) -> Vec<TaskEvent> {
+        let history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        history.events.iter().cloned().collect()
+    }
+
+    /// Replace the retained events with `events` (oldest first), as read back
+    /// from disk, keeping only the newest that fit.
+    pub fn restore_history(&self, events: Vec<TaskEvent>) {
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        let skip = events.len().saturating_sub(history.capacity);
+        history.events = events.into_iter().skip(skip).collect();
+    }
+
+    /// Retained events caused by `user_id`, oldest first
+    pub fn history_by_actor(&self, user_id: &str) -> Vec<TaskEvent> {
+        let history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        history.events.iter().filter(|event| event.user_id == user_id).cloned().collect()
+    }
+
+    /// Drop retained events caused by `user_id` or whose task snapshot
+    /// mentions them, so a purged user can't be replayed to new streams
+    pub fn forget_user(&self, user_id: &str) {
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        history.events.retain(|event| {
+            let involved = event.task.as_ref().is_some_and(|task| {
+                task.assigned_to == user_id
+                    || task.watchers.iter().any(|watcher| watcher == user_id)
+                    || task.comments.iter().any(|comment| comment.author_id == user_id)
+            });
+            event.user_id != user_id && !involved
+        });
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Subscribe, along with the retained events a reconnecting client missed:
+    /// those after `after_event_id`, or after `since` when that id is empty or
+    /// no longer retained. With neither, nothing is replayed.
+    pub fn subscribe_since(
+        &self,
+        since: Option<&SerdeTimestamp>,
+        after_event_id: &str,
+    ) -> (Vec<TaskEvent>, broadcast::Receiver<TaskEvent>) {
+        let history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        let receiver = self.sender.subscribe();
+
+        let position = (!after_event_id.is_empty())
+            .then(|| history.events.iter().position(|event| event.event_id == after_event_id))
+            .flatten();
+        let missed = match (position, since) {
+            (Some(position), _) => history.events.iter().skip(position + 1).cloned().collect(),
+            (None, Some(since)) => history.events
+                .iter()
+                .filter(|event| {
+                    event.timestamp.as_ref()
+                        .is_some_and(|at| (at.seconds, at.nanos) > (since.seconds, since.nanos))
+                })
+                .cloned()
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+        (missed, receiver)
+    }
+
+    /// Queue `item` on a stream's outgoing buffer. When the buffer is full this
+    /// waits for the client to drain it rather than dropping anything, so a slow
+    /// client holds back its own forwarding task; while that task waits it isn't
+    /// reading the bus, and once it's `EVENT_CAPACITY` events behind it skips
+    /// ahead. Each wait is counted. Fails only when the client has gone away.
+    pub async fn send_to_stream<T>(&self, tx: &mpsc::Sender<T>, item: T) -> Result<(), mpsc::error::SendError<T>> {
+        match tx.try_send(item) {
+            Ok(()) => Ok(()),
+            Err(mpsc::error::TrySendError::Full(item)) => {
+                self.saturation.buffer_full.fetch_add(1, Ordering::Relaxed);
+                tx.send(item).await
+            }
+            Err(mpsc::error::TrySendError::Closed(item)) => Err(mpsc::error::SendError(item)),
+        }
+    }
+
+    /// List a newly connected stream until the returned registration is dropped.
+    /// Clients are told apart by user, or by IP address when anonymous; one
+    /// already holding `limit` streams gets `None` (0 = no limit).
+    pub fn register_stream(
+        &self,
+        kind: &'static str,
+        user_id: &str,
+        peer_ip: &str,
+        filters: StreamFilters,
+        limit: usize,
+    ) -> Option<StreamRegistration> {
+        let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        let same_client = |stream: &&StreamInfo| {
+            if user_id.is_empty() {
+                stream.user_id.is_empty() && stream.peer_ip == peer_ip
+            } else {
+                stream.user_id == user_id
+            }
+        };
+        if limit > 0 && streams.active.values().filter(same_client).count() >= limit {
+            return None;
+        }
+
+        streams.next_id += 1;
+        let id = streams.next_id;
+        streams.active.insert(id, StreamInfo {
+            id,
+            kind,
+            user_id: user_id.to_string(),
+            peer_ip: peer_ip.to_string(),
+            filters,
+            connected_at: chrono::Utc::now().to_rfc3339(),
+        });
+        Some(StreamRegistration {
+            id,
+            streams: self.streams.clone(),
+        })
+    }
+
+    /// Connected streams, oldest first.
+    pub fn active_streams(&self) -> Vec<StreamInfo> {
+        let streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        let mut active: Vec<_> = streams.active.values().cloned().collect();
+        active.sort_by_key(|stream| stream.id);
+        active
+    }
+
+    /// Note that a subscriber fell behind and missed `skipped` events.
+    pub fn record_lagged(&self, skipped: u64) {
+        self.saturation.lagged.fetch_add(skipped, Ordering::Relaxed);
+    }
+
+    pub fn stream_stats(&self) -> StreamStats {
+        StreamStats {
+            buffer_full_waits: self.saturation.buffer_full.load(Ordering::Relaxed),
+            lagged_events: self.saturation.lagged.load(Ordering::Relaxed),
+        }
+    }
+}
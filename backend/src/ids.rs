@@ -0,0 +1,68 @@
+// src/ids.rs
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use ulid::{Generator, Ulid};
+use uuid::Uuid;
+
+/// Hands out ULIDs that keep increasing even within one millisecond, so ids
+/// from a single process sort in the order they were created
+static ULIDS: Mutex<Generator> = Mutex::new(Generator::new());
+
+/// How new task, user and project ids are generated. Ids are opaque strings
+/// everywhere else, so switching formats leaves existing records loadable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdFormat {
+    /// Random UUIDv4
+    #[default]
+    Uuid,
+    /// 26-character ULIDs, which sort lexicographically by creation time
+    Ulid,
+}
+
+impl IdFormat {
+    pub fn generate(self) -> String {
+        match self {
+            Self::Uuid => Uuid::new_v4().to_string(),
+            Self::Ulid => {
+                let mut ulids = ULIDS.lock().unwrap_or_else(|e| e.into_inner());
+                // Only fails once a millisecond's random space is used up
+                ulids.generate().unwrap_or_else(|_| Ulid::new()).to_string()
+            }
+        }
+    }
+}
+
+impl FromStr for IdFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "uuid" | "uuid4" => Ok(Self::Uuid),
+            "ulid" => Ok(Self::Ulid),
+            other => Err(anyhow::anyhow!("unknown id format: {}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ulid_ids_sort_in_creation_order() {
+        let ids: Vec<String> = (0..500).map(|_| IdFormat::Ulid.generate()).collect();
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(sorted, ids);
+        assert!(ids.iter().all(|id| id.len() == 26));
+    }
+
+    #[test]
+    fn formats_parse_by_name() {
+        assert_eq!("ULID".parse::<IdFormat>().unwrap(), IdFormat::Ulid);
+        assert_eq!("uuid4".parse::<IdFormat>().unwrap(), IdFormat::Uuid);
+        assert!(Uuid::parse_str(&IdFormat::Uuid.generate()).is_ok());
+        assert!("snowflake".parse::<IdFormat>().is_err());
+    }
+}
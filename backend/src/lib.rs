@@ -1,7 +1,23 @@
 // src/lib.rs
+pub mod attachments;
+pub mod auth;
+pub mod calendar;
+pub mod config;
+pub mod encryption;
+pub mod error;
+pub mod events;
+pub mod ids;
+pub mod mentions;
+pub mod moderation;
 pub mod protogen;
+pub mod read_only;
+pub mod request_id;
+pub mod search;
 pub mod services;
 pub mod storage;
+pub mod workflow;
 pub mod types;
+pub mod unknown_method;
+pub mod validation;
 // Re-export commonly used types for convenience
 pub use types::SerdeTimestamp;
\ No newline at end of file
@@ -1,387 +1,2610 @@
 // src/main.rs
 use std::net::SocketAddr;
 use std::sync::Arc;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::time::Duration;
 
 use axum::{
-    extract::{Json, Path, Query, State},
-    http::{HeaderValue, Method, StatusCode},
-    response::{IntoResponse},
-    routing::{delete, get, post, put},
+    async_trait,
+    error_handling::HandleErrorLayer,
+    body::{Body, HttpBody},
+    extract::{FromRequestParts, Json, Path, Query, State},
+    http::{header, request::Parts, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    handler::Handler,
+    routing::{on, MethodFilter},
     Router,
 };
 use serde_json::{json, Value};
-use tonic::{transport::Server, Request};
+use tonic::codec::CompressionEncoding;
+use tonic::transport::{Identity, Server, ServerTlsConfig};
+use tonic::{Request, Status};
 use tonic_web::GrpcWebLayer;
-use tower_http::cors::{Any, CorsLayer};
-use tracing::{info};
+use tonic_health::pb::health_server::{Health, HealthServer};
+use tower::limit::GlobalConcurrencyLimitLayer;
+use tower::load_shed::error::Overloaded;
+use tower::timeout::error::Elapsed;
+use tower::{BoxError, ServiceBuilder};
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::{AllowHeaders, AllowOrigin, CorsLayer};
+use tokio::sync::watch;
+use tracing::{info, warn};
+use utoipa::OpenApi;
 
-mod protogen;
-mod services;
-mod storage;
-mod types;
-
-use protogen::{
+use backend::protogen::{
+    self,
+    project_service_server::ProjectServiceServer,
     task_service_server::TaskServiceServer,
     user_service_server::UserServiceServer,
-    *,
 };
-use protogen::task_service_server::TaskService;
-use protogen::user_service_server::UserService;
+use backend::protogen::project_service_server::ProjectService;
+use backend::protogen::task_service_server::TaskService;
+use backend::protogen::user_service_server::UserService;
+
+use backend::attachments::{self, AttachmentStore, ByteRange};
+use backend::auth;
+use backend::config::{Config, LogFormat};
+use backend::error::TaskerError;
+use backend::read_only::ReadOnlyLayer;
+use backend::request_id::RequestIdLayer;
+use backend::services::{ProjectServiceImpl, TaskServiceImpl, UserServiceImpl};
+use backend::storage::Storage;
+use backend::types::timestamp::TimestampFormat;
+use backend::unknown_method::UnknownMethodLayer;
+
+/// Shared state handed to every HTTP handler
+#[derive(Clone)]
+struct AppState {
+    storage: Arc<Storage>,
+    config: Arc<Config>,
+    routes: Arc<Vec<RouteInfo>>,
+}
+
+/// OpenAPI document for the REST API, served at `/api/openapi.json`. Request
+/// and response schemas are derived from the proto messages, so enum fields
+/// appear as their numeric values, as they do in responses.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Tasker API", description = "REST interface to the task, user and auth services"),
+    paths(
+        create_task, upsert_task, list_tasks, get_task, get_task_by_number, update_task, update_task_status, delete_task,
+        archive_task, list_archived_tasks, restore_archived_task,
+        clone_task, merge_tasks, snooze_task, set_reminder, search_tasks, download_attachment,
+        bulk_update_tasks, bulk_update_tasks_by_filter, bulk_tag_tasks,
+        add_comment, list_task_comments, edit_comment, delete_comment,
+        list_tags, rename_tag,
+        get_task_analytics, get_workload, get_task_timeseries,
+        create_user, import_users, list_users, get_user, update_user, delete_user, export_user,
+        create_saved_view, list_saved_views, apply_saved_view,
+        get_me, update_me, my_tasks,
+        create_project, list_projects, get_project, update_project, delete_project, list_project_tasks,
+        login, refresh_token, logout,
+        admin_stats, admin_streams, admin_compact, admin_reindex, admin_reassign_user_tasks,
+        admin_read_only, admin_set_read_only,
+        health_check, readiness_check, list_routes,
+    ),
+    tags(
+        (name = "tasks"), (name = "comments"), (name = "tags"), (name = "analytics"),
+        (name = "users"), (name = "views", description = "Filters a user saved by name"),
+        (name = "me", description = "The signed-in user"), (name = "projects"),
+        (name = "auth", description = "Bearer tokens for the `Authorization` header"),
+        (name = "admin", description = "Admin-only maintenance"), (name = "meta"),
+    ),
+)]
+struct ApiDoc;
+
+/// An HTTP path and the methods it answers, as listed by `/api/routes`
+#[derive(Debug, serde::Serialize)]
+struct RouteInfo {
+    path: &'static str,
+    methods: Vec<String>,
+}
+
+/// Endpoint name for routes that don't front an RPC; they can't be disabled
+const HTTP_ONLY: &str = "";
+
+/// Router builder that remembers what it registers, so the route listing
+/// can't drift from the routes actually served.
+struct ApiRoutes {
+    router: Router<AppState>,
+    registered: BTreeMap<&'static str, Vec<String>>,
+    /// Endpoint names from `disabled_endpoints`
+    disabled: HashSet<String>,
+}
+
+impl ApiRoutes {
+    fn new(disabled: &[String]) -> Self {
+        Self {
+            router: Router::new(),
+            registered: BTreeMap::new(),
+            disabled: disabled.iter().cloned().collect(),
+        }
+    }
+
+    /// Register `handler`, which calls the RPC named by `endpoint` (in the
+    /// snake_case of `disabled_endpoints`), or is `HTTP_ONLY`.
+    fn add<H, T>(mut self, method: MethodFilter, name: &str, path: &'static str, endpoint: &str, handler: H) -> Self
+    where
+        H: Handler<T, AppState>,
+        T: 'static,
+    {
+        if self.disabled.contains(endpoint) {
+            // 404 like any unknown route rather than 405, and stay out of the listing
+            self.router = self.router.route(path, on(method, route_not_found));
+            return self;
+        }
+        self.router = self.router.route(path, on(method, handler));
+        self.registered.entry(path).or_default().push(name.to_string());
+        self
+    }
+
+    fn get<H: Handler<T, AppState>, T: 'static>(self, path: &'static str, endpoint: &str, handler: H) -> Self {
+        self.add(MethodFilter::GET, "GET", path, endpoint, handler)
+    }
+
+    fn post<H: Handler<T, AppState>, T: 'static>(self, path: &'static str, endpoint: &str, handler: H) -> Self {
+        self.add(MethodFilter::POST, "POST", path, endpoint, handler)
+    }
+
+    fn put<H: Handler<T, AppState>, T: 'static>(self, path: &'static str, endpoint: &str, handler: H) -> Self {
+        self.add(MethodFilter::PUT, "PUT", path, endpoint, handler)
+    }
+
+    fn patch<H: Handler<T, AppState>, T: 'static>(self, path: &'static str, endpoint: &str, handler: H) -> Self {
+        self.add(MethodFilter::PATCH, "PATCH", path, endpoint, handler)
+    }
+
+    fn delete<H: Handler<T, AppState>, T: 'static>(self, path: &'static str, endpoint: &str, handler: H) -> Self {
+        self.add(MethodFilter::DELETE, "DELETE", path, endpoint, handler)
+    }
+
+    fn into_parts(self) -> (Router<AppState>, Vec<RouteInfo>) {
+        let routes = self.registered
+            .into_iter()
+            .map(|(path, methods)| RouteInfo { path, methods })
+            .collect();
+        (self.router, routes)
+    }
+}
+
+impl AppState {
+    fn task_service(&self) -> TaskServiceImpl {
+        TaskServiceImpl::new(self.storage.clone(), self.config.clone())
+    }
+
+    fn user_service(&self) -> UserServiceImpl {
+        UserServiceImpl::new(self.storage.clone(), self.config.clone())
+    }
+
+    fn project_service(&self) -> ProjectServiceImpl {
+        ProjectServiceImpl::new(self.storage.clone(), self.config.clone())
+    }
+}
+
+/// Resolve the caller from the `Authorization: Bearer` header
+async fn authenticated_user(parts: &Parts, state: &AppState) -> Result<protogen::User, (StatusCode, String)> {
+    let token = parts.headers.get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(auth::bearer_token)
+        .ok_or((StatusCode::UNAUTHORIZED, "Missing bearer token".to_string()))?;
+
+    auth::authenticate(&state.storage, token)
+        .await
+        .ok_or((StatusCode::UNAUTHORIZED, "Invalid or expired token".to_string()))
+}
+
+/// Wrap a message for the gRPC services, carrying the caller's credentials along.
+fn grpc_request<T>(message: T, headers: &HeaderMap) -> Request<T> {
+    let mut request = Request::new(message);
+    if let Some(value) = headers.get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+    {
+        request.metadata_mut().insert("authorization", value);
+    }
+    request
+}
+
+/// Answer only health checks until the initial load has finished, so
+/// nothing reads, or writes over, data that is about to be replaced.
+async fn reject_until_loaded(
+    State(state): State<AppState>,
+    request: axum::http::Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    if !state.storage.is_loaded() && !request.uri().path().starts_with("/api/health") {
+        return (StatusCode::SERVICE_UNAVAILABLE, "Server is still loading its data").into_response();
+    }
+    next.run(request).await
+}
+
+/// Turn away writes while in read-only mode. Auth routes stay open so an
+/// operator can still sign in, as does the switch that ends the mode.
+async fn reject_writes_when_read_only(
+    State(state): State<AppState>,
+    request: axum::http::Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let method = request.method();
+    let path = request.uri().path();
+    let is_read = method == Method::GET || method == Method::HEAD || method == Method::OPTIONS;
+    let exempt = path.starts_with("/api/auth/") || path == "/api/admin/read-only";
+
+    if state.storage.is_read_only() && !is_read && !exempt {
+        return (StatusCode::SERVICE_UNAVAILABLE, "Server is in read-only mode").into_response();
+    }
+    next.run(request).await
+}
+
+/// Request header choosing how timestamps are rendered, overriding the server default
+const TIMESTAMP_FORMAT_HEADER: &str = "x-timestamp-format";
+
+/// Request header choosing whether deleting a missing task succeeds, overriding the server default
+const IDEMPOTENT_DELETE_HEADER: &str = "x-idempotent-delete";
+
+/// Re-render timestamps in JSON responses when the client or config asks for
+/// something other than UTC RFC 3339.
+async fn format_timestamps(
+    State(state): State<AppState>,
+    request: axum::http::Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let requested = request.headers()
+        .get(TIMESTAMP_FORMAT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::parse::<TimestampFormat>);
+    let format = match requested {
+        Some(Ok(format)) => format,
+        Some(Err(e)) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        None => state.config.timestamp_format,
+    };
+
+    let response = next.run(request).await;
+    if format == TimestampFormat::Rfc3339 || !is_json(&response) {
+        return response;
+    }
+    rewrite_json(response, |value| format.apply(value)).await
+}
+
+fn is_json(response: &Response) -> bool {
+    response.headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|value| value.as_bytes().starts_with(b"application/json"))
+}
+
+/// Buffer a JSON response and re-serialize it after `rewrite`; bodies that
+/// don't parse pass through untouched
+async fn rewrite_json(response: Response, rewrite: impl FnOnce(&mut Value)) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match collect_body(body).await {
+        Ok(bytes) => bytes,
+        Err(response) => return response,
+    };
+    let Ok(mut value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, axum::body::boxed(Body::from(bytes)));
+    };
+    rewrite(&mut value);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, axum::body::boxed(Body::from(value.to_string())))
+}
+
+/// Buffer a whole response body
+async fn collect_body(mut body: axum::body::BoxBody) -> Result<Vec<u8>, Response> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        match chunk {
+            Ok(chunk) => bytes.extend_from_slice(&chunk),
+            Err(e) => return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()),
+        }
+    }
+    Ok(bytes)
+}
+
+/// Response fields that describe the page rather than the data, moved into
+/// `meta.pagination` by the envelope
+const PAGINATION_FIELDS: [&str; 5] = ["next_page_token", "total_count", "grand_total", "current_page", "total_pages"];
 
-use services::{TaskServiceImpl, UserServiceImpl};
-use storage::Storage;
+/// The `profile` an `Accept` header asks for, e.g. `application/json; profile="envelope"`
+fn accept_profile(headers: &HeaderMap) -> Option<String> {
+    let accept = headers.get(header::ACCEPT)?.to_str().ok()?;
+    accept.split([',', ';'])
+        .filter_map(|param| param.trim().strip_prefix("profile="))
+        .map(|profile| profile.trim_matches('"').to_ascii_lowercase())
+        .next()
+}
+
+/// Wrap successful JSON responses as `{"data": ..., "meta": ...}` when the
+/// config or the client's `Accept` profile asks for it. `meta` carries the
+/// request id and, for paged lists, the pagination fields. Errors, downloads
+/// and the OpenAPI document are left alone.
+async fn wrap_in_envelope(
+    State(state): State<AppState>,
+    request: axum::http::Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let enabled = match accept_profile(request.headers()).as_deref() {
+        Some("envelope") => true,
+        Some("raw") => false,
+        _ => state.config.response_envelope,
+    };
+    let request_id_header = state.config.request_id_header().expect("validated at startup");
+    let request_id = request.headers()
+        .get(&request_id_header)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let is_spec = request.uri().path() == "/api/openapi.json";
+
+    let response = next.run(request).await;
+    let is_download = response.headers().contains_key(header::CONTENT_DISPOSITION);
+    if !enabled || is_spec || is_download || !response.status().is_success() || !is_json(&response) {
+        return response;
+    }
+    rewrite_json(response, |value| {
+        let mut meta = json!({ "request_id": request_id });
+        if let Value::Object(fields) = value {
+            let pagination: serde_json::Map<String, Value> = PAGINATION_FIELDS.iter()
+                .filter_map(|name| fields.remove(*name).map(|field| (name.to_string(), field)))
+                .collect();
+            if !pagination.is_empty() {
+                meta["pagination"] = Value::Object(pagination);
+            }
+        }
+        *value = json!({ "data": value.take(), "meta": meta });
+    })
+    .await
+}
+
+/// Guard for admin-only routes
+struct RequireAdmin;
+
+#[async_trait]
+impl FromRequestParts<AppState> for RequireAdmin {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let user = authenticated_user(parts, state).await?;
+        if auth::is_admin(&user) {
+            Ok(RequireAdmin)
+        } else {
+            Err((StatusCode::FORBIDDEN, "Admin role required".to_string()))
+        }
+    }
+}
+
+/// The caller behind the request's bearer token; rejects anonymous requests.
+struct CurrentUser(protogen::User);
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        authenticated_user(parts, state).await.map(CurrentUser)
+    }
+}
 
+/// Parse a proto enum from a query or body value, accepting both the short
+/// form (`DONE`) and the full name (`TASK_STATUS_DONE`).
+fn enum_param<T>(value: &str, prefix: &str, from_str_name: fn(&str) -> Option<T>) -> Option<T> {
+    let value = value.trim().to_ascii_uppercase();
+    from_str_name(&value).or_else(|| from_str_name(&format!("{}{}", prefix, value)))
+}
+
+/// Build a task filter from `status`, `priority` and `tags` (each comma-separated)
+/// plus a free-text `q`.
+fn task_filter_from_query(params: &HashMap<String, String>) -> Result<protogen::TaskFilter, TaskerError> {
+    let list = |key: &str| -> Vec<&str> {
+        params.get(key)
+            .map(|value| value.split(',').filter(|item| !item.trim().is_empty()).collect())
+            .unwrap_or_default()
+    };
+
+    let status = list("status").into_iter()
+        .map(|name| enum_param(name, "TASK_STATUS_", protogen::TaskStatus::from_str_name)
+            .map(|status| status as i32)
+            .ok_or_else(|| TaskerError::Validation(format!("Unknown status: {}", name))))
+        .collect::<Result<_, _>>()?;
+    let priority = list("priority").into_iter()
+        .map(|name| enum_param(name, "TASK_PRIORITY_", protogen::TaskPriority::from_str_name)
+            .map(|priority| priority as i32)
+            .ok_or_else(|| TaskerError::Validation(format!("Unknown priority: {}", name))))
+        .collect::<Result<_, _>>()?;
+
+    // `custom.<key>=<value>`, one parameter per field
+    let custom_fields = params.iter()
+        .filter_map(|(name, value)| name.strip_prefix("custom.").map(|key| (key.to_string(), value.clone())))
+        .collect();
+
+    Ok(protogen::TaskFilter {
+        status,
+        priority,
+        custom_fields,
+        tags: list("tags").into_iter().map(|tag| tag.trim().to_string()).collect(),
+        search_query: params.get("q").cloned().unwrap_or_default(),
+        unassigned: params.get("unassigned").is_some_and(|value| value == "true"),
+        due_soon: params.get("due_soon").is_some_and(|value| value == "true"),
+        ..Default::default()
+    })
+}
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+async fn main() -> Result<(), TaskerError> {
 
-    tracing_subscriber::fmt::init();
+    let config = Arc::new(Config::from_env()?);
+    init_logging(config.log_format);
+    config.validate()?;
 
     // Create storage with persistence
-    let storage = Storage::with_persistence("data/storage.json", true);
-    
-    // Load existing data on startup
-    storage.load_from_disk().await?;
+    let storage = Storage::from_config(&config)?;
     
+    // Fail before anything starts listening if TLS is misconfigured
+    let grpc_tls = load_grpc_tls(&config)?;
+
     // Clone storage for both servers
     let grpc_storage = storage.clone();
     let http_storage = storage.clone();
+    let grpc_config = config.clone();
+    let http_config = config.clone();
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
     // Start gRPC server
+    let grpc_shutdown = shutdown_requested(shutdown_rx.clone());
     let grpc_handle = tokio::spawn(async move {
-        start_grpc_server(grpc_storage.into()).await
+        start_grpc_server(grpc_storage.into(), grpc_config, grpc_tls, grpc_shutdown).await
     });
 
     // Start HTTP server
+    let http_shutdown = shutdown_requested(shutdown_rx);
     let http_handle = tokio::spawn(async move {
-        start_http_server(http_storage.into()).await
+        start_http_server(http_storage.into(), http_config, http_shutdown).await
     });
 
-    // Wait for both servers
-    tokio::try_join!(grpc_handle, http_handle)?;
+    // Load existing data once the servers are listening, so readiness probes
+    // can see the load in progress; API calls get 503 until it finishes
+    if let Err(e) = storage.load_from_disk().await {
+        storage.release_lock();
+        return Err(e.into());
+    }
+    info!("Storage loaded; ready to serve");
+    if config.storage_watch {
+        storage.watch_for_changes()?;
+    }
+    if config.archive_done_after_secs > 0 {
+        spawn_archive_job(storage.clone(), &config);
+    }
+    if config.escalate_overdue_levels > 0 {
+        spawn_escalation_job(storage.clone(), &config);
+    }
+    if config.reminder_interval_secs > 0 {
+        spawn_reminder_job(storage.clone(), &config);
+    }
+
+    let servers = async { tokio::try_join!(grpc_handle, http_handle) };
+    tokio::pin!(servers);
+
+    // Run until a server fails or we're asked to stop
+    tokio::select! {
+        joined = &mut servers => {
+            let (grpc_result, http_result) = joined?;
+            grpc_result?;
+            http_result?;
+            return Ok(());
+        }
+        _ = shutdown_signal() => {}
+    }
+
+    // Streams get a final event and close; the servers stop accepting and
+    // wait for in-flight requests, up to the grace period
+    info!("Shutting down");
+    storage.events().publish_shutdown();
+    let _ = shutdown_tx.send(true);
+
+    let grace = Duration::from_secs(config.shutdown_grace_secs);
+    match tokio::time::timeout(grace, &mut servers).await {
+        Ok(joined) => {
+            let (grpc_result, http_result) = joined?;
+            grpc_result?;
+            http_result?;
+        }
+        Err(_) => warn!("Grace period elapsed with requests still in flight"),
+    }
+    if let Err(e) = storage.save_event_history().await {
+        warn!("Failed to save event history: {}", e);
+    }
+    storage.release_lock();
 
     Ok(())
 }
 
-async fn start_grpc_server(storage: Arc<Storage>) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// Ctrl-C, or SIGTERM from a process manager.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+async fn shutdown_requested(mut shutdown: watch::Receiver<bool>) {
+    while !*shutdown.borrow() {
+        if shutdown.changed().await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Read the gRPC certificate and key, if configured. Setting only one of
+/// the two is treated as a mistake rather than silently serving plain text.
+fn load_grpc_tls(config: &Config) -> Result<Option<ServerTlsConfig>, TaskerError> {
+    let (cert_path, key_path) = match (&config.grpc_tls_cert_path, &config.grpc_tls_key_path) {
+        (Some(cert), Some(key)) => (cert, key),
+        (None, None) => return Ok(None),
+        _ => return Err(TaskerError::Config(
+            "TASKER_GRPC_TLS_CERT and TASKER_GRPC_TLS_KEY must be set together".to_string(),
+        )),
+    };
+    let read = |path: &str, what: &str| std::fs::read(path)
+        .map_err(|e| TaskerError::Config(format!("Failed to read gRPC TLS {} {}: {}", what, path, e)));
+    let cert = read(cert_path, "certificate")?;
+    let key = read(key_path, "key")?;
+    Ok(Some(ServerTlsConfig::new().identity(Identity::from_pem(cert, key))))
+}
+
+fn init_logging(format: LogFormat) {
+    let builder = tracing_subscriber::fmt().with_target(true);
+    match format {
+        LogFormat::Pretty => builder.init(),
+        LogFormat::Json => builder.json().init(),
+    }
+}
+
+/// Periodically archive tasks that have sat in `Done` past the retention
+/// period, announcing each as archived so streams drop them too
+fn spawn_archive_job(storage: Storage, config: &Config) {
+    let max_age_secs = config.archive_done_after_secs;
+    let period = Duration::from_secs(config.archive_interval_secs);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        loop {
+            ticker.tick().await;
+            // Read-only mode freezes the data; whatever is due is picked up
+            // on the first tick after it's switched off
+            if storage.is_read_only() {
+                continue;
+            }
+            match storage.archive_done_tasks(max_age_secs).await {
+                Ok(tasks) => {
+                    for task in &tasks {
+                        storage.events().publish(protogen::TaskEventType::Archived, task, "", HashMap::new());
+                    }
+                    info!(archived = tasks.len(), "Archived done tasks older than {}s", max_age_secs);
+                }
+                Err(e) => warn!("Failed to archive done tasks: {}", e),
+            }
+        }
+    });
+}
+
+/// Periodically bump the priority of overdue tasks, announcing each change
+/// with the previous priority so clients can tell escalations from edits
+fn spawn_escalation_job(storage: Storage, config: &Config) {
+    let levels = config.escalate_overdue_levels;
+    let max_priority = config.escalation_max_priority;
+    let period = Duration::from_secs(config.escalation_interval_secs);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        loop {
+            ticker.tick().await;
+            // Escalation resumes, overdue tasks and all, once read-only mode ends
+            if storage.is_read_only() {
+                continue;
+            }
+            match storage.escalate_overdue_tasks(levels, max_priority).await {
+                Ok(tasks) => {
+                    for (task, previous) in &tasks {
+                        let metadata = HashMap::from([
+                            ("reason".to_string(), "escalated".to_string()),
+                            ("previous_priority".to_string(), previous.as_str_name().to_string()),
+                        ]);
+                        storage.events().publish(protogen::TaskEventType::Updated, task, "", metadata);
+                    }
+                    if !tasks.is_empty() {
+                        info!(escalated = tasks.len(), "Escalated overdue tasks");
+                    }
+                }
+                Err(e) => warn!("Failed to escalate overdue tasks: {}", e),
+            }
+        }
+    });
+}
+
+/// Periodically fire reminders whose time has come, announcing each as a
+/// reminder event for the user who set it
+fn spawn_reminder_job(storage: Storage, config: &Config) {
+    let period = Duration::from_secs(config.reminder_interval_secs);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        loop {
+            ticker.tick().await;
+            // Reminders that come due meanwhile fire once read-only mode ends
+            if storage.is_read_only() {
+                continue;
+            }
+            let now = chrono::Utc::now().timestamp();
+            match storage.fire_due_reminders(now).await {
+                Ok(fired) => {
+                    for (task, reminder) in &fired {
+                        let metadata = HashMap::from([
+                            ("reminder_id".to_string(), reminder.id.clone()),
+                            ("user_id".to_string(), reminder.user_id.clone()),
+                            ("before_secs".to_string(), reminder.before_secs.to_string()),
+                        ]);
+                        storage.events().publish(protogen::TaskEventType::Reminder, task, "", metadata);
+                    }
+                }
+                Err(e) => warn!("Failed to fire reminders: {}", e),
+            }
+        }
+    });
+}
+
+/// Caps requests in flight across every route of one server. The global
+/// variant shares its permits between the copies a router or connection makes.
+fn in_flight_limit(config: &Config) -> Option<GlobalConcurrencyLimitLayer> {
+    (config.max_in_flight_requests > 0).then(|| GlobalConcurrencyLimitLayer::new(config.max_in_flight_requests))
+}
+
+/// Time out, shed and cap in-flight HTTP requests per config
+fn with_request_limits<S>(router: Router<S>, config: &Config) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router.layer(
+        ServiceBuilder::new()
+            .layer(HandleErrorLayer::new(request_limit_error))
+            .load_shed()
+            .option_layer(in_flight_limit(config))
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+    )
+}
+
+async fn request_limit_error(e: BoxError) -> (StatusCode, &'static str) {
+    if e.is::<Overloaded>() {
+        (StatusCode::SERVICE_UNAVAILABLE, "Server is busy; try again shortly")
+    } else if e.is::<Elapsed>() {
+        (StatusCode::GATEWAY_TIMEOUT, "Request timed out")
+    } else {
+        warn!("Request failed in middleware: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+    }
+}
+
+/// Standard grpc.health.v1 service for load balancers and probes; the
+/// custom Health RPC stays for existing clients. Each tasker service reports
+/// NOT_SERVING until storage has loaded.
+async fn grpc_health_service(storage: Storage) -> HealthServer<impl Health> {
+    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter.set_not_serving::<TaskServiceServer<TaskServiceImpl>>().await;
+    health_reporter.set_not_serving::<UserServiceServer<UserServiceImpl>>().await;
+    health_reporter.set_not_serving::<ProjectServiceServer<ProjectServiceImpl>>().await;
+    tokio::spawn(async move {
+        storage.wait_until_loaded().await;
+        health_reporter.set_serving::<TaskServiceServer<TaskServiceImpl>>().await;
+        health_reporter.set_serving::<UserServiceServer<UserServiceImpl>>().await;
+        health_reporter.set_serving::<ProjectServiceServer<ProjectServiceImpl>>().await;
+    });
+    health_service
+}
+
+async fn start_grpc_server(
+    storage: Arc<Storage>,
+    config: Arc<Config>,
+    tls: Option<ServerTlsConfig>,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), TaskerError> {
     let addr: SocketAddr = "0.0.0.0:50051".parse()?;
     
-    let task_service = TaskServiceImpl::new(storage.clone());
-    let user_service = UserServiceImpl::new(storage);
+    let request_timeout = Duration::from_secs(config.request_timeout_secs);
+    let read_only = ReadOnlyLayer::new(Storage::clone(&storage));
+    let request_id_header = config.request_id_header().expect("validated at startup");
+    let reflection = if config.grpc_reflection {
+        Some(tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(protogen::DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+            .build()?)
+    } else {
+        None
+    };
+    let health_service = grpc_health_service(Storage::clone(&storage)).await;
+    let mut task_server = TaskServiceServer::new(TaskServiceImpl::new(storage.clone(), config.clone()));
+    let mut user_server = UserServiceServer::new(UserServiceImpl::new(storage.clone(), config.clone()));
+    let mut project_server = ProjectServiceServer::new(ProjectServiceImpl::new(storage, config.clone()));
+    // Responses are only compressed for clients whose grpc-accept-encoding lists gzip
+    if config.compression {
+        task_server = task_server
+            .accept_compressed(CompressionEncoding::Gzip)
+            .send_compressed(CompressionEncoding::Gzip);
+        user_server = user_server
+            .accept_compressed(CompressionEncoding::Gzip)
+            .send_compressed(CompressionEncoding::Gzip);
+        project_server = project_server
+            .accept_compressed(CompressionEncoding::Gzip)
+            .send_compressed(CompressionEncoding::Gzip);
+    }
 
-    info!("Starting gRPC server on {}", addr);
+    let mut builder = Server::builder();
+    if let Some(tls) = tls {
+        builder = builder.tls_config(tls)?;
+        info!("Starting gRPC server on {} with TLS", addr);
+    } else {
+        info!("Starting gRPC server on {}", addr);
+    }
 
-    Server::builder()
+    builder
         .accept_http1(true)
+        .layer(RequestIdLayer::new(request_id_header.clone()))
+        // Only the wait for response headers is bounded, and only that holds
+        // an in-flight slot, so server-streaming RPCs are unaffected once their
+        // stream has been handed back; open streams have their own per-client cap
+        .layer(
+            ServiceBuilder::new()
+                .map_err(|e: BoxError| -> BoxError {
+                    if e.is::<tower::timeout::error::Elapsed>() {
+                        Status::deadline_exceeded("Request timed out").into()
+                    } else if e.is::<Overloaded>() {
+                        Status::unavailable("Server is busy; try again shortly").into()
+                    } else {
+                        e
+                    }
+                })
+                .load_shed()
+                .option_layer(in_flight_limit(&config))
+                .timeout(request_timeout)
+        )
         .layer(
-            CorsLayer::new()
-                .allow_origin(Any)
-                .allow_headers(Any)
+            cors_layer(&config)
                 .allow_methods([Method::GET, Method::POST])
+                // grpc-web clients read the call status from these
+                .expose_headers([
+                    HeaderName::from_static("grpc-status"),
+                    HeaderName::from_static("grpc-message"),
+                    request_id_header,
+                ])
         )
         .layer(GrpcWebLayer::new())
-        .add_service(tonic_reflection::server::Builder::configure()
-            .register_encoded_file_descriptor_set(protogen::DESCRIPTOR_SET)
-            .build()?)
-        .add_service(TaskServiceServer::new(task_service))
-        .add_service(UserServiceServer::new(user_service))
-        .serve(addr)
+        .layer(read_only)
+        .layer(UnknownMethodLayer::new(&config.disabled_endpoints))
+        .add_optional_service(reflection)
+        .add_service(health_service)
+        .add_service(task_server)
+        .add_service(user_server)
+        .add_service(project_server)
+        .serve_with_shutdown(addr, shutdown)
         .await?;
 
     Ok(())
 }
 
-async fn start_http_server(storage: Arc<Storage>) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+async fn start_http_server(
+    storage: Arc<Storage>,
+    config: Arc<Config>,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), TaskerError> {
     let addr: SocketAddr = "0.0.0.0:3001".parse()?;
-
-    let cors = CorsLayer::new()
-        .allow_origin("http://localhost:3000".parse::<HeaderValue>()?)
-        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-        .allow_headers(Any);
-
-    let app = Router::new()
-        .route("/api/tasks", post(create_task))
-        .route("/api/tasks", get(list_tasks))
-        .route("/api/tasks/:id", get(get_task))
-        .route("/api/tasks/:id", put(update_task))
-        .route("/api/tasks/:id", delete(delete_task))
-        .route("/api/tasks/bulk", put(bulk_update_tasks))
-        .route("/api/tasks/analytics", get(get_task_analytics))
-        .route("/api/users", post(create_user))
-        .route("/api/users", get(list_users))
-        .route("/api/users/:id", get(get_user))
-        .route("/api/users/:id", put(update_user))
-        .route("/api/users/:id", delete(delete_user))
-        .route("/api/auth/login", post(login))
-        .route("/api/auth/refresh", post(refresh_token))
-        .route("/api/auth/logout", post(logout))
-        .route("/api/health", get(health_check))
-        .with_state(storage)
-        .layer(cors);
+    let app = http_app(storage, config);
 
     info!("Starting HTTP server on {}", addr);
 
     axum::Server::bind(&addr)
         .serve(app.into_make_service())
-        .await?;
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| TaskerError::Server(e.to_string()))?;
 
     Ok(())
 }
 
+/// CORS for both servers from `cors_origins` and `cors_allow_credentials`;
+/// each adds the methods and exposed headers it needs
+fn cors_layer(config: &Config) -> CorsLayer {
+    let allow_origin = if config.cors_origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(config.cors_origins.iter().filter_map(|origin| HeaderValue::from_str(origin).ok()))
+    };
+    // A wildcard isn't allowed alongside credentials, so echo what was asked for instead
+    let allow_headers = if config.cors_allow_credentials {
+        AllowHeaders::mirror_request()
+    } else {
+        AllowHeaders::any()
+    };
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_headers(allow_headers)
+        .allow_credentials(config.cors_allow_credentials)
+        .max_age(Duration::from_secs(config.cors_max_age_secs))
+}
+
+/// The HTTP API with all of its middleware
+fn http_app(storage: Arc<Storage>, config: Arc<Config>) -> Router {
+    let request_id_header = config.request_id_header().expect("validated at startup");
+
+    let cors = cors_layer(&config)
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+        .expose_headers([
+            header::ETAG,
+            header::LOCATION,
+            header::CONTENT_DISPOSITION,
+            header::CONTENT_RANGE,
+            header::ACCEPT_RANGES,
+            request_id_header.clone(),
+        ])
+        // Set here because this layer replaces any `Vary` from inside it. The
+        // envelope and timestamp format change the body, and so its ETag
+        .vary([
+            header::ORIGIN,
+            header::ACCESS_CONTROL_REQUEST_METHOD,
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            header::ACCEPT,
+            HeaderName::from_static(TIMESTAMP_FORMAT_HEADER),
+        ]);
+
+    let (router, routes) = ApiRoutes::new(&config.disabled_endpoints)
+        .post("/api/tasks", "create_task", create_task)
+        .get("/api/tasks", "list_tasks", list_tasks)
+        .post("/api/tasks/upsert", "upsert_task", upsert_task)
+        .get("/api/tasks/:id", "get_task", get_task)
+        .get("/api/tasks/number/:number", "get_task_by_number", get_task_by_number)
+        .put("/api/tasks/:id", "update_task", update_task)
+        .delete("/api/tasks/:id", "delete_task", delete_task)
+        .patch("/api/tasks/:id/status", "update_task_status", update_task_status)
+        .post("/api/tasks/:id/archive", "archive_task", archive_task)
+        .get("/api/tasks/archived", "list_archived_tasks", list_archived_tasks)
+        .post("/api/tasks/archived/:id/restore", "restore_archived_task", restore_archived_task)
+        .post("/api/tasks/:id/clone", "clone_task", clone_task)
+        .post("/api/tasks/:id/merge", "merge_tasks", merge_tasks)
+        .post("/api/tasks/:id/snooze", "snooze_task", snooze_task)
+        .post("/api/tasks/:id/reminders", "set_reminder", set_reminder)
+        .post("/api/tasks/:id/comments", "add_comment", add_comment)
+        .get("/api/tasks/:id/comments", "list_task_comments", list_task_comments)
+        .put("/api/tasks/:id/comments/:comment_id", "edit_comment", edit_comment)
+        .delete("/api/tasks/:id/comments/:comment_id", "delete_comment", delete_comment)
+        .put("/api/tasks/bulk", "bulk_update_tasks", bulk_update_tasks)
+        .put("/api/tasks/bulk/by-filter", "bulk_update_tasks_by_filter", bulk_update_tasks_by_filter)
+        .post("/api/tasks/bulk/tags", "bulk_tag_tasks", bulk_tag_tasks)
+        .get("/api/tasks/search", "search_tasks", search_tasks)
+        .get("/api/tasks/analytics", "get_task_analytics", get_task_analytics)
+        .get("/api/tasks/workload", "get_workload", get_workload)
+        .get("/api/tasks/timeseries", "get_task_timeseries", get_task_timeseries)
+        .get("/attachments/:id", HTTP_ONLY, download_attachment)
+        .get("/api/tags", "list_tags", list_tags)
+        .post("/api/tags/rename", "rename_tag", rename_tag)
+        .post("/api/users", "create_user", create_user)
+        .post("/api/users/import", "batch_create_users", import_users)
+        .get("/api/users", "list_users", list_users)
+        .get("/api/users/:id", "get_user", get_user)
+        .put("/api/users/:id", "update_user", update_user)
+        .delete("/api/users/:id", "delete_user", delete_user)
+        .post("/api/users/:id/views", "create_saved_view", create_saved_view)
+        .get("/api/users/:id/views", "list_saved_views", list_saved_views)
+        .get("/api/users/:id/export", HTTP_ONLY, export_user)
+        .get("/api/users/:id/views/:view_id/tasks", "apply_saved_view", apply_saved_view)
+        .post("/api/projects", "create_project", create_project)
+        .get("/api/projects", "list_projects", list_projects)
+        .get("/api/projects/:id", "get_project", get_project)
+        .put("/api/projects/:id", "update_project", update_project)
+        .delete("/api/projects/:id", "delete_project", delete_project)
+        .get("/api/projects/:id/tasks", "list_project_tasks", list_project_tasks)
+        .get("/api/me", HTTP_ONLY, get_me)
+        .patch("/api/me", "update_user", update_me)
+        .get("/api/me/tasks", "list_tasks", my_tasks)
+        .post("/api/auth/login", "login", login)
+        .post("/api/auth/refresh", "refresh_token", refresh_token)
+        .post("/api/auth/logout", "logout", logout)
+        .get("/api/health", HTTP_ONLY, health_check)
+        .get("/api/health/ready", HTTP_ONLY, readiness_check)
+        .get("/api/routes", HTTP_ONLY, list_routes)
+        .get("/api/openapi.json", HTTP_ONLY, openapi_spec)
+        .get("/api/docs", HTTP_ONLY, swagger_ui)
+        .get("/api/admin/stats", HTTP_ONLY, admin_stats)
+        .get("/api/admin/streams", HTTP_ONLY, admin_streams)
+        .post("/api/admin/compact", HTTP_ONLY, admin_compact)
+        .post("/api/admin/reindex", HTTP_ONLY, admin_reindex)
+        .post("/api/admin/users/:id/reassign", "reassign_user_tasks", admin_reassign_user_tasks)
+        .get("/api/admin/read-only", HTTP_ONLY, admin_read_only)
+        .post("/api/admin/read-only", HTTP_ONLY, admin_set_read_only)
+        .into_parts();
+    let state = AppState { storage, config: config.clone(), routes: Arc::new(routes) };
+
+    let router = router
+        .layer(middleware::from_fn_with_state(state.clone(), reject_writes_when_read_only))
+        .layer(middleware::from_fn_with_state(state.clone(), reject_until_loaded))
+        .layer(middleware::from_fn_with_state(state.clone(), format_timestamps))
+        .layer(middleware::from_fn_with_state(state.clone(), wrap_in_envelope))
+        .layer(middleware::from_fn(answer_conditional_get));
+    with_request_limits(router, &config)
+        .fallback(route_not_found)
+        .with_state(state)
+        // Outside the timestamp rewriting so that sees plain JSON; the default
+        // predicate leaves small bodies and event streams uncompressed
+        .layer(CompressionLayer::new().gzip(config.compression).br(config.compression))
+        .layer(RequestIdLayer::new(request_id_header))
+        .layer(cors)
+}
+
 // HTTP handlers
+#[utoipa::path(post, path = "/api/tasks", tag = "tasks",
+    request_body = protogen::CreateTaskRequest,
+    responses(
+        (status = 200, description = "Task created", body = protogen::CreateTaskResponse),
+        (status = 400, description = "Invalid task"),
+        (status = 429, description = "Assignee's task quota is used up"),
+    ))]
 async fn create_task(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
+    let service = state.task_service();
     let request = match serde_json::from_value(payload) {
         Ok(r) => r,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
 
-    match service.create_task(Request::new(request)).await {
+    match service.create_task(grpc_request(request, &headers)).await {
         Ok(res) => match serde_json::to_value(res.into_inner()) {
             Ok(json) => Json(json).into_response(),
             Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
         },
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/upsert", tag = "tasks",
+    request_body = protogen::CreateTaskRequest,
+    responses(
+        (status = 201, description = "No task had the same natural key, so one was created", body = protogen::UpsertTaskResponse),
+        (status = 200, description = "The existing task with the same natural key", body = protogen::UpsertTaskResponse),
+        (status = 400, description = "Invalid task"),
+        (status = 429, description = "Assignee's task quota is used up"),
+    ))]
+async fn upsert_task(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.upsert_task(grpc_request(request, &headers)).await {
+        Ok(res) => {
+            let res = res.into_inner();
+            let status = if res.created { StatusCode::CREATED } else { StatusCode::OK };
+            match serde_json::to_value(res) {
+                Ok(json) => (status, Json(json)).into_response(),
+                Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+            }
+        }
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
+#[utoipa::path(get, path = "/api/tasks/{id}", tag = "tasks",
+    params(
+        ("id" = String, Path, description = "Task id"),
+        ("include_comments" = Option<bool>, Query, description = "Include the task's comments"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a previous response"),
+    ),
+    responses(
+        (status = 200, description = "The task", body = protogen::GetTaskResponse),
+        (status = 304, description = "Unchanged since the given ETag"),
+        (status = 404, description = "No such task"),
+    ))]
 async fn get_task(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Path(id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
-    let request = protogen::GetTaskRequest { id, include_comments: true };
+    let service = state.task_service();
+    let include_comments = params.get("include_comments").is_some_and(|v| v == "true");
+    let request = protogen::GetTaskRequest { id, include_comments };
 
     match service.get_task(Request::new(request)).await {
         Ok(res) => match serde_json::to_value(res.into_inner()) {
-            Ok(json) => Json(json).into_response(),
+            Ok(json) => conditional_json(json),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/tasks/number/{number}", tag = "tasks",
+    params(
+        ("number" = String, Path, description = "Task number, optionally with the configured prefix"),
+        ("include_comments" = Option<bool>, Query, description = "Include the task's comments"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a previous response"),
+    ),
+    responses(
+        (status = 200, description = "The task", body = protogen::GetTaskResponse),
+        (status = 304, description = "Unchanged since the given ETag"),
+        (status = 400, description = "Not a task number"),
+    ))]
+async fn get_task_by_number(
+    State(state): State<AppState>,
+    Path(number): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let prefix = &state.config.task_number_prefix;
+    let digits = number.strip_prefix(prefix.as_str()).unwrap_or(&number);
+    let Ok(number) = digits.parse::<u64>() else {
+        return (StatusCode::BAD_REQUEST, format!("Not a task number: {}", number)).into_response();
+    };
+    let service = state.task_service();
+    let include_comments = params.get("include_comments").is_some_and(|v| v == "true");
+    let request = protogen::GetTaskByNumberRequest { number, include_comments };
+
+    match service.get_task_by_number(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => conditional_json(json),
             Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
         },
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
+#[utoipa::path(get, path = "/api/tasks", tag = "tasks",
+    params(
+        ("page_size" = Option<i32>, Query, description = "Tasks per page, default 20"),
+        ("page_token" = Option<String>, Query, description = "`next_page_token` of the previous page"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a previous response"),
+    ),
+    responses(
+        (status = 200, description = "A page of tasks", body = protogen::ListTasksResponse),
+        (status = 304, description = "Unchanged since the given ETag"),
+    ))]
 async fn list_tasks(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
+    let service = state.task_service();
+    let filter = match task_filter_from_query(&params) {
+        Ok(filter) => filter,
+        Err(e) => return e.into_response(),
+    };
     let request = protogen::ListTasksRequest {
         page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
         page_token: params.get("page_token").cloned().unwrap_or_default(),
-        filter: None,
+        filter: Some(filter),
         sort: None,
     };
 
     match service.list_tasks(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => conditional_json(json),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-async fn update_task(
-    State(storage): State<Arc<Storage>>,
-    Path(id): Path<String>,
-    Json(payload): Json<Value>,
-) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
-    let mut request: protogen::UpdateTaskRequest = match serde_json::from_value(payload) {
-        Ok(r) => r,
-        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+/// Marks a response `answer_conditional_get` should tag with an ETag
+#[derive(Clone, Copy)]
+struct Conditional;
+
+/// Serves `body` as JSON, to be given an ETag once it's final
+fn conditional_json(body: Value) -> Response {
+    let mut response = Json(body).into_response();
+    response.extensions_mut().insert(Conditional);
+    response
+}
+
+/// Give responses marked `Conditional` a weak ETag hashed from the bytes the
+/// client actually gets, after timestamp formatting and the envelope, or a
+/// bare 304 when the request's `If-None-Match` already names that tag; the
+/// CORS layer adds the `Vary` header those two rewrites call for. Hashing
+/// the whole body means a list's tag changes whenever any task on the page does.
+async fn answer_conditional_get(
+    request: axum::http::Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let if_none_match: Vec<String> = request.headers()
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().to_string())
+        .collect();
+
+    let response = next.run(request).await;
+    if response.extensions().get::<Conditional>().is_none() || response.status() != StatusCode::OK {
+        return response;
+    }
+    let (parts, body) = response.into_parts();
+    let bytes = match collect_body(body).await {
+        Ok(bytes) => bytes,
+        Err(response) => return response,
     };
+    let etag = etag_for(&bytes);
 
-    request.id = id;
+    // If-None-Match uses weak comparison, so `W/` prefixes are ignored
+    let opaque = |tag: &str| tag.trim_start_matches("W/").to_string();
+    let matches = if_none_match.iter().any(|tag| tag == "*" || opaque(tag) == opaque(&etag));
 
-    match service.update_task(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    let mut response = if matches {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        Response::from_parts(parts, axum::body::boxed(Body::from(bytes)))
+    };
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response.headers_mut().insert(header::ETAG, value);
     }
+    response
 }
 
-async fn delete_task(
-    State(storage): State<Arc<Storage>>,
-    Path(id): Path<String>,
-) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
-    let request = protogen::DeleteTaskRequest { id, force: false };
+/// A weak ETag from the SHA-256 of `body`, so tags survive restarts and
+/// upgrades. An envelope's `meta.request_id` is new on every request and is
+/// left out, or enveloped responses would never be answered with a 304.
+fn etag_for(body: &[u8]) -> String {
+    let envelope = serde_json::from_slice::<Value>(body).ok()
+        .and_then(|mut value| {
+            value.get_mut("meta")?.as_object_mut()?.remove("request_id")?;
+            serde_json::to_vec(&value).ok()
+        });
+    let digest = ring::digest::digest(&ring::digest::SHA256, envelope.as_deref().unwrap_or(body));
+    let hex: String = digest.as_ref()[..16].iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("W/\"{}\"", hex)
+}
 
-    match service.delete_task(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
-    }
+/// The caller's own record; credentials live in sessions, not on `User`
+#[utoipa::path(get, path = "/api/me", tag = "me",
+    responses(
+        (status = 200, description = "The signed-in user, under `user`"),
+        (status = 401, description = "No valid bearer token"),
+    ))]
+async fn get_me(CurrentUser(user): CurrentUser) -> impl IntoResponse {
+    Json(json!({ "user": user }))
 }
 
-async fn bulk_update_tasks(
-    State(storage): State<Arc<Storage>>,
+/// Self-service profile edits. Only `full_name`, `preferences` and `profile`
+/// can be changed here, and the nested objects are merged field by field, so
+/// `{"preferences": {"theme": "dark"}}` leaves the other preferences alone.
+#[utoipa::path(patch, path = "/api/me", tag = "me",
+    request_body(content = Object, description = "Any of `full_name`, `preferences` and `profile`; objects are merged into the current values"),
+    responses(
+        (status = 200, description = "The updated user", body = protogen::UpdateUserResponse),
+        (status = 400, description = "A field that can't be changed here"),
+        (status = 401, description = "No valid bearer token"),
+    ))]
+async fn update_me(
+    CurrentUser(user): CurrentUser,
+    State(state): State<AppState>,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
-    let request = match serde_json::from_value(payload) {
-        Ok(r) => r,
+    let Value::Object(changes) = payload else {
+        return (StatusCode::BAD_REQUEST, "Expected a JSON object".to_string()).into_response();
+    };
+    if let Some(field) = changes.keys().find(|key| !matches!(key.as_str(), "full_name" | "preferences" | "profile")) {
+        return (StatusCode::BAD_REQUEST, format!("Field '{}' can't be changed here", field)).into_response();
+    }
+
+    let mut merged = match serde_json::to_value(&user) {
+        Ok(value) => value,
+        Err(e) => return TaskerError::from(e).into_response(),
+    };
+    for (key, change) in changes {
+        match (merged.get_mut(&key), change) {
+            (Some(Value::Object(current)), Value::Object(fields)) => current.extend(fields),
+            (_, change) => merged[key.as_str()] = change,
+        }
+    }
+    let updated: protogen::User = match serde_json::from_value(merged) {
+        Ok(user) => user,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
 
-    match service.bulk_update_tasks(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    let service = state.user_service();
+    let request = protogen::UpdateUserRequest { id: user.id, user: Some(updated) };
+    match service.update_user(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-async fn get_task_analytics(
-    State(storage): State<Arc<Storage>>,
+/// The caller's assigned tasks, with the same filters as a task listing
+#[utoipa::path(get, path = "/api/me/tasks", tag = "me",
+    params(
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a previous response"),
+    ),
+    responses(
+        (status = 200, description = "Tasks assigned to the signed-in user", body = protogen::ListTasksResponse),
+        (status = 304, description = "Unchanged since the given ETag"),
+        (status = 401, description = "No valid bearer token"),
+    ))]
+async fn my_tasks(
+    CurrentUser(user): CurrentUser,
+    State(state): State<AppState>,
     Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    let service = TaskServiceImpl::new(storage);
-    let request = protogen::GetTaskAnalyticsRequest {
-        start_date: None,
-        end_date: None,
-        user_ids: vec![],
-        group_by: params.get("group_by").cloned().unwrap_or_default(),
+    let service = state.task_service();
+    let filter = match task_filter_from_query(&params) {
+        Ok(filter) => filter,
+        Err(e) => return e.into_response(),
+    };
+    let request = protogen::ListTasksRequest {
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+        filter: Some(protogen::TaskFilter { assigned_to: user.id, ..filter }),
+        sort: None,
     };
 
-    match service.get_task_analytics(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    match service.list_tasks(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => conditional_json(json),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-// User handlers (similar pattern)
-
-async fn create_user(
-    State(storage): State<Arc<Storage>>,
+#[utoipa::path(put, path = "/api/tasks/{id}", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body = protogen::UpdateTaskRequest,
+    responses(
+        (status = 200, description = "Task updated", body = protogen::UpdateTaskResponse),
+        (status = 400, description = "Invalid update"),
+        (status = 404, description = "No such task"),
+    ))]
+async fn update_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
-
-    let request = match serde_json::from_value(payload) {
+    let service = state.task_service();
+    let mut request: protogen::UpdateTaskRequest = match serde_json::from_value(payload) {
         Ok(r) => r,
-        Err(e) => return (
-            StatusCode::BAD_REQUEST,
-            format!("Invalid request: {}", e)
-        ).into_response(),
-    };
-
-    let response = match service.create_user(tonic::Request::new(request)).await {
-        Ok(res) => res,
-        Err(e) => return (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("gRPC error: {}", e)
-        ).into_response(),
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
 
-    match serde_json::to_value(response.into_inner()) {
-        Ok(json) => Json(json).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Serialization error: {}", e)
-        ).into_response(),
-    }
-}
+    request.id = id;
 
+    match service.update_task(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/attachments/{id}", tag = "tasks",
+    params(
+        ("id" = String, Path, description = "Attachment id"),
+        ("Range" = Option<String>, Header, description = "A single `bytes=` range"),
+    ),
+    responses(
+        (status = 200, description = "The whole file"),
+        (status = 206, description = "The requested byte range"),
+        (status = 404, description = "No such attachment"),
+        (status = 416, description = "The range lies outside the file"),
+    ))]
+async fn download_attachment(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let Some(attachment) = state.storage.find_attachment(&id).await else {
+        return (StatusCode::NOT_FOUND, "Attachment not found".to_string()).into_response();
+    };
+    let store = AttachmentStore::from_config(&state.config);
+    let len = match store.file_len(&id).await {
+        Ok(len) => len,
+        Err(_) => return (StatusCode::NOT_FOUND, "Attachment file missing".to_string()).into_response(),
+    };
+
+    let range = headers.get(header::RANGE).and_then(|value| value.to_str().ok());
+    let (status, start, end) = match attachments::parse_range(range, len) {
+        ByteRange::Full => (StatusCode::OK, 0, len.saturating_sub(1)),
+        ByteRange::Partial(start, end) => (StatusCode::PARTIAL_CONTENT, start, end),
+        ByteRange::Unsatisfiable => {
+            return (
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{}", len))],
+            ).into_response();
+        }
+    };
+    let body_len = if len == 0 { 0 } else { end - start + 1 };
+
+    let body = match store.read_range(&id, start, body_len).await {
+        Ok(body) => body,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to read attachment: {}", e)).into_response(),
+    };
+
+    let content_type = if attachment.content_type.is_empty() {
+        "application/octet-stream".to_string()
+    } else {
+        attachment.content_type
+    };
+    let mut response_headers = HeaderMap::new();
+    let mut set = |name, value: String| {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response_headers.insert(name, value);
+        }
+    };
+    set(header::CONTENT_TYPE, content_type);
+    set(header::CONTENT_LENGTH, body_len.to_string());
+    set(header::CONTENT_DISPOSITION, attachments::content_disposition(&attachment.filename));
+    // Uploads are never modified in place, so caches can keep them forever
+    set(header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string());
+    set(header::ACCEPT_RANGES, "bytes".to_string());
+    if status == StatusCode::PARTIAL_CONTENT {
+        set(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, len));
+    }
+
+    (status, response_headers, body).into_response()
+}
+
+#[utoipa::path(patch, path = "/api/tasks/{id}/status", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body(content = Object, description = "`{\"status\": \"DONE\"}`; names may omit the `TASK_STATUS_` prefix"),
+    responses(
+        (status = 200, description = "Status changed", body = protogen::UpdateTaskResponse),
+        (status = 400, description = "Unknown status or disallowed transition"),
+        (status = 404, description = "No such task"),
+    ))]
+async fn update_task_status(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let status = payload.get("status")
+        .and_then(Value::as_str)
+        .and_then(|name| enum_param(name, "TASK_STATUS_", protogen::TaskStatus::from_str_name))
+        .filter(|status| *status != protogen::TaskStatus::Unspecified);
+    let Some(status) = status else {
+        return (StatusCode::BAD_REQUEST, "Unknown or missing status".to_string()).into_response();
+    };
+
+    let request = protogen::UpdateTaskStatusRequest { id, status: status as i32 };
+    match service.update_task_status(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(delete, path = "/api/tasks/{id}", tag = "tasks",
+    params(
+        ("id" = String, Path, description = "Task id"),
+        ("x-idempotent-delete" = Option<bool>, Header, description = "Overrides the server's idempotent delete setting"),
+    ),
+    responses(
+        (status = 200, description = "Task deleted", body = protogen::DeleteTaskResponse),
+        (status = 204, description = "Task was already gone and deletes are idempotent"),
+        (status = 404, description = "No such task", body = protogen::DeleteTaskResponse),
+    ))]
+async fn delete_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let idempotent = match headers.get(IDEMPOTENT_DELETE_HEADER).map(|value| value.to_str().map(str::parse::<bool>)) {
+        Some(Ok(Ok(idempotent))) => idempotent,
+        Some(_) => return (StatusCode::BAD_REQUEST, format!("{} must be true or false", IDEMPOTENT_DELETE_HEADER)).into_response(),
+        None => state.config.idempotent_deletes,
+    };
+    let service = state.task_service();
+    let request = protogen::DeleteTaskRequest { id, force: !state.config.http_soft_delete };
+
+    match service.delete_task(grpc_request(request, &headers)).await {
+        Ok(res) => {
+            let res = res.into_inner();
+            if !res.success && idempotent {
+                return StatusCode::NO_CONTENT.into_response();
+            }
+            let status = if res.success { StatusCode::OK } else { StatusCode::NOT_FOUND };
+            match serde_json::to_value(res) {
+                Ok(json) => (status, Json(json)).into_response(),
+                Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+            }
+        }
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/{id}/archive", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    responses(
+        (status = 200, description = "Task moved to the archive", body = protogen::UpdateTaskResponse),
+        (status = 404, description = "No such active task"),
+    ))]
+async fn archive_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::ArchiveTaskRequest { id };
+
+    match service.archive_task(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/tasks/archived", tag = "tasks",
+    params(
+        ("page_size" = Option<i32>, Query, description = "Tasks per page, 20 if unset"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+    ),
+    responses((status = 200, description = "Archived tasks, most recently archived first", body = protogen::ListTasksResponse)))]
+async fn list_archived_tasks(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::ListArchivedTasksRequest {
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+    };
+
+    match service.list_archived_tasks(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/archived/{id}/restore", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    responses(
+        (status = 200, description = "Task moved back to the active tasks", body = protogen::UpdateTaskResponse),
+        (status = 404, description = "No such archived task"),
+    ))]
+async fn restore_archived_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::RestoreArchivedTaskRequest { id };
+
+    match service.restore_archived_task(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/{id}/clone", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body(content = Option<protogen::TaskOverrides>, description = "Fields to change on the copy"),
+    responses(
+        (status = 200, description = "The copy, starting in Todo", body = protogen::CreateTaskResponse),
+        (status = 404, description = "No such task"),
+    ))]
+async fn clone_task(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    payload: Option<Json<Value>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let overrides = match payload.map(|Json(value)| serde_json::from_value(value)).transpose() {
+        Ok(o) => o,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    let request = protogen::CloneTaskRequest { task_id, overrides };
+
+    match service.clone_task(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+/// Merge the task named by `source_id` in the body into the task in the path
+#[utoipa::path(post, path = "/api/tasks/{id}/merge", tag = "tasks",
+    params(("id" = String, Path, description = "Task the others are merged into")),
+    request_body = protogen::MergeTasksRequest,
+    responses(
+        (status = 200, description = "The merged task", body = protogen::UpdateTaskResponse),
+        (status = 400, description = "A task can't be merged into itself"),
+        (status = 404, description = "No such task"),
+    ))]
+async fn merge_tasks(
+    State(state): State<AppState>,
+    Path(target_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let mut request: protogen::MergeTasksRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.target_id = target_id;
+
+    match service.merge_tasks(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/{id}/snooze", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body = protogen::SnoozeTaskRequest,
+    responses(
+        (status = 200, description = "The snoozed task", body = protogen::UpdateTaskResponse),
+        (status = 404, description = "No such task"),
+    ))]
+async fn snooze_task(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let mut request: protogen::SnoozeTaskRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.id = task_id;
+
+    match service.snooze_task(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/{id}/reminders", tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body = protogen::SetReminderRequest,
+    responses(
+        (status = 201, description = "Reminder scheduled", body = protogen::SetReminderResponse),
+        (status = 404, description = "No such task"),
+    ))]
+async fn set_reminder(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let mut request: protogen::SetReminderRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.task_id = task_id;
+
+    match service.set_reminder(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => (StatusCode::CREATED, Json(json)).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/{id}/comments", tag = "comments",
+    params(("id" = String, Path, description = "Task id")),
+    request_body = protogen::AddCommentRequest,
+    responses(
+        (status = 201, description = "Comment added", body = protogen::CommentResponse),
+        (status = 404, description = "No such task"),
+    ))]
+async fn add_comment(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let mut request: protogen::AddCommentRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.task_id = task_id;
+
+    match service.add_comment(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => (StatusCode::CREATED, Json(json)).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/tasks/{id}/comments", tag = "comments",
+    params(
+        ("id" = String, Path, description = "Task id"),
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+        ("order" = Option<String>, Query, description = "`oldest` for oldest first; newest first otherwise"),
+    ),
+    responses(
+        (status = 200, description = "A page of the task's comments", body = protogen::ListTaskCommentsResponse),
+        (status = 404, description = "No such task"),
+    ))]
+async fn list_task_comments(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::ListTaskCommentsRequest {
+        task_id,
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+        oldest_first: params.get("order").is_some_and(|v| v == "oldest"),
+    };
+
+    match service.list_task_comments(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(put, path = "/api/tasks/{id}/comments/{comment_id}", tag = "comments",
+    params(("id" = String, Path, description = "Task id"), ("comment_id" = String, Path, description = "Comment id")),
+    request_body = protogen::EditCommentRequest,
+    responses(
+        (status = 200, description = "The edited comment", body = protogen::CommentResponse),
+        (status = 403, description = "Only the author or an admin can edit a comment"),
+        (status = 404, description = "No such task or comment"),
+    ))]
+async fn edit_comment(
+    State(state): State<AppState>,
+    Path((task_id, comment_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let mut request: protogen::EditCommentRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.task_id = task_id;
+    request.comment_id = comment_id;
+
+    match service.edit_comment(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(delete, path = "/api/tasks/{id}/comments/{comment_id}", tag = "comments",
+    params(("id" = String, Path, description = "Task id"), ("comment_id" = String, Path, description = "Comment id")),
+    responses(
+        (status = 200, description = "Comment deleted", body = protogen::DeleteCommentResponse),
+        (status = 403, description = "Only the author or an admin can delete a comment"),
+        (status = 404, description = "No such task or comment"),
+    ))]
+async fn delete_comment(
+    State(state): State<AppState>,
+    Path((task_id, comment_id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::DeleteCommentRequest { task_id, comment_id };
+
+    match service.delete_comment(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(put, path = "/api/tasks/bulk", tag = "tasks",
+    request_body = protogen::BulkUpdateTasksRequest,
+    responses(
+        (status = 200, description = "Every task was updated"),
+        (status = 207, description = "Some tasks failed; each result carries its own status"),
+    ))]
+async fn bulk_update_tasks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.bulk_update_tasks(grpc_request(request, &headers)).await {
+        Ok(res) => bulk_update_response(res.into_inner()),
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tasks/bulk/tags", tag = "tasks",
+    request_body = protogen::BulkTagTasksRequest,
+    responses(
+        (status = 200, description = "Every task was tagged"),
+        (status = 207, description = "Some tasks failed; each result carries its own status"),
+    ))]
+async fn bulk_tag_tasks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.bulk_tag_tasks(grpc_request(request, &headers)).await {
+        Ok(res) => bulk_update_response(res.into_inner()),
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(put, path = "/api/tasks/bulk/by-filter", tag = "tasks",
+    request_body = protogen::BulkUpdateTasksByFilterRequest,
+    responses(
+        (status = 200, description = "Every matching task was updated"),
+        (status = 207, description = "Some tasks failed; each result carries its own status"),
+    ))]
+async fn bulk_update_tasks_by_filter(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.bulk_update_tasks_by_filter(grpc_request(request, &headers)).await {
+        Ok(res) => bulk_update_response(res.into_inner()),
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+fn bulk_update_response(res: protogen::BulkUpdateTasksResponse) -> Response {
+    // Multi-status: each id carries the status it would have had on its own
+    let results: Vec<Value> = res.results.iter()
+        .map(|result| {
+            let status = match protogen::BulkUpdateError::try_from(result.error) {
+                _ if result.success => StatusCode::OK,
+                Ok(protogen::BulkUpdateError::NotFound) => StatusCode::NOT_FOUND,
+                Ok(protogen::BulkUpdateError::Invalid) => StatusCode::BAD_REQUEST,
+                Ok(protogen::BulkUpdateError::QuotaExceeded) => StatusCode::TOO_MANY_REQUESTS,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            json!({
+                "task_id": result.task_id,
+                "status": status.as_u16(),
+                "error": result.error_message,
+            })
+        })
+        .collect();
+    let status = if res.failed_ids.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+    (status, Json(json!({
+        "updated_count": res.updated_count,
+        "failed_ids": res.failed_ids,
+        "message": res.message,
+        "results": results,
+        "dry_run": res.dry_run,
+    }))).into_response()
+}
+
+#[utoipa::path(get, path = "/api/tasks/search", tag = "tasks",
+    params(
+        ("q" = String, Query, description = "Words to look for in titles and descriptions"),
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+        ("fuzzy" = Option<bool>, Query, description = "Allow near misses"),
+        ("max_distance" = Option<u32>, Query, description = "Edits allowed per word when fuzzy"),
+    ),
+    responses(
+        (status = 200, description = "Matching tasks", body = protogen::SearchTasksResponse),
+    ))]
+async fn search_tasks(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::SearchTasksRequest {
+        query: params.get("q").cloned().unwrap_or_default(),
+        filters: None,
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+        fuzzy: params.get("fuzzy").is_some_and(|v| v == "true"),
+        max_distance: params.get("max_distance").and_then(|s| s.parse().ok()).unwrap_or(0),
+    };
+
+    match service.search_tasks(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+/// Task counts and hours per assignee, optionally limited to the
+/// comma-separated `user_ids`
+#[utoipa::path(get, path = "/api/tasks/workload", tag = "analytics",
+    params(("user_ids" = Option<String>, Query, description = "Comma-separated user ids to report on")),
+    responses((status = 200, description = "Workload per assignee", body = protogen::GetWorkloadResponse)))]
+async fn get_workload(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::GetWorkloadRequest {
+        user_ids: params.get("user_ids")
+            .map(|ids| ids.split(',').map(str::trim).filter(|id| !id.is_empty()).map(String::from).collect())
+            .unwrap_or_default(),
+    };
+
+    match service.get_workload(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+/// Tasks created or completed per day or week, with empty buckets included
+#[utoipa::path(get, path = "/api/tasks/timeseries", tag = "analytics",
+    params(
+        ("metric" = String, Query, description = "`created` or `completed`"),
+        ("from" = Option<String>, Query, description = "First day, YYYY-MM-DD (UTC); defaults to 29 days before `to`"),
+        ("to" = Option<String>, Query, description = "Last day, YYYY-MM-DD (UTC); defaults to today"),
+        ("bucket" = Option<String>, Query, description = "`day` (default) or `week`"),
+    ),
+    responses((status = 200, description = "Counts per bucket", body = protogen::GetTaskTimeseriesResponse)))]
+async fn get_task_timeseries(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let metric = match params.get("metric") {
+        Some(name) => match enum_param(name, "TIMESERIES_METRIC_", protogen::TimeseriesMetric::from_str_name) {
+            Some(metric) => metric,
+            None => return TaskerError::Validation(format!("Unknown metric: {}", name)).into_response(),
+        },
+        None => return TaskerError::Validation("metric is required".to_string()).into_response(),
+    };
+    let bucket = match params.get("bucket") {
+        Some(name) => match enum_param(name, "TIMESERIES_BUCKET_", protogen::TimeseriesBucket::from_str_name) {
+            Some(bucket) => bucket,
+            None => return TaskerError::Validation(format!("Unknown bucket: {}", name)).into_response(),
+        },
+        None => protogen::TimeseriesBucket::Day,
+    };
+    let request = protogen::GetTaskTimeseriesRequest {
+        metric: metric as i32,
+        from: params.get("from").cloned().unwrap_or_default(),
+        to: params.get("to").cloned().unwrap_or_default(),
+        bucket: bucket as i32,
+    };
+
+    match service.get_task_timeseries(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/tasks/analytics", tag = "analytics",
+    params(
+        ("group_by" = Option<String>, Query, description = "Grouping for the breakdowns"),
+        ("include_archived" = Option<bool>, Query, description = "Count archived tasks too; the server's default if unset"),
+    ),
+    responses((status = 200, description = "Task analytics", body = protogen::GetTaskAnalyticsResponse)))]
+async fn get_task_analytics(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let include_archived = match params.get("include_archived").map(|value| value.parse::<bool>()) {
+        Some(Ok(include)) => Some(include),
+        Some(Err(_)) => return TaskerError::Validation("include_archived must be true or false".to_string()).into_response(),
+        None => None,
+    };
+    let service = state.task_service();
+    let request = protogen::GetTaskAnalyticsRequest {
+        start_date: None,
+        end_date: None,
+        user_ids: vec![],
+        group_by: params.get("group_by").cloned().unwrap_or_default(),
+        include_archived,
+    };
+
+    match service.get_task_analytics(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/users/{id}/views", tag = "views",
+    params(("id" = String, Path, description = "User id")),
+    request_body = protogen::CreateSavedViewRequest,
+    responses(
+        (status = 200, description = "View saved", body = protogen::CreateSavedViewResponse),
+        (status = 403, description = "Only the user or an admin can use these saved views"),
+    ))]
+async fn create_saved_view(
+    CurrentUser(caller): CurrentUser,
+    State(state): State<AppState>,
+    Path(user_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    if caller.id != user_id && !auth::is_admin(&caller) {
+        return (StatusCode::FORBIDDEN, "Only the user or an admin can use these saved views".to_string()).into_response();
+    }
+    let service = state.task_service();
+    let mut request: protogen::CreateSavedViewRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    request.user_id = user_id;
+
+    match service.create_saved_view(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/users/{id}/views", tag = "views",
+    params(("id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "The user's saved views", body = protogen::ListSavedViewsResponse),
+        (status = 403, description = "Only the user or an admin can use these saved views"),
+    ))]
+async fn list_saved_views(
+    CurrentUser(caller): CurrentUser,
+    State(state): State<AppState>,
+    Path(user_id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if caller.id != user_id && !auth::is_admin(&caller) {
+        return (StatusCode::FORBIDDEN, "Only the user or an admin can use these saved views".to_string()).into_response();
+    }
+    let service = state.task_service();
+    let request = protogen::ListSavedViewsRequest { user_id };
+
+    match service.list_saved_views(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/users/{id}/views/{view_id}/tasks", tag = "views",
+    params(
+        ("id" = String, Path, description = "User id"),
+        ("view_id" = String, Path, description = "Saved view id"),
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "Tasks the view's filter selects", body = protogen::ListTasksResponse),
+        (status = 403, description = "Only the user or an admin can use these saved views"),
+        (status = 404, description = "No such view"),
+    ))]
+async fn apply_saved_view(
+    CurrentUser(caller): CurrentUser,
+    State(state): State<AppState>,
+    Path((user_id, view_id)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if caller.id != user_id && !auth::is_admin(&caller) {
+        return (StatusCode::FORBIDDEN, "Only the user or an admin can use these saved views".to_string()).into_response();
+    }
+    let service = state.task_service();
+    let request = protogen::ApplySavedViewRequest {
+        user_id,
+        view_id,
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+    };
+
+    match service.apply_saved_view(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/tags", tag = "tags",
+    params(("prefix" = Option<String>, Query, description = "Only tags starting with this")),
+    responses(
+        (status = 200, description = "Tags with their task counts", body = protogen::ListTagsResponse),
+    ))]
+async fn list_tags(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = protogen::ListTagsRequest {
+        prefix: params.get("prefix").cloned().unwrap_or_default(),
+    };
+
+    match service.list_tags(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/tags/rename", tag = "tags",
+    request_body = protogen::RenameTagRequest,
+    responses(
+        (status = 200, description = "Tag renamed on every task", body = protogen::RenameTagResponse),
+    ))]
+async fn rename_tag(
+    State(state): State<AppState>,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.task_service();
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.rename_tag(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+// User handlers (similar pattern)
+
+#[utoipa::path(post, path = "/api/users", tag = "users",
+    request_body = protogen::CreateUserRequest,
+    responses(
+        (status = 200, description = "User created", body = protogen::CreateUserResponse),
+        (status = 400, description = "Invalid user"),
+        (status = 409, description = "Username or email already taken"),
+    ))]
+async fn create_user(
+    State(state): State<AppState>,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.user_service();
+
+    let request = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid request: {}", e)
+        ).into_response(),
+    };
+
+    let response = match service.create_user(tonic::Request::new(request)).await {
+        Ok(res) => res,
+        Err(e) => return TaskerError::from(e).into_response(),
+    };
+
+    match serde_json::to_value(response.into_inner()) {
+        Ok(json) => Json(json).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Serialization error: {}", e)
+        ).into_response(),
+    }
+}
+
+/// Admin-only bulk sign-up from a CSV with a header row naming `username`,
+/// `email` and `password` columns, plus optional `full_name` and `role`.
+/// Each data row gets its own result, keyed by its line in the file.
+#[utoipa::path(post, path = "/api/users/import", tag = "users",
+    params(("format" = Option<String>, Query, description = "Only `csv` is supported")),
+    request_body(content = String, content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Every row created a user"),
+        (status = 207, description = "Some rows failed; each result carries its own status"),
+        (status = 400, description = "Unreadable CSV or unsupported format"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn import_users(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let format = params.get("format").map_or("csv", String::as_str);
+    if !format.eq_ignore_ascii_case("csv") {
+        return (StatusCode::BAD_REQUEST, format!("Unsupported import format: {}", format)).into_response();
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(body.as_bytes());
+    let columns = match reader.headers() {
+        Ok(columns) => columns.clone(),
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid CSV: {}", e)).into_response(),
+    };
+    let column = |name: &str| columns.iter().position(|column| column.eq_ignore_ascii_case(name));
+    let (Some(username), Some(email), Some(password)) = (column("username"), column("email"), column("password")) else {
+        return (StatusCode::BAD_REQUEST, "CSV must have username, email and password columns".to_string()).into_response();
+    };
+    let (full_name, role) = (column("full_name"), column("role"));
+
+    // Rows that don't parse are reported alongside the service's results
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let row = match &record {
+            Ok(record) => record.position().map_or(0, |position| position.line()),
+            Err(e) => e.position().map_or(0, |position| position.line()),
+        };
+        let parsed = record
+            .map_err(|e| format!("Invalid CSV row: {}", e))
+            .and_then(|record| {
+                let field = |index: Option<usize>| index.and_then(|index| record.get(index)).unwrap_or_default().to_string();
+                let role = match field(role) {
+                    name if name.is_empty() => protogen::UserRole::Unspecified,
+                    name => enum_param(&name, "USER_ROLE_", protogen::UserRole::from_str_name)
+                        .ok_or_else(|| format!("Unknown role: {}", name))?,
+                };
+                Ok(protogen::CreateUserRequest {
+                    username: field(Some(username)),
+                    email: field(Some(email)),
+                    password: field(Some(password)),
+                    full_name: field(full_name),
+                    role: role as i32,
+                    preferences: None,
+                })
+            });
+        rows.push((row, parsed));
+    }
+
+    let request = protogen::BatchCreateUsersRequest {
+        users: rows.iter().filter_map(|(_, parsed)| parsed.as_ref().ok().cloned()).collect(),
+    };
+    let response = match state.user_service().batch_create_users(grpc_request(request, &headers)).await {
+        Ok(res) => res.into_inner(),
+        Err(e) => return TaskerError::from(e).into_response(),
+    };
+
+    let mut created = response.results.into_iter();
+    let results: Vec<Value> = rows.into_iter()
+        .map(|(row, parsed)| {
+            let (status, error, user) = match parsed {
+                Err(message) => (StatusCode::BAD_REQUEST, message, None),
+                Ok(_) => match created.next() {
+                    Some(result) if result.success => (StatusCode::CREATED, String::new(), result.user),
+                    Some(result) => {
+                        let status = match protogen::BatchCreateUserError::try_from(result.error) {
+                            Ok(protogen::BatchCreateUserError::Invalid) => StatusCode::BAD_REQUEST,
+                            Ok(protogen::BatchCreateUserError::AlreadyExists)
+                            | Ok(protogen::BatchCreateUserError::Duplicate) => StatusCode::CONFLICT,
+                            Ok(protogen::BatchCreateUserError::LimitReached) => StatusCode::TOO_MANY_REQUESTS,
+                            _ => StatusCode::INTERNAL_SERVER_ERROR,
+                        };
+                        (status, result.error_message, None)
+                    }
+                    None => (StatusCode::INTERNAL_SERVER_ERROR, "No result for row".to_string(), None),
+                },
+            };
+            json!({
+                "row": row,
+                "status": status.as_u16(),
+                "error": error,
+                "user": user,
+            })
+        })
+        .collect();
+
+    let status = if results.iter().all(|result| result["status"] == StatusCode::CREATED.as_u16()) {
+        StatusCode::OK
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+    (status, Json(json!({
+        "created_count": response.created_count,
+        "failed_count": results.len() as u64 - response.created_count,
+        "results": results,
+    }))).into_response()
+}
+
+#[utoipa::path(get, path = "/api/users/{id}", tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "The user", body = protogen::GetUserResponse),
+        (status = 404, description = "No such user"),
+    ))]
 async fn get_user(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
+    let service = state.user_service();
     let request = protogen::GetUserRequest { id };
 
-    match service.get_user(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    match service.get_user(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/users", tag = "users",
+    params(
+        ("page_size" = Option<i32>, Query, description = "Users per page, default 20"),
+        ("page_token" = Option<String>, Query, description = "`next_page_token` of the previous page"),
+        ("active_only" = Option<bool>, Query, description = "Skip deactivated users, default true"),
+    ),
+    responses((status = 200, description = "A page of users", body = protogen::ListUsersResponse)))]
+async fn list_users(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.user_service();
+    let request = protogen::ListUsersRequest {
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+        role: 0,
+        active_only: params.get("active_only").is_none_or(|v| v == "true"),
+    };
+
+    match service.list_users(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(put, path = "/api/users/{id}", tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    request_body = protogen::UpdateUserRequest,
+    responses(
+        (status = 200, description = "User updated", body = protogen::UpdateUserResponse),
+        (status = 400, description = "Invalid update"),
+        (status = 404, description = "No such user"),
+    ))]
+async fn update_user(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.user_service();
+    let mut request: protogen::UpdateUserRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    request.id = id;
+
+    match service.update_user(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(delete, path = "/api/users/{id}", tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    responses((status = 200, description = "Deletion outcome", body = protogen::DeleteUserResponse)))]
+/// Delete a user; `?purge=true` (admins only) also scrubs them from every
+/// task, comment and project, and `reassign_to` hands their tasks on
+async fn delete_user(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let service = state.user_service();
+    let request = protogen::DeleteUserRequest {
+        id,
+        purge: params.get("purge").is_some_and(|value| value == "true"),
+        reassign_to: params.get("reassign_to").cloned().unwrap_or_default(),
+    };
+
+    match service.delete_user(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/projects", tag = "projects",
+    request_body = protogen::CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created", body = protogen::ProjectResponse),
+        (status = 400, description = "Invalid project"),
+    ))]
+async fn create_project(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.project_service();
+    let request: protogen::CreateProjectRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+
+    match service.create_project(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => (StatusCode::CREATED, Json(json)).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-async fn list_users(
-    State(storage): State<Arc<Storage>>,
+#[utoipa::path(get, path = "/api/projects", tag = "projects",
+    params(
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "A page of projects", body = protogen::ListProjectsResponse),
+    ))]
+async fn list_projects(
+    State(state): State<AppState>,
     Query(params): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
-    let request = protogen::ListUsersRequest {
+    let service = state.project_service();
+    let request = protogen::ListProjectsRequest {
         page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
         page_token: params.get("page_token").cloned().unwrap_or_default(),
-        role: 0,
-        active_only: params.get("active_only").map_or(true, |v| v == "true"),
     };
 
-    match service.list_users(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    match service.list_projects(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-async fn update_user(
-    State(storage): State<Arc<Storage>>,
+#[utoipa::path(get, path = "/api/projects/{id}", tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "The project", body = protogen::GetProjectResponse),
+        (status = 404, description = "No such project"),
+    ))]
+async fn get_project(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let service = state.project_service();
+    let request = protogen::GetProjectRequest { id };
+
+    match service.get_project(Request::new(request)).await {
+        Ok(res) if !res.get_ref().found => TaskerError::NotFound("Project not found".to_string()).into_response(),
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(put, path = "/api/projects/{id}", tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    request_body = protogen::UpdateProjectRequest,
+    responses(
+        (status = 200, description = "The updated project", body = protogen::ProjectResponse),
+        (status = 404, description = "No such project"),
+    ))]
+async fn update_project(
+    State(state): State<AppState>,
     Path(id): Path<String>,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
-    let mut request: protogen::UpdateUserRequest = match serde_json::from_value(payload) {
+    let service = state.project_service();
+    let mut request: protogen::UpdateProjectRequest = match serde_json::from_value(payload) {
         Ok(r) => r,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
-
     request.id = id;
 
-    match service.update_user(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    match service.update_project(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
-async fn delete_user(
-    State(storage): State<Arc<Storage>>,
+#[utoipa::path(delete, path = "/api/projects/{id}", tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Project deleted", body = protogen::DeleteProjectResponse),
+        (status = 404, description = "No such project"),
+    ))]
+async fn delete_project(
+    State(state): State<AppState>,
     Path(id): Path<String>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
-    let request = protogen::DeleteUserRequest { id };
+    let service = state.project_service();
+    let request = protogen::DeleteProjectRequest { id };
 
-    match service.delete_user(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("gRPC error: {}", e)).into_response(),
+    match service.delete_project(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/projects/{id}/tasks", tag = "projects",
+    params(
+        ("id" = String, Path, description = "Project id"),
+        ("page_size" = Option<i32>, Query, description = "Results per page"),
+        ("page_token" = Option<String>, Query, description = "Token from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "A page of the project's tasks", body = protogen::ListTasksResponse),
+        (status = 404, description = "No such project"),
+    ))]
+async fn list_project_tasks(
+    State(state): State<AppState>,
+    Path(project_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let service = state.project_service();
+    let request = protogen::ListProjectTasksRequest {
+        project_id,
+        page_size: params.get("page_size").and_then(|s| s.parse().ok()).unwrap_or(20),
+        page_token: params.get("page_token").cloned().unwrap_or_default(),
+    };
+
+    match service.list_project_tasks(Request::new(request)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
     }
 }
 
+#[utoipa::path(post, path = "/api/auth/login", tag = "auth",
+    request_body = protogen::LoginRequest,
+    responses(
+        (status = 200, description = "Access and refresh tokens", body = protogen::LoginResponse),
+        (status = 401, description = "Wrong username or password"),
+    ))]
 async fn login(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
+    let service = state.user_service();
     let request = match serde_json::from_value(payload) {
         Ok(r) => r,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
 
     match service.login(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
         Err(e) => (StatusCode::UNAUTHORIZED, format!("Login failed: {}", e)).into_response(),
     }
 }
 
+#[utoipa::path(post, path = "/api/auth/refresh", tag = "auth",
+    request_body = protogen::RefreshTokenRequest,
+    responses(
+        (status = 200, description = "A new access token", body = protogen::RefreshTokenResponse),
+        (status = 401, description = "Refresh token is invalid or expired"),
+    ))]
 async fn refresh_token(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
+    let service = state.user_service();
     let request = match serde_json::from_value(payload) {
         Ok(r) => r,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
     };
 
     match service.refresh_token(Request::new(request)).await {
-        Ok(res) => Json(serde_json::to_value(res.into_inner()).unwrap()).into_response(),
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
         Err(e) => (StatusCode::UNAUTHORIZED, format!("Token refresh failed: {}", e)).into_response(),
     }
 }
 
+#[utoipa::path(post, path = "/api/auth/logout", tag = "auth",
+    request_body = protogen::LogoutRequest,
+    responses((status = 200, description = "Session ended")))]
 async fn logout(
-    State(storage): State<Arc<Storage>>,
+    State(state): State<AppState>,
     Json(payload): Json<Value>,
 ) -> impl IntoResponse {
-    let service = UserServiceImpl::new(storage);
+    let service = state.user_service();
     let request: protogen::LogoutRequest = match serde_json::from_value(payload) {
         Ok(r) => r,
         Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
@@ -394,10 +2617,967 @@ async fn logout(
 }
 
 
+async fn route_not_found(
+    State(state): State<AppState>,
+    method: Method,
+    uri: axum::http::Uri,
+) -> impl IntoResponse {
+    let mut body = json!({
+        "error": "not_found",
+        "message": format!("No route for {} {}", method, uri.path()),
+    });
+    if state.config.expose_routes {
+        body["routes"] = json!(*state.routes);
+    }
+    (StatusCode::NOT_FOUND, Json(body))
+}
+
+async fn openapi_spec() -> impl IntoResponse {
+    Json(ApiDoc::openapi())
+}
+
+/// Swagger UI for the spec, loaded from a CDN so the server doesn't bundle it
+async fn swagger_ui() -> impl IntoResponse {
+    axum::response::Html(SWAGGER_UI_PAGE)
+}
+
+const SWAGGER_UI_PAGE: &str = r##"<!doctype html>
+<html>
+<head>
+  <title>Tasker API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>SwaggerUIBundle({ url: "/api/openapi.json", dom_id: "#swagger-ui" });</script>
+</body>
+</html>
+"##;
+
+#[utoipa::path(get, path = "/api/routes", tag = "meta",
+    responses(
+        (status = 200, description = "Every path and the methods it answers"),
+        (status = 404, description = "Route listing is turned off"),
+    ))]
+async fn list_routes(
+    State(state): State<AppState>,
+    method: Method,
+    uri: axum::http::Uri,
+) -> Response {
+    if !state.config.expose_routes {
+        return route_not_found(State(state), method, uri).await.into_response();
+    }
+    Json(json!({ "routes": *state.routes })).into_response()
+}
+
+#[utoipa::path(get, path = "/api/health", tag = "meta",
+    responses((status = 200, description = "The server is up")))]
 async fn health_check() -> Json<Value> {
     Json(serde_json::json!({
         "healthy": true,
         "version": "0.1.0",
         "timestamp": chrono::Utc::now().to_rfc3339()
     }))
-}
\ No newline at end of file
+}
+
+/// 503 until the storage file has been loaded and indexed, for load
+/// balancers deciding whether to route traffic here yet
+#[utoipa::path(get, path = "/api/health/ready", tag = "meta",
+    responses(
+        (status = 200, description = "Storage is loaded"),
+        (status = 503, description = "Storage is still loading"),
+    ))]
+async fn readiness_check(State(state): State<AppState>) -> impl IntoResponse {
+    let ready = state.storage.is_loaded();
+    let status = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(json!({ "ready": ready })))
+}
+
+#[utoipa::path(get, path = "/api/admin/stats", tag = "admin",
+    responses(
+        (status = 200, description = "Record and index counts"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_stats(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    Json(state.storage.stats().await)
+}
+
+#[utoipa::path(get, path = "/api/admin/streams", tag = "admin",
+    responses(
+        (status = 200, description = "Open event streams"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_streams(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let streams = state.storage.events().active_streams();
+    Json(json!({
+        "count": streams.len(),
+        "streams": streams,
+    }))
+}
+
+#[utoipa::path(post, path = "/api/admin/compact", tag = "admin",
+    params(("older_than_secs" = Option<u64>, Query, description = "Purge tombstones older than this; defaults to the configured retention")),
+    responses(
+        (status = 200, description = "What compaction removed"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_compact(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let max_age = params.get("older_than_secs")
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(state.config.tombstone_retention_secs);
+
+    match state.storage.compact(max_age, &AttachmentStore::from_config(&state.config)).await {
+        Ok(report) => Json(report).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Compaction failed: {}", e)).into_response(),
+    }
+}
+
+/// Download everything stored about a user, for the user themselves or an admin
+#[utoipa::path(get, path = "/api/users/{id}/export", tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Everything stored about the user, as a JSON download"),
+        (status = 403, description = "Only the user or an admin can export this data"),
+        (status = 404, description = "No such user"),
+    ))]
+async fn export_user(
+    CurrentUser(caller): CurrentUser,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    if caller.id != id && !auth::is_admin(&caller) {
+        return (StatusCode::FORBIDDEN, "Only the user or an admin can export this data".to_string()).into_response();
+    }
+    let Some(export) = state.storage.export_user(&id).await else {
+        return (StatusCode::NOT_FOUND, "User not found".to_string()).into_response();
+    };
+    let disposition = attachments::content_disposition(&format!("user-{}-export.json", id));
+    ([(header::CONTENT_DISPOSITION, disposition)], Json(export)).into_response()
+}
+
+#[utoipa::path(post, path = "/api/admin/reindex", tag = "admin",
+    responses(
+        (status = 200, description = "Indices rebuilt, with the number of entries repaired"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_reindex(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    match state.storage.reindex().await {
+        Ok(report) => {
+            info!(repaired = report.repaired_entries, duration_ms = report.duration_ms, "Rebuilt indices");
+            Json(report).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Reindex failed: {}", e)).into_response(),
+    }
+}
+
+#[utoipa::path(post, path = "/api/admin/users/{id}/reassign", tag = "admin",
+    params(("id" = String, Path, description = "User whose tasks are handed over")),
+    request_body = protogen::ReassignUserTasksRequest,
+    responses(
+        (status = 200, description = "Tasks reassigned", body = protogen::ReassignUserTasksResponse),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_reassign_user_tasks(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+    Path(from_user_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let service = state.user_service();
+    let mut request: protogen::ReassignUserTasksRequest = match serde_json::from_value(payload) {
+        Ok(r) => r,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)).into_response(),
+    };
+    request.from_user_id = from_user_id;
+
+    match service.reassign_user_tasks(grpc_request(request, &headers)).await {
+        Ok(res) => match serde_json::to_value(res.into_inner()) {
+            Ok(json) => Json(json).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Serialization error: {}", e)).into_response(),
+        },
+        Err(e) => TaskerError::from(e).into_response(),
+    }
+}
+
+#[utoipa::path(get, path = "/api/admin/read-only", tag = "admin",
+    responses(
+        (status = 200, description = "Whether writes are refused"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_read_only(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    Json(json!({ "read_only": state.storage.is_read_only() }))
+}
+
+#[utoipa::path(post, path = "/api/admin/read-only", tag = "admin",
+    request_body(content = Object, description = "`{\"enabled\": bool}`"),
+    responses(
+        (status = 200, description = "The new setting"),
+        (status = 403, description = "Admins only"),
+    ))]
+async fn admin_set_read_only(
+    _admin: RequireAdmin,
+    State(state): State<AppState>,
+    Json(payload): Json<Value>,
+) -> impl IntoResponse {
+    let Some(enabled) = payload.get("enabled").and_then(Value::as_bool) else {
+        return (StatusCode::BAD_REQUEST, "Expected {\"enabled\": bool}".to_string()).into_response();
+    };
+    state.storage.set_read_only(enabled);
+    info!("Read-only mode {}", if enabled { "enabled" } else { "disabled" });
+    Json(json!({ "read_only": enabled })).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request as HttpRequest;
+    use backend::storage::Session;
+    use backend::unknown_method::known_endpoints;
+    use tower::ServiceExt;
+
+    async fn app_with(config: Config) -> Router {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        http_app(storage, Arc::new(config))
+    }
+
+    async fn send(app: &Router, method: Method, uri: &str, body: Value) -> Response {
+        let request = HttpRequest::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap().into_response()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        let mut body = response.into_body();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.data().await {
+            bytes.extend_from_slice(&chunk.unwrap());
+        }
+        bytes
+    }
+
+    async fn json_body(response: Response) -> Value {
+        serde_json::from_slice(&body_bytes(response).await).unwrap()
+    }
+
+    fn new_user(username: &str) -> Value {
+        json!({ "username": username, "email": format!("{}@example.com", username), "password": "Passw0rd!long" })
+    }
+
+    #[tokio::test]
+    async fn handler_slower_than_the_timeout_returns_504() {
+        let config = Config { request_timeout_secs: 1, ..Default::default() };
+        let slow = Router::new().route("/slow", axum::routing::get(|| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            "done"
+        }));
+        let app = with_request_limits(slow, &config);
+
+        let started = std::time::Instant::now();
+        let response = send(&app, Method::GET, "/slow", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn only_timeouts_map_to_504() {
+        let (status, _) = request_limit_error(Elapsed::new().into()).await;
+        assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+        let (status, _) = request_limit_error(Overloaded::new().into()).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        let (status, _) = request_limit_error("broken".into()).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn admin_stats_counts_seeded_tasks_and_users() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        for (id, role) in [("admin", protogen::UserRole::Admin), ("ann", protogen::UserRole::Member)] {
+            let user = protogen::User { id: id.to_string(), username: id.to_string(), role: role as i32, is_active: true, ..Default::default() };
+            storage.create_user(user).await.unwrap();
+        }
+        for id in ["t1", "t2", "t3"] {
+            storage.create_task(protogen::Task { id: id.to_string(), ..Default::default() }).await.unwrap();
+        }
+        let session = Session { user_id: "admin".to_string(), expires_at: i64::MAX / 2, refresh: false };
+        storage.create_session("admin-token", session).await.unwrap();
+        let app = http_app(storage, Arc::new(Config::default()));
+
+        let response = send(&app, Method::GET, "/api/admin/stats", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let request = HttpRequest::builder()
+            .uri("/api/admin/stats")
+            .header(header::AUTHORIZATION, "Bearer admin-token")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap().into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stats = json_body(response).await;
+        assert_eq!(stats["task_count"], 3);
+        assert_eq!(stats["user_count"], 2);
+    }
+
+    async fn get_with(app: &Router, uri: &str, headers: &[(&str, &str)]) -> Response {
+        let mut request = HttpRequest::builder().uri(uri);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        app.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap().into_response()
+    }
+
+    #[tokio::test]
+    async fn etag_covers_the_final_body_and_varies_on_accept() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let created_at = Some(backend::types::SerdeTimestamp(prost_types::Timestamp { seconds: 1_700_000_000, nanos: 0 }));
+        storage.create_task(protogen::Task { id: "t1".to_string(), created_at, ..Default::default() }).await.unwrap();
+        let app = http_app(storage, Arc::new(Config::default()));
+
+        let response = get_with(&app, "/api/tasks/t1", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let vary: Vec<_> = response.headers().get_all(header::VARY).iter().collect();
+        assert!(vary.contains(&&HeaderValue::from_static("accept")), "{:?}", vary);
+        assert!(vary.contains(&&HeaderValue::from_static(TIMESTAMP_FORMAT_HEADER)), "{:?}", vary);
+        let etag = response.headers()[header::ETAG].to_str().unwrap().to_string();
+
+        let response = get_with(&app, "/api/tasks/t1", &[("if-none-match", &etag)]).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], etag.as_str());
+
+        // Same task, different bytes: the tag must not match
+        let millis = get_with(&app, "/api/tasks/t1", &[(TIMESTAMP_FORMAT_HEADER, "epoch_millis"), ("if-none-match", &etag)]).await;
+        assert_eq!(millis.status(), StatusCode::OK);
+        let enveloped = get_with(&app, "/api/tasks/t1", &[("accept", "application/json; profile=\"envelope\""), ("if-none-match", &etag)]).await;
+        assert_eq!(enveloped.status(), StatusCode::OK);
+        assert!(json_body(enveloped).await["data"]["task"].is_object());
+    }
+
+    #[tokio::test]
+    async fn repeated_get_returns_304_with_and_without_the_envelope() {
+        for response_envelope in [false, true] {
+            let storage = Arc::new(Storage::new());
+            storage.load_from_disk().await.unwrap();
+            storage.create_task(protogen::Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+            let app = http_app(storage, Arc::new(Config { response_envelope, ..Default::default() }));
+
+            let first = get_with(&app, "/api/tasks/t1", &[]).await;
+            assert_eq!(first.status(), StatusCode::OK);
+            let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+            let repeat = get_with(&app, "/api/tasks/t1", &[("if-none-match", &etag)]).await;
+            assert_eq!(repeat.status(), StatusCode::NOT_MODIFIED, "envelope: {}", response_envelope);
+        }
+    }
+
+    #[tokio::test]
+    async fn my_tasks_lists_only_the_callers_tasks_and_honors_etags() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let user = protogen::User { id: "ann".to_string(), username: "ann".to_string(), is_active: true, ..Default::default() };
+        storage.create_user(user).await.unwrap();
+        storage.create_session("ann-token", Session { user_id: "ann".to_string(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        for (id, assignee) in [("t1", "ann"), ("t2", "bob")] {
+            let task = protogen::Task { id: id.to_string(), assigned_to: assignee.to_string(), ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+        let app = http_app(storage, Arc::new(Config::default()));
+
+        let auth = ("authorization", "Bearer ann-token");
+        let first = get_with(&app, "/api/me/tasks", &[auth]).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+        let ids: Vec<Value> = json_body(first).await["tasks"].as_array().unwrap()
+            .iter()
+            .map(|task| task["id"].clone())
+            .collect();
+        assert_eq!(ids, [json!("t1")]);
+
+        let repeat = get_with(&app, "/api/me/tasks", &[auth, ("if-none-match", &etag)]).await;
+        assert_eq!(repeat.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[test]
+    fn etag_is_a_stable_sha256() {
+        assert_eq!(etag_for(b"{}"), "W/\"44136fa355b3678a1146ad16f7e8649e\"");
+        let one = etag_for(br#"{"data":{"id":1},"meta":{"request_id":"a"}}"#);
+        let two = etag_for(br#"{"data":{"id":1},"meta":{"request_id":"b"}}"#);
+        assert_eq!(one, two);
+        assert_ne!(one, etag_for(br#"{"data":{"id":2},"meta":{"request_id":"a"}}"#));
+    }
+
+    #[tokio::test]
+    async fn disabled_create_user_returns_404() {
+        let config = Config { disabled_endpoints: vec!["create_user".to_string()], ..Default::default() };
+        let app = app_with(config).await;
+
+        let response = send(&app, Method::POST, "/api/users", new_user("ann")).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = send(&app, Method::GET, "/api/users", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn disabling_an_rpc_disables_every_route_calling_it() {
+        let config = Config { disabled_endpoints: vec!["batch_create_users".to_string()], ..Default::default() };
+        let app = app_with(config).await;
+
+        let response = send(&app, Method::POST, "/api/users/import", json!({ "users": [new_user("ann")] })).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = send(&app, Method::POST, "/api/users", new_user("bob")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn every_rpc_route_names_a_known_endpoint() {
+        // With every RPC disabled, only routes registered as HTTP_ONLY remain;
+        // a misspelt endpoint name would leave its route behind
+        let config = Config { disabled_endpoints: known_endpoints(), expose_routes: true, ..Default::default() };
+        let app = app_with(config).await;
+
+        let routes = json_body(send(&app, Method::GET, "/api/routes", Value::Null).await).await;
+        let mut paths: Vec<&str> = routes["routes"].as_array().unwrap()
+            .iter()
+            .map(|route| route["path"].as_str().unwrap())
+            .collect();
+        paths.sort_unstable();
+        assert_eq!(paths, [
+            "/api/admin/compact", "/api/admin/read-only", "/api/admin/reindex", "/api/admin/stats",
+            "/api/admin/streams", "/api/docs", "/api/health", "/api/health/ready", "/api/me",
+            "/api/openapi.json", "/api/routes", "/api/users/:id/export", "/attachments/:id",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn openapi_spec_documents_every_route() {
+        let app = app_with(Config { expose_routes: true, ..Default::default() }).await;
+
+        let spec = json_body(send(&app, Method::GET, "/api/openapi.json", Value::Null).await).await;
+        let documented = spec["paths"].as_object().unwrap();
+        assert!(documented.contains_key("/api/tasks"));
+
+        let routes = json_body(send(&app, Method::GET, "/api/routes", Value::Null).await).await;
+        for route in routes["routes"].as_array().unwrap() {
+            let path = route["path"].as_str().unwrap();
+            if matches!(path, "/api/openapi.json" | "/api/docs") {
+                continue;
+            }
+            let templated: Vec<String> = path.split('/')
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => format!("{{{}}}", name),
+                    None => segment.to_string(),
+                })
+                .collect();
+            assert!(documented.contains_key(&templated.join("/")), "{} is missing from the spec", path);
+        }
+    }
+
+    #[tokio::test]
+    async fn http_status_change_records_the_caller_as_actor() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let user = protogen::User { id: "ann".to_string(), username: "ann".to_string(), is_active: true, ..Default::default() };
+        storage.create_user(user).await.unwrap();
+        storage.create_session("ann-token", Session { user_id: "ann".to_string(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        let task = protogen::Task { id: "t1".to_string(), status: protogen::TaskStatus::Todo as i32, ..Default::default() };
+        storage.create_task(task).await.unwrap();
+        let (_, mut events) = storage.events().subscribe_since(None, "");
+        let app = http_app(storage, Arc::new(Config::default()));
+
+        let request = HttpRequest::builder()
+            .method(Method::PATCH)
+            .uri("/api/tasks/t1/status")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, "Bearer ann-token")
+            .body(Body::from(json!({ "status": "IN_PROGRESS" }).to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap().into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(events.recv().await.unwrap().user_id, "ann");
+    }
+
+    #[tokio::test]
+    async fn cors_layer_honors_configured_origins_and_credentials() {
+        let config = Config {
+            cors_origins: vec!["https://app.example.com".to_string()],
+            cors_allow_credentials: true,
+            ..Default::default()
+        };
+        let app = Router::new()
+            .route("/grpc", axum::routing::post(|| async { "ok" }))
+            .layer(cors_layer(&config).allow_methods([Method::POST]));
+        let preflight = |origin: &str| HttpRequest::builder()
+            .method(Method::OPTIONS)
+            .uri("/grpc")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+            .body(Body::empty())
+            .unwrap();
+
+        let allowed = app.clone().oneshot(preflight("https://app.example.com")).await.unwrap();
+        let headers = allowed.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example.com");
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_HEADERS], "authorization");
+
+        let refused = app.oneshot(preflight("https://evil.example.com")).await.unwrap();
+        assert!(refused.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+    }
+
+    #[tokio::test]
+    async fn bulk_update_with_a_missing_task_is_a_multi_status() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        storage.create_task(protogen::Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+
+        let body = json!({ "task_ids": ["t1", "nope"], "tags_to_add": ["ops"] });
+        let response = send(&app, Method::PUT, "/api/tasks/bulk", body).await;
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+        let body = json_body(response).await;
+        let statuses: Vec<(&str, u64)> = body["results"].as_array().unwrap()
+            .iter()
+            .map(|result| (result["task_id"].as_str().unwrap(), result["status"].as_u64().unwrap()))
+            .collect();
+        assert_eq!(statuses, [("t1", 200), ("nope", 404)]);
+        assert_eq!(body["failed_ids"], json!(["nope"]));
+        assert_eq!(storage.get_task("t1").await.unwrap().tags, ["ops"]);
+
+        let body = json!({ "task_ids": ["t1"], "tags_to_add": ["api"] });
+        let response = send(&app, Method::PUT, "/api/tasks/bulk", body).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn attachment_downloads_carry_disposition_caching_and_range_headers() {
+        let dir = std::env::temp_dir().join(format!("tasker-attachments-{}", uuid::Uuid::new_v4()));
+        let config = Config { attachments_dir: dir.to_string_lossy().to_string(), ..Default::default() };
+        AttachmentStore::from_config(&config).save("a1", b"hello world").await.unwrap();
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let attachment = protogen::TaskAttachment {
+            id: "a1".to_string(),
+            filename: "résumé \"final\".txt".to_string(),
+            content_type: "text/plain".to_string(),
+            ..Default::default()
+        };
+        storage.create_task(protogen::Task { id: "t1".to_string(), attachments: vec![attachment], ..Default::default() }).await.unwrap();
+        let app = http_app(storage, Arc::new(config));
+
+        let response = get_with(&app, "/attachments/a1", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(headers[header::CONTENT_TYPE], "text/plain");
+        assert_eq!(
+            headers[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"r_sum_ _final_.txt\"; filename*=UTF-8''r%C3%A9sum%C3%A9%20%22final%22.txt",
+        );
+        assert!(headers[header::CACHE_CONTROL].to_str().unwrap().contains("immutable"));
+        assert_eq!(headers[header::ACCEPT_RANGES], "bytes");
+
+        let partial = get_with(&app, "/attachments/a1", &[("range", "bytes=6-")]).await;
+        assert_eq!(partial.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(partial.headers()[header::CONTENT_RANGE], "bytes 6-10/11");
+        let outside = get_with(&app, "/attachments/a1", &[("range", "bytes=20-")]).await;
+        assert_eq!(outside.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(get_with(&app, "/attachments/nope", &[]).await.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn grpc_tls_needs_both_a_readable_cert_and_key() {
+        assert!(load_grpc_tls(&Config::default()).unwrap().is_none());
+
+        let only_cert = Config { grpc_tls_cert_path: Some("cert.pem".to_string()), ..Default::default() };
+        assert!(matches!(load_grpc_tls(&only_cert), Err(TaskerError::Config(_))));
+
+        let missing = Config {
+            grpc_tls_cert_path: Some("/nonexistent/cert.pem".to_string()),
+            grpc_tls_key_path: Some("/nonexistent/key.pem".to_string()),
+            ..Default::default()
+        };
+        let Err(TaskerError::Config(message)) = load_grpc_tls(&missing) else {
+            panic!("an unreadable certificate was accepted");
+        };
+        assert!(message.contains("/nonexistent/cert.pem"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn preflights_are_cached_for_the_configured_age_and_responses_expose_headers() {
+        let config = Config { cors_max_age_secs: 600, request_id_header: "x-trace-id".to_string(), ..Default::default() };
+        let app = app_with(config).await;
+
+        let preflight = HttpRequest::builder()
+            .method(Method::OPTIONS)
+            .uri("/api/tasks")
+            .header(header::ORIGIN, "https://app.example.com")
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(preflight).await.unwrap();
+        assert_eq!(response.headers()[header::ACCESS_CONTROL_MAX_AGE], "600");
+
+        let response = get_with(&app, "/api/tasks", &[("origin", "https://app.example.com")]).await;
+        let exposed = response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS].to_str().unwrap().to_string();
+        for name in ["etag", "content-disposition", "x-trace-id"] {
+            assert!(exposed.contains(name), "{} missing from {}", name, exposed);
+        }
+    }
+
+
+    #[tokio::test]
+    async fn me_returns_and_updates_the_signed_in_user() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let user = protogen::User { id: "ann".to_string(), username: "ann".to_string(), email: "ann@example.com".to_string(), is_active: true, ..Default::default() };
+        storage.create_user(user).await.unwrap();
+        storage.create_session("ann-token", Session { user_id: "ann".to_string(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+
+        assert_eq!(send(&app, Method::GET, "/api/me", Value::Null).await.status(), StatusCode::UNAUTHORIZED);
+        let response = get_with(&app, "/api/me", &[("authorization", "Bearer ann-token")]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(json_body(response).await["user"]["username"], "ann");
+
+        let patch = |body: Value| HttpRequest::builder()
+            .method(Method::PATCH)
+            .uri("/api/me")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, "Bearer ann-token")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(patch(json!({ "preferences": { "theme": "dark" } }))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let preferences = storage.get_user("ann").await.unwrap().preferences.unwrap();
+        assert_eq!(preferences.theme, "dark");
+
+        let response = app.oneshot(patch(json!({ "role": "ADMIN" }))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+
+    #[tokio::test]
+    async fn timestamps_render_in_the_requested_format() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let created_at = Some(backend::types::SerdeTimestamp(prost_types::Timestamp { seconds: 1_700_000_000, nanos: 0 }));
+        let task = protogen::Task { id: "t1".to_string(), title: "2023-11-14T22:13:20Z".to_string(), created_at, ..Default::default() };
+        storage.create_task(task).await.unwrap();
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+
+        let task = json_body(get_with(&app, "/api/tasks/t1", &[(TIMESTAMP_FORMAT_HEADER, "epoch_millis")]).await).await["task"].clone();
+        assert_eq!(task["created_at"], 1_700_000_000_000_i64);
+        assert_eq!(task["title"], "2023-11-14T22:13:20Z");
+
+        let task = json_body(get_with(&app, "/api/tasks/t1", &[(TIMESTAMP_FORMAT_HEADER, "+02:00")]).await).await["task"].clone();
+        assert_eq!(task["created_at"], "2023-11-15T00:13:20+02:00");
+
+        let response = get_with(&app, "/api/tasks/t1", &[(TIMESTAMP_FORMAT_HEADER, "sundial")]).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // The configured default applies when the header is absent
+        let app = http_app(storage, Arc::new(Config { timestamp_format: TimestampFormat::EpochMillis, ..Default::default() }));
+        let task = json_body(get_with(&app, "/api/tasks/t1", &[]).await).await["task"].clone();
+        assert!(task["created_at"].is_i64());
+    }
+
+
+    #[tokio::test]
+    async fn unknown_paths_get_a_json_404() {
+        let app = app_with(Config { expose_routes: false, ..Default::default() }).await;
+
+        let response = send(&app, Method::DELETE, "/api/nowhere", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = json_body(response).await;
+        assert_eq!(body["error"], "not_found");
+        assert_eq!(body["message"], "No route for DELETE /api/nowhere");
+        assert!(body.get("routes").is_none());
+    }
+
+
+    #[tokio::test]
+    async fn route_listing_can_be_switched_off() {
+        let app = app_with(Config { expose_routes: true, ..Default::default() }).await;
+        let routes = json_body(send(&app, Method::GET, "/api/routes", Value::Null).await).await;
+        let routes = routes["routes"].as_array().unwrap();
+        let tasks = routes.iter().find(|route| route["path"] == "/api/tasks/:id").unwrap();
+        assert_eq!(tasks["methods"], json!(["GET", "PUT", "DELETE"]));
+        let not_found = json_body(send(&app, Method::GET, "/api/nowhere", Value::Null).await).await;
+        assert_eq!(not_found["routes"].as_array().unwrap().len(), routes.len());
+
+        let app = app_with(Config { expose_routes: false, ..Default::default() }).await;
+        let response = send(&app, Method::GET, "/api/routes", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(json_body(response).await.get("routes").is_none());
+    }
+
+
+    #[tokio::test]
+    async fn standard_health_service_serves_once_storage_loads() {
+        use tonic::server::NamedService;
+        use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+
+        let storage = Storage::new();
+        let client = HealthClient::new(grpc_health_service(storage.clone()).await);
+        let status_of = |service: &str| {
+            let (mut client, request) = (client.clone(), HealthCheckRequest { service: service.to_string() });
+            async move { client.check(request).await.unwrap().into_inner().status }
+        };
+        let tasks = <TaskServiceServer<TaskServiceImpl> as NamedService>::NAME;
+        assert_eq!(status_of(tasks).await, ServingStatus::NotServing as i32);
+
+        storage.load_from_disk().await.unwrap();
+        for _ in 0..100 {
+            if status_of(tasks).await == ServingStatus::Serving as i32 {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("task service never reported SERVING");
+    }
+
+
+    #[tokio::test]
+    async fn repeated_deletes_are_404_unless_idempotent() {
+        for idempotent_deletes in [false, true] {
+            let storage = Arc::new(Storage::new());
+            storage.load_from_disk().await.unwrap();
+            storage.create_task(protogen::Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+            let app = http_app(storage, Arc::new(Config { idempotent_deletes, ..Default::default() }));
+
+            assert_eq!(send(&app, Method::DELETE, "/api/tasks/t1", Value::Null).await.status(), StatusCode::OK);
+            let repeat = send(&app, Method::DELETE, "/api/tasks/t1", Value::Null).await;
+            let expected = if idempotent_deletes { StatusCode::NO_CONTENT } else { StatusCode::NOT_FOUND };
+            assert_eq!(repeat.status(), expected);
+
+            // The header overrides the server setting either way
+            let delete = |value: &str| HttpRequest::builder()
+                .method(Method::DELETE)
+                .uri("/api/tasks/t1")
+                .header(IDEMPOTENT_DELETE_HEADER, value)
+                .body(Body::empty())
+                .unwrap();
+            let overridden = app.clone().oneshot(delete(&(!idempotent_deletes).to_string())).await.unwrap();
+            let expected = if idempotent_deletes { StatusCode::NOT_FOUND } else { StatusCode::NO_CONTENT };
+            assert_eq!(overridden.status(), expected);
+            assert_eq!(app.clone().oneshot(delete("maybe")).await.unwrap().status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+
+    #[tokio::test]
+    async fn large_responses_are_gzipped_for_clients_that_accept_it() {
+        for compression in [true, false] {
+            let storage = Arc::new(Storage::new());
+            storage.load_from_disk().await.unwrap();
+            for n in 0..50 {
+                let task = protogen::Task { id: format!("t{}", n), description: "lorem ipsum ".repeat(20), ..Default::default() };
+                storage.create_task(task).await.unwrap();
+            }
+            let app = http_app(storage, Arc::new(Config { compression, ..Default::default() }));
+
+            let plain = body_bytes(get_with(&app, "/api/tasks?page_size=50", &[]).await).await;
+            let response = get_with(&app, "/api/tasks?page_size=50", &[("accept-encoding", "gzip")]).await;
+            let encoding = response.headers().get(header::CONTENT_ENCODING).cloned();
+            let body = body_bytes(response).await;
+            if compression {
+                assert_eq!(encoding.unwrap(), "gzip");
+                assert_eq!(body[..2], [0x1f, 0x8b]);
+                assert!(body.len() * 4 < plain.len(), "{} vs {} bytes", body.len(), plain.len());
+            } else {
+                assert!(encoding.is_none());
+                assert_eq!(body, plain);
+            }
+        }
+    }
+
+
+    #[tokio::test]
+    async fn csv_import_flags_a_duplicate_email_and_creates_the_rest() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let admin = protogen::User { id: "admin".to_string(), username: "admin".to_string(), role: protogen::UserRole::Admin as i32, is_active: true, ..Default::default() };
+        storage.create_user(admin).await.unwrap();
+        storage.create_session("admin-token", Session { user_id: "admin".to_string(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+
+        let csv = "username,email,full_name,role,password\n\
+            ann,ann@example.com,Ann,member,Passw0rd!long\n\
+            bob,bob@example.com,Bob,,Passw0rd!long\n\
+            ann2,ANN@example.com,Ann Again,member,Passw0rd!long\n\
+            eve,eve@example.com,Eve,overlord,Passw0rd!long\n";
+        let import = |token: Option<&str>| {
+            let mut request = HttpRequest::builder()
+                .method(Method::POST)
+                .uri("/api/users/import?format=csv")
+                .header(header::CONTENT_TYPE, "text/csv");
+            if let Some(token) = token {
+                request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+            }
+            app.clone().oneshot(request.body(Body::from(csv)).unwrap())
+        };
+
+        assert_eq!(import(None).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+        let response = import(Some("admin-token")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+        let body = json_body(response).await;
+        assert_eq!(body["created_count"], 2);
+        let statuses: Vec<_> = body["results"].as_array().unwrap().iter().map(|result| result["status"].clone()).collect();
+        assert_eq!(statuses, [json!(201), json!(201), json!(409), json!(400)]);
+        assert_eq!(body["results"][2]["row"], 4);
+        assert!(storage.get_user_by_email("bob@example.com").await.is_some());
+        assert!(storage.get_user_by_email("eve@example.com").await.is_none());
+    }
+
+
+    #[tokio::test]
+    async fn custom_fields_round_trip_and_filter() {
+        let app = app_with(Config::default()).await;
+        let mut ids = Vec::new();
+        for (title, points) in [("Small", "1"), ("Large", "8")] {
+            let body = json!({ "title": title, "custom_fields": { "points": points, "customer": "acme" } });
+            let response = send(&app, Method::POST, "/api/tasks", body).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            ids.push(json_body(response).await["task"]["id"].as_str().unwrap().to_string());
+        }
+
+        let task = json_body(send(&app, Method::GET, &format!("/api/tasks/{}", ids[1]), Value::Null).await).await;
+        assert_eq!(task["task"]["custom_fields"], json!({ "points": "8", "customer": "acme" }));
+
+        let listed = json_body(send(&app, Method::GET, "/api/tasks?custom.points=8&custom.customer=acme", Value::Null).await).await;
+        let found: Vec<_> = listed["tasks"].as_array().unwrap().iter().map(|task| task["id"].as_str().unwrap()).collect();
+        assert_eq!(found, [ids[1].as_str()]);
+        let listed = json_body(send(&app, Method::GET, "/api/tasks?custom.points=3", Value::Null).await).await;
+        assert!(listed["tasks"].as_array().unwrap().is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn user_export_holds_their_tasks_and_no_password() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+        let created = json_body(send(&app, Method::POST, "/api/users", new_user("ann")).await).await;
+        let ann = created["user"]["id"].as_str().unwrap().to_string();
+        storage.create_session("ann-token", Session { user_id: ann.clone(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        storage.create_session("bob-token", Session { user_id: "bob".to_string(), expires_at: i64::MAX / 2, refresh: false }).await.unwrap();
+        storage.create_user(protogen::User { id: "bob".to_string(), username: "bob".to_string(), is_active: true, ..Default::default() }).await.unwrap();
+
+        let comment = protogen::TaskComment { id: "c1".to_string(), author_id: ann.clone(), content: "On it".to_string(), ..Default::default() };
+        storage.create_task(protogen::Task { id: "mine".to_string(), assigned_to: ann.clone(), ..Default::default() }).await.unwrap();
+        storage.create_task(protogen::Task { id: "theirs".to_string(), assigned_to: "bob".to_string(), comments: vec![comment], ..Default::default() }).await.unwrap();
+
+        let uri = format!("/api/users/{}/export", ann);
+        let response = get_with(&app, &uri, &[("authorization", "Bearer ann-token")]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = String::from_utf8(body_bytes(response).await).unwrap();
+        assert!(!body.contains("Passw0rd") && !body.to_lowercase().contains("password"), "{}", body);
+        let export: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(export["user"]["username"], "ann");
+        let task_ids: Vec<&str> = export["tasks"].as_array().unwrap().iter().map(|task| task["id"].as_str().unwrap()).collect();
+        assert_eq!(task_ids, vec!["mine"]);
+        assert_eq!(export["comments"][0]["id"], "c1");
+
+        let response = get_with(&app, &uri, &[("authorization", "Bearer bob-token")]).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+
+    #[tokio::test]
+    async fn enveloped_lists_carry_pagination_in_meta() {
+        let storage = Arc::new(Storage::new());
+        storage.load_from_disk().await.unwrap();
+        for id in ["t1", "t2", "t3"] {
+            storage.create_task(protogen::Task { id: id.to_string(), title: id.to_string(), ..Default::default() }).await.unwrap();
+        }
+        let app = http_app(storage, Arc::new(Config { response_envelope: true, ..Default::default() }));
+
+        let body = json_body(get_with(&app, "/api/tasks?page_size=2", &[("x-request-id", "req-42")]).await).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 2);
+        assert!(body["data"].get("total_count").is_none());
+        assert_eq!(body["meta"]["request_id"], "req-42");
+        let pagination = &body["meta"]["pagination"];
+        assert_eq!(pagination["total_count"], 3);
+        assert_eq!(pagination["total_pages"], 2);
+        assert!(!pagination["next_page_token"].as_str().unwrap().is_empty());
+
+        let raw = json_body(get_with(&app, "/api/tasks?page_size=2", &[("accept", "application/json; profile=raw")]).await).await;
+        assert_eq!(raw["total_count"], 3);
+        assert!(raw.get("meta").is_none());
+    }
+
+
+    #[tokio::test]
+    async fn requests_beyond_the_in_flight_limit_are_shed() {
+        let config = Config { max_in_flight_requests: 1, ..Default::default() };
+        let entered = Arc::new(tokio::sync::Notify::new());
+        let release = Arc::new(tokio::sync::Notify::new());
+        let handler = {
+            let (entered, release) = (entered.clone(), release.clone());
+            move || async move {
+                entered.notify_one();
+                release.notified().await;
+                "done"
+            }
+        };
+        let app = with_request_limits(Router::new().route("/held", axum::routing::get(handler)), &config);
+
+        let request = HttpRequest::builder().uri("/held").body(Body::empty()).unwrap();
+        let first = tokio::spawn(app.clone().oneshot(request));
+        entered.notified().await;
+        let shed = send(&app, Method::GET, "/held", Value::Null).await;
+        assert_eq!(shed.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        release.notify_one();
+        assert_eq!(first.await.unwrap().unwrap().status(), StatusCode::OK);
+        release.notify_one();
+        assert_eq!(send(&app, Method::GET, "/held", Value::Null).await.status(), StatusCode::OK);
+    }
+
+
+    #[tokio::test]
+    async fn readiness_waits_for_the_initial_load() {
+        let path = std::env::temp_dir().join(format!("tasker-ready-{}", uuid::Uuid::new_v4())).join("storage.json");
+        let seed = Storage::with_persistence(&path, false).unwrap();
+        seed.create_task(protogen::Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+        seed.force_save().await.unwrap();
+        drop(seed);
+
+        let storage = Arc::new(Storage::with_persistence(&path, false).unwrap());
+        let app = http_app(storage.clone(), Arc::new(Config::default()));
+        let response = send(&app, Method::GET, "/api/health/ready", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(json_body(response).await["ready"], false);
+        let response = send(&app, Method::GET, "/api/tasks/t1", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        storage.load_from_disk().await.unwrap();
+        let response = send(&app, Method::GET, "/api/health/ready", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(json_body(response).await["ready"], true);
+        let response = send(&app, Method::GET, "/api/tasks/t1", Value::Null).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,24 @@
+// src/mentions.rs
+
+/// Usernames `@mentioned` in `text`, in order of first appearance and without
+/// duplicates. An `@` only starts a mention at the start of the text or after
+/// a character that can't be part of a name, so email addresses don't count.
+pub fn mentioned_usernames(text: &str) -> Vec<String> {
+    let is_name_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.');
+    let mut names: Vec<String> = Vec::new();
+    let mut previous = None;
+
+    for (i, c) in text.char_indices() {
+        if c == '@' && !previous.is_some_and(is_name_char) {
+            let rest = &text[i + 1..];
+            let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+            // "@alice." at the end of a sentence mentions alice
+            let name = rest[..end].trim_end_matches('.');
+            if !name.is_empty() && !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+        }
+        previous = Some(c);
+    }
+    names
+}
@@ -0,0 +1,174 @@
+// src/moderation.rs
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::config::Config;
+
+/// Stands in for a link removed under `CommentUrlPolicy::Strip`
+const STRIPPED_LINK: &str = "[link removed]";
+
+/// What a content filter decided about a comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Allow,
+    /// Keep the comment with this text instead
+    Rewrite(String),
+    /// Keep the comment but mark it for review, saying why
+    Flag(String),
+    /// Refuse the comment, saying why
+    Reject(String),
+}
+
+/// A hook that reviews comment text before it's stored. Filters run in the
+/// order they were added, each seeing the text as rewritten by the ones before.
+pub trait ContentFilter: Send + Sync {
+    fn review(&self, content: &str) -> Verdict;
+}
+
+/// What happens to links in comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommentUrlPolicy {
+    #[default]
+    Allow,
+    /// Replace each link with a placeholder
+    Strip,
+    /// Keep links but flag the comment
+    Flag,
+}
+
+impl FromStr for CommentUrlPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "allow" => Ok(Self::Allow),
+            "strip" => Ok(Self::Strip),
+            "flag" => Ok(Self::Flag),
+            other => Err(anyhow::anyhow!("unknown comment URL policy: {}", other)),
+        }
+    }
+}
+
+fn is_link(word: &str) -> bool {
+    let word = word.to_ascii_lowercase();
+    word.starts_with("http://") || word.starts_with("https://") || word.starts_with("www.")
+}
+
+/// Applies a `CommentUrlPolicy`
+pub struct UrlFilter {
+    policy: CommentUrlPolicy,
+}
+
+impl UrlFilter {
+    pub fn new(policy: CommentUrlPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl ContentFilter for UrlFilter {
+    fn review(&self, content: &str) -> Verdict {
+        if !content.split_whitespace().any(is_link) {
+            return Verdict::Allow;
+        }
+        match self.policy {
+            CommentUrlPolicy::Allow => Verdict::Allow,
+            CommentUrlPolicy::Flag => Verdict::Flag("contains a link".to_string()),
+            // Rebuilt word by word, which also collapses runs of whitespace
+            CommentUrlPolicy::Strip => Verdict::Rewrite(
+                content.lines()
+                    .map(|line| {
+                        line.split_whitespace()
+                            .map(|word| if is_link(word) { STRIPPED_LINK } else { word })
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
+    }
+}
+
+/// Flags comments containing any of a list of words, matched whole and
+/// case-insensitively
+pub struct BlockedWordsFilter {
+    words: Vec<String>,
+}
+
+impl BlockedWordsFilter {
+    pub fn new(words: &[String]) -> Self {
+        Self { words: words.iter().map(|word| word.trim().to_lowercase()).filter(|word| !word.is_empty()).collect() }
+    }
+}
+
+impl ContentFilter for BlockedWordsFilter {
+    fn review(&self, content: &str) -> Verdict {
+        let blocked = content
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| !word.is_empty() && self.words.contains(&word.to_lowercase()));
+        if blocked {
+            Verdict::Flag("contains a blocked word".to_string())
+        } else {
+            Verdict::Allow
+        }
+    }
+}
+
+/// Comment text once every filter has passed it
+#[derive(Debug, Clone, Default)]
+pub struct Reviewed {
+    pub content: String,
+    /// Why filters flagged the comment; empty if none did
+    pub flags: Vec<String>,
+}
+
+/// The length limit and filter chain comments go through
+#[derive(Clone, Default)]
+pub struct CommentModeration {
+    max_length: usize,
+    filters: Vec<Arc<dyn ContentFilter>>,
+}
+
+impl CommentModeration {
+    pub fn from_config(config: &Config) -> Self {
+        let mut moderation = Self { max_length: config.max_comment_length, filters: Vec::new() };
+        if config.comment_url_policy != CommentUrlPolicy::Allow {
+            moderation = moderation.with_filter(UrlFilter::new(config.comment_url_policy));
+        }
+        if !config.comment_blocked_words.is_empty() {
+            moderation = moderation.with_filter(BlockedWordsFilter::new(&config.comment_blocked_words));
+        }
+        moderation
+    }
+
+    /// Add a filter to the end of the chain
+    pub fn with_filter(mut self, filter: impl ContentFilter + 'static) -> Self {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Check `content` and run it through the filters, or say why it's refused
+    pub fn review(&self, content: &str) -> Result<Reviewed, String> {
+        if content.trim().is_empty() {
+            return Err("Comment content is required".to_string());
+        }
+        let length = content.chars().count();
+        if self.max_length > 0 && length > self.max_length {
+            return Err(format!("Comment is {} characters long; the limit is {}", length, self.max_length));
+        }
+
+        let mut reviewed = Reviewed { content: content.to_string(), flags: Vec::new() };
+        for filter in &self.filters {
+            match filter.review(&reviewed.content) {
+                Verdict::Allow => {}
+                Verdict::Rewrite(content) => reviewed.content = content,
+                Verdict::Flag(reason) => reviewed.flags.push(reason),
+                Verdict::Reject(reason) => return Err(format!("Comment rejected: {}", reason)),
+            }
+        }
+        if reviewed.content.trim().is_empty() {
+            return Err("Comment is empty once filtered".to_string());
+        }
+        Ok(reviewed)
+    }
+}
@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// Core domain entities
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Task {
@@ -30,8 +32,36 @@ pub struct Task {
     pub comments: ::prost::alloc::vec::Vec<TaskComment>,
     #[prost(message, repeated, tag = "13")]
     pub attachments: ::prost::alloc::vec::Vec<TaskAttachment>,
+    /// Finer-grained ordering, 0-100, among tasks of the same priority; tasks sort by priority first
+    #[prost(double, optional, tag = "14")]
+    pub priority_score: ::core::option::Option<f64>,
+    /// User ids following the task, e.g. after being @mentioned
+    #[prost(string, repeated, tag = "15")]
+    pub watchers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Empty = not in a project
+    #[prost(string, tag = "16")]
+    pub project_id: ::prost::alloc::string::String,
+    /// Times the due date has been deferred with SnoozeTask
+    #[prost(uint32, tag = "17")]
+    pub snooze_count: u32,
+    /// Team-specific extras such as story points; keys are letters, digits, '_' and '-'
+    #[prost(map = "string, string", tag = "18")]
+    pub custom_fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(message, repeated, tag = "19")]
+    pub reminders: ::prost::alloc::vec::Vec<TaskReminder>,
+    /// Sequential per server, assigned on create and never reused
+    #[prost(uint64, tag = "20")]
+    pub number: u64,
+    /// Set on create to make this a subtask of another task
+    #[prost(string, tag = "21")]
+    pub parent_id: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskComment {
@@ -45,8 +75,34 @@ pub struct TaskComment {
     pub content: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "5")]
     pub created_at: ::core::option::Option<crate::types::SerdeTimestamp>,
+    /// Unset until the comment is first edited
+    #[prost(message, optional, tag = "6")]
+    pub edited_at: ::core::option::Option<crate::types::SerdeTimestamp>,
+    /// Why content filters flagged the comment for review; empty if they didn't
+    #[prost(string, repeated, tag = "7")]
+    pub flags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Fires once, as a REMINDER event, when the clock reaches the due date minus `before_secs`
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskReminder {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Who set it, and who is reminded
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub before_secs: i64,
+    /// Unset until it fires
+    #[prost(message, optional, tag = "4")]
+    pub fired_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskMetrics {
@@ -58,6 +114,8 @@ pub struct TaskMetrics {
     pub completion_percentage: f64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskAttachment {
@@ -77,6 +135,8 @@ pub struct TaskAttachment {
     pub url: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct User {
@@ -108,6 +168,8 @@ pub struct User {
     pub profile: ::core::option::Option<UserProfile>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UserPreferences {
@@ -123,6 +185,8 @@ pub struct UserPreferences {
     pub email_notifications: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UserProfile {
@@ -137,8 +201,29 @@ pub struct UserProfile {
     #[prost(string, tag = "5")]
     pub location: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Project {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub owner_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<crate::types::SerdeTimestamp>,
+    #[prost(message, optional, tag = "6")]
+    pub updated_at: ::core::option::Option<crate::types::SerdeTimestamp>,
+}
 /// Standard CRUD operations
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTaskRequest {
@@ -154,8 +239,24 @@ pub struct CreateTaskRequest {
     pub assigned_to: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "6")]
     pub due_date: ::core::option::Option<crate::types::SerdeTimestamp>,
+    #[prost(double, optional, tag = "7")]
+    pub priority_score: ::core::option::Option<f64>,
+    #[prost(string, tag = "8")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(map = "string, string", tag = "9")]
+    pub custom_fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Accept a due date in the past, e.g. when backfilling historical tasks
+    #[prost(bool, tag = "10")]
+    pub allow_past_due: bool,
+    #[prost(string, tag = "11")]
+    pub parent_id: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTaskResponse {
@@ -167,6 +268,90 @@ pub struct CreateTaskResponse {
     pub message: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskOverrides {
+    #[prost(string, optional, tag = "1")]
+    pub title: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", optional, tag = "3")]
+    pub priority: ::core::option::Option<i32>,
+    /// Empty = keep the source task's tags
+    #[prost(string, repeated, tag = "4")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub due_date: ::core::option::Option<crate::types::SerdeTimestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloneTaskRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub overrides: ::core::option::Option<TaskOverrides>,
+}
+/// Folds a duplicate into the task it duplicates; the target keeps its own title, status etc.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeTasksRequest {
+    /// Soft-deleted once merged
+    #[prost(string, tag = "1")]
+    pub source_id: ::prost::alloc::string::String,
+    /// Gains the source's comments, attachments, tags and watchers
+    #[prost(string, tag = "2")]
+    pub target_id: ::prost::alloc::string::String,
+}
+/// Push a task's due date back; set exactly one of until or duration_secs
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnoozeTaskRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Must be later than both now and the current due date
+    #[prost(message, optional, tag = "2")]
+    pub until: ::core::option::Option<crate::types::SerdeTimestamp>,
+    /// Counted from the current due date, or from now if that has passed or is unset
+    #[prost(int64, tag = "3")]
+    pub duration_secs: i64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetReminderRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    /// How long before the due date to fire; 0 fires at the due date
+    #[prost(int64, tag = "2")]
+    pub before_secs: i64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetReminderResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reminder: ::core::option::Option<TaskReminder>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTaskRequest {
@@ -176,15 +361,45 @@ pub struct GetTaskRequest {
     pub include_comments: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpsertTaskResponse {
+    #[prost(message, optional, tag = "1")]
+    pub task: ::core::option::Option<Task>,
+    /// False when an existing task with the same natural key was returned
+    #[prost(bool, tag = "2")]
+    pub created: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskByNumberRequest {
+    #[prost(uint64, tag = "1")]
+    pub number: u64,
+    #[prost(bool, tag = "2")]
+    pub include_comments: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTaskResponse {
+    /// Only the most recent comments unless include_comments is set
     #[prost(message, optional, tag = "1")]
     pub task: ::core::option::Option<Task>,
     #[prost(bool, tag = "2")]
     pub found: bool,
+    #[prost(uint64, tag = "3")]
+    pub comment_count: u64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateTaskRequest {
@@ -197,6 +412,8 @@ pub struct UpdateTaskRequest {
     pub update_mask: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateTaskResponse {
@@ -207,7 +424,21 @@ pub struct UpdateTaskResponse {
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
 }
+/// Status-only update, checked against the allowed workflow transitions
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTaskStatusRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskStatus", tag = "2")]
+    pub status: i32,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteTaskRequest {
@@ -218,6 +449,8 @@ pub struct DeleteTaskRequest {
     pub force: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteTaskResponse {
@@ -226,8 +459,134 @@ pub struct DeleteTaskResponse {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
+/// Archived tasks leave active listings and scans but are kept until restored
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArchiveTaskRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListArchivedTasksRequest {
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+    #[prost(string, tag = "2")]
+    pub page_token: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreArchivedTaskRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Comments; the author is the authenticated caller
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddCommentRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub content: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditCommentRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub comment_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCommentRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub comment_id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommentResponse {
+    #[prost(message, optional, tag = "1")]
+    pub comment: ::core::option::Option<TaskComment>,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCommentResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTaskCommentsRequest {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// Newest first by default
+    #[prost(bool, tag = "4")]
+    pub oldest_first: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTaskCommentsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub comments: ::prost::alloc::vec::Vec<TaskComment>,
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub total_count: u64,
+    /// One-based
+    #[prost(uint64, tag = "4")]
+    pub current_page: u64,
+    #[prost(uint64, tag = "5")]
+    pub total_pages: u64,
+}
 /// List/Query operations with pagination and filtering
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListTasksRequest {
@@ -240,7 +599,21 @@ pub struct ListTasksRequest {
     #[prost(message, optional, tag = "4")]
     pub sort: ::core::option::Option<TaskSort>,
 }
+/// Every matching task, one message each, without paging
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamTasksRequest {
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(message, optional, tag = "2")]
+    pub sort: ::core::option::Option<TaskSort>,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListTasksResponse {
@@ -248,10 +621,24 @@ pub struct ListTasksResponse {
     pub tasks: ::prost::alloc::vec::Vec<Task>,
     #[prost(string, tag = "2")]
     pub next_page_token: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub total_count: i32,
+    /// Tasks matching the filter
+    #[prost(uint64, tag = "3")]
+    pub total_count: u64,
+    /// All tasks, regardless of filter
+    #[prost(uint64, tag = "4")]
+    pub grand_total: u64,
+    /// One-based
+    #[prost(uint64, tag = "5")]
+    pub current_page: u64,
+    #[prost(uint64, tag = "6")]
+    pub total_pages: u64,
+    /// Set when total_count is over the server's soft limit
+    #[prost(string, tag = "7")]
+    pub warning: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskFilter {
@@ -269,8 +656,22 @@ pub struct TaskFilter {
     pub due_after: ::core::option::Option<crate::types::SerdeTimestamp>,
     #[prost(string, tag = "7")]
     pub search_query: ::prost::alloc::string::String,
+    /// Only tasks nobody is assigned to
+    #[prost(bool, tag = "8")]
+    pub unassigned: bool,
+    /// Only open tasks inside the server's due-soon window
+    #[prost(bool, tag = "9")]
+    pub due_soon: bool,
+    /// Each key must be set to exactly this value
+    #[prost(map = "string, string", tag = "10")]
+    pub custom_fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskSort {
@@ -279,80 +680,294 @@ pub struct TaskSort {
     #[prost(enumeration = "SortDirection", tag = "2")]
     pub direction: i32,
 }
-/// Bulk operations
+/// Saved filters/views
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BulkUpdateTasksRequest {
-    #[prost(string, repeated, tag = "1")]
-    pub task_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-    #[prost(enumeration = "TaskStatus", tag = "2")]
-    pub status: i32,
+pub struct SavedView {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
-    pub assigned_to: ::prost::alloc::string::String,
-    #[prost(string, repeated, tag = "4")]
-    pub tags_to_add: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-    #[prost(string, repeated, tag = "5")]
-    pub tags_to_remove: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(message, optional, tag = "5")]
+    pub sort: ::core::option::Option<TaskSort>,
+    #[prost(message, optional, tag = "6")]
+    pub created_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BulkUpdateTasksResponse {
-    #[prost(int32, tag = "1")]
-    pub updated_count: i32,
-    #[prost(string, repeated, tag = "2")]
-    pub failed_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+pub struct CreateSavedViewRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(message, optional, tag = "4")]
+    pub sort: ::core::option::Option<TaskSort>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateSavedViewResponse {
+    #[prost(message, optional, tag = "1")]
+    pub view: ::core::option::Option<SavedView>,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
 }
-/// Search operations
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SearchTasksRequest {
+pub struct ListSavedViewsRequest {
     #[prost(string, tag = "1")]
-    pub query: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "2")]
-    pub filters: ::core::option::Option<TaskFilter>,
-    #[prost(int32, tag = "3")]
-    pub page_size: i32,
-    #[prost(string, tag = "4")]
-    pub page_token: ::prost::alloc::string::String,
+    pub user_id: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SearchTasksResponse {
+pub struct ListSavedViewsResponse {
     #[prost(message, repeated, tag = "1")]
-    pub tasks: ::prost::alloc::vec::Vec<Task>,
-    #[prost(uint32, tag = "2")]
-    pub total_count: u32,
-    #[prost(uint32, tag = "3")]
-    pub search_time_ms: u32,
+    pub views: ::prost::alloc::vec::Vec<SavedView>,
 }
-/// Real-time streaming messages
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TaskEvent {
+pub struct ApplySavedViewRequest {
     #[prost(string, tag = "1")]
-    pub event_id: ::prost::alloc::string::String,
-    #[prost(enumeration = "TaskEventType", tag = "2")]
-    pub event_type: i32,
-    #[prost(message, optional, tag = "3")]
-    pub task: ::core::option::Option<Task>,
-    #[prost(string, tag = "4")]
     pub user_id: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "5")]
-    pub timestamp: ::core::option::Option<crate::types::SerdeTimestamp>,
-    #[prost(map = "string, string", tag = "6")]
-    pub metadata: ::std::collections::HashMap<
-        ::prost::alloc::string::String,
-        ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub view_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+    #[prost(string, tag = "4")]
+    pub page_token: ::prost::alloc::string::String,
+}
+/// Bulk operations
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkUpdateTasksRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub task_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskStatus", tag = "2")]
+    pub status: i32,
+    #[prost(string, tag = "3")]
+    pub assigned_to: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub tags_to_add: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "5")]
+    pub tags_to_remove: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Report what would change without saving anything
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+/// The same changes as BulkUpdateTasksRequest, applied to every task matching a filter
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkUpdateTasksByFilterRequest {
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(enumeration = "TaskStatus", tag = "2")]
+    pub status: i32,
+    #[prost(string, tag = "3")]
+    pub assigned_to: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub tags_to_add: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "5")]
+    pub tags_to_remove: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+/// Tag-only bulk change; tags are normalized like everywhere else
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTagTasksRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub task_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "2")]
+    pub add: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "3")]
+    pub remove: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "4")]
+    pub dry_run: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkUpdateTasksResponse {
+    #[prost(uint64, tag = "1")]
+    pub updated_count: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub failed_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub results: ::prost::alloc::vec::Vec<BulkUpdateResult>,
+    /// Nothing was saved; counts and results show what would have happened
+    #[prost(bool, tag = "5")]
+    pub dry_run: bool,
+}
+/// Outcome for one id in a bulk update, in request order
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkUpdateResult {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(enumeration = "BulkUpdateError", tag = "3")]
+    pub error: i32,
+    #[prost(string, tag = "4")]
+    pub error_message: ::prost::alloc::string::String,
+}
+/// Tag operations
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameTagRequest {
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameTagResponse {
+    #[prost(uint64, tag = "1")]
+    pub updated_count: u64,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsRequest {
+    /// Empty = all tags
+    #[prost(string, tag = "1")]
+    pub prefix: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagCount {
+    #[prost(string, tag = "1")]
+    pub tag: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub tags: ::prost::alloc::vec::Vec<TagCount>,
+}
+/// Search operations
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTasksRequest {
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub filters: ::core::option::Option<TaskFilter>,
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+    #[prost(string, tag = "4")]
+    pub page_token: ::prost::alloc::string::String,
+    /// Typo-tolerant matching, ranked by edit distance
+    #[prost(bool, tag = "5")]
+    pub fuzzy: bool,
+    /// 0 = server default
+    #[prost(uint32, tag = "6")]
+    pub max_distance: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTasksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub tasks: ::prost::alloc::vec::Vec<Task>,
+    #[prost(uint64, tag = "2")]
+    pub total_count: u64,
+    #[prost(uint32, tag = "3")]
+    pub search_time_ms: u32,
+}
+/// Real-time streaming messages
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskEvent {
+    #[prost(string, tag = "1")]
+    pub event_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskEventType", tag = "2")]
+    pub event_type: i32,
+    #[prost(message, optional, tag = "3")]
+    pub task: ::core::option::Option<Task>,
+    #[prost(string, tag = "4")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: ::core::option::Option<crate::types::SerdeTimestamp>,
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
     >,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StreamTaskEventsRequest {
@@ -365,9 +980,17 @@ pub struct StreamTaskEventsRequest {
     /// Filter by user
     #[prost(string, tag = "3")]
     pub user_id: ::prost::alloc::string::String,
+    /// Catch up on recent events before going live: those after `after_event_id`
+    /// when it's still retained, otherwise those after `since`
+    #[prost(message, optional, tag = "4")]
+    pub since: ::core::option::Option<crate::types::SerdeTimestamp>,
+    #[prost(string, tag = "5")]
+    pub after_event_id: ::prost::alloc::string::String,
 }
 /// Analytics and reporting
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTaskAnalyticsRequest {
@@ -380,8 +1003,13 @@ pub struct GetTaskAnalyticsRequest {
     /// "status", "user", "priority", "day", "week"
     #[prost(string, tag = "4")]
     pub group_by: ::prost::alloc::string::String,
+    /// Unset = the server's default
+    #[prost(bool, optional, tag = "5")]
+    pub include_archived: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTaskAnalyticsResponse {
@@ -391,60 +1019,153 @@ pub struct GetTaskAnalyticsResponse {
     pub generated_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskAnalytics {
-    #[prost(uint32, tag = "1")]
-    pub total_tasks: u32,
-    #[prost(uint32, tag = "2")]
-    pub completed_tasks: u32,
-    #[prost(uint32, tag = "3")]
-    pub in_progress_tasks: u32,
-    #[prost(uint32, tag = "4")]
-    pub todo_tasks: u32,
+    #[prost(uint64, tag = "1")]
+    pub total_tasks: u64,
+    #[prost(uint64, tag = "2")]
+    pub completed_tasks: u64,
+    #[prost(uint64, tag = "3")]
+    pub in_progress_tasks: u64,
+    #[prost(uint64, tag = "4")]
+    pub todo_tasks: u64,
     #[prost(float, tag = "5")]
     pub completion_rate: f32,
     #[prost(float, tag = "6")]
     pub average_completion_time_hours: f32,
-    #[prost(uint32, tag = "7")]
-    pub overdue_tasks: u32,
-    #[prost(map = "int32, uint32", tag = "8")]
-    pub tasks_by_priority: ::std::collections::HashMap<i32, u32>,
-    #[prost(uint32, tag = "9")]
-    pub tasks_created_this_week: u32,
-    #[prost(uint32, tag = "10")]
-    pub tasks_completed_this_week: u32,
+    #[prost(uint64, tag = "7")]
+    pub overdue_tasks: u64,
+    #[prost(map = "int32, uint64", tag = "8")]
+    pub tasks_by_priority: ::std::collections::HashMap<i32, u64>,
+    #[prost(uint64, tag = "9")]
+    pub tasks_created_this_week: u64,
+    #[prost(uint64, tag = "10")]
+    pub tasks_completed_this_week: u64,
+    #[prost(uint64, tag = "11")]
+    pub due_soon_tasks: u64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskMetricPoint {
     #[prost(string, tag = "1")]
     pub label: ::prost::alloc::string::String,
-    #[prost(int32, tag = "2")]
-    pub count: i32,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
     #[prost(double, tag = "3")]
     pub value: f64,
     #[prost(message, optional, tag = "4")]
     pub timestamp: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
+/// Open and finished work per assignee
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWorkloadRequest {
+    /// Empty = everyone with assigned tasks
+    #[prost(string, repeated, tag = "1")]
+    pub user_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWorkloadResponse {
+    /// Busiest first
+    #[prost(message, repeated, tag = "1")]
+    pub workloads: ::prost::alloc::vec::Vec<AssigneeWorkload>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssigneeWorkload {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Empty if the user no longer exists
+    #[prost(string, tag = "2")]
+    pub username: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub total_tasks: u64,
+    /// Keyed by TaskStatus
+    #[prost(map = "int32, uint64", tag = "4")]
+    pub tasks_by_status: ::std::collections::HashMap<i32, u64>,
+    #[prost(int64, tag = "5")]
+    pub estimated_hours: i64,
+    #[prost(int64, tag = "6")]
+    pub actual_hours: i64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskTimeseriesRequest {
+    #[prost(enumeration = "TimeseriesMetric", tag = "1")]
+    pub metric: i32,
+    /// YYYY-MM-DD in UTC; defaults to 29 days before `to`
+    #[prost(string, tag = "2")]
+    pub from: ::prost::alloc::string::String,
+    /// YYYY-MM-DD in UTC, inclusive; defaults to today
+    #[prost(string, tag = "3")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(enumeration = "TimeseriesBucket", tag = "4")]
+    pub bucket: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskTimeseriesResponse {
+    /// Every bucket in the range, oldest first
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<TimeseriesPoint>,
+    #[prost(uint64, tag = "2")]
+    pub total: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeseriesPoint {
+    /// First day of the bucket, YYYY-MM-DD
+    #[prost(string, tag = "1")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TaskSummary {
-    #[prost(int32, tag = "1")]
-    pub total_tasks: i32,
-    #[prost(int32, tag = "2")]
-    pub completed_tasks: i32,
+    #[prost(uint64, tag = "1")]
+    pub total_tasks: u64,
+    #[prost(uint64, tag = "2")]
+    pub completed_tasks: u64,
     #[prost(double, tag = "3")]
     pub completion_rate: f64,
     #[prost(double, tag = "4")]
     pub average_completion_time_hours: f64,
-    #[prost(int32, tag = "5")]
-    pub overdue_tasks: i32,
+    #[prost(uint64, tag = "5")]
+    pub overdue_tasks: u64,
 }
 /// File upload/attachment support
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UploadTaskAttachmentRequest {
@@ -464,6 +1185,8 @@ pub struct UploadTaskAttachmentRequest {
     pub is_final_chunk: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UploadTaskAttachmentResponse {
@@ -479,6 +1202,8 @@ pub struct UploadTaskAttachmentResponse {
     pub message: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateUserRequest {
@@ -492,8 +1217,14 @@ pub struct CreateUserRequest {
     pub full_name: ::prost::alloc::string::String,
     #[prost(enumeration = "UserRole", tag = "5")]
     pub role: i32,
+    /// Theme, language and timezone left empty take the server defaults;
+    /// notifications always start enabled
+    #[prost(message, optional, tag = "6")]
+    pub preferences: ::core::option::Option<UserPreferences>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateUserResponse {
@@ -504,7 +1235,47 @@ pub struct CreateUserResponse {
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
 }
+/// Admin-only: create many users at once; each is validated like CreateUser
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateUsersRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub users: ::prost::alloc::vec::Vec<CreateUserRequest>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateUsersResponse {
+    #[prost(uint64, tag = "1")]
+    pub created_count: u64,
+    /// One per requested user, in request order
+    #[prost(message, repeated, tag = "2")]
+    pub results: ::prost::alloc::vec::Vec<BatchCreateUserResult>,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateUserResult {
+    /// Unset unless created
+    #[prost(message, optional, tag = "1")]
+    pub user: ::core::option::Option<User>,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(enumeration = "BatchCreateUserError", tag = "3")]
+    pub error: i32,
+    #[prost(string, tag = "4")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetUserRequest {
@@ -512,6 +1283,8 @@ pub struct GetUserRequest {
     pub id: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetUserResponse {
@@ -521,6 +1294,8 @@ pub struct GetUserResponse {
     pub found: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListUsersRequest {
@@ -534,6 +1309,8 @@ pub struct ListUsersRequest {
     pub active_only: bool,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListUsersResponse {
@@ -541,10 +1318,17 @@ pub struct ListUsersResponse {
     pub users: ::prost::alloc::vec::Vec<User>,
     #[prost(string, tag = "2")]
     pub next_page_token: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub total_count: i32,
+    #[prost(uint64, tag = "3")]
+    pub total_count: u64,
+    /// One-based
+    #[prost(uint64, tag = "4")]
+    pub current_page: u64,
+    #[prost(uint64, tag = "5")]
+    pub total_pages: u64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateUserRequest {
@@ -554,6 +1338,8 @@ pub struct UpdateUserRequest {
     pub user: ::core::option::Option<User>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateUserResponse {
@@ -565,13 +1351,24 @@ pub struct UpdateUserResponse {
     pub message: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteUserRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Admin only: also strip the user from every task, comment, project and
+    /// retained event, and deal with their tasks per the server's purge policy
+    #[prost(bool, tag = "2")]
+    pub purge: bool,
+    /// With purge: hand their tasks to this user instead
+    #[prost(string, tag = "3")]
+    pub reassign_to: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteUserResponse {
@@ -579,8 +1376,16 @@ pub struct DeleteUserResponse {
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    /// Purge only: tasks changed or deleted
+    #[prost(uint64, tag = "3")]
+    pub affected_tasks: u64,
+    /// Purge only
+    #[prost(uint64, tag = "4")]
+    pub anonymized_comments: u64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthenticateUserRequest {
@@ -590,6 +1395,8 @@ pub struct AuthenticateUserRequest {
     pub password: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthenticateUserResponse {
@@ -605,6 +1412,8 @@ pub struct AuthenticateUserResponse {
     pub expires_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetUserTasksRequest {
@@ -616,6 +1425,8 @@ pub struct GetUserTasksRequest {
     pub page_token: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetUserTasksResponse {
@@ -623,10 +1434,17 @@ pub struct GetUserTasksResponse {
     pub tasks: ::prost::alloc::vec::Vec<Task>,
     #[prost(string, tag = "2")]
     pub next_page_token: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub total_count: i32,
+    #[prost(uint64, tag = "3")]
+    pub total_count: u64,
+    /// One-based
+    #[prost(uint64, tag = "4")]
+    pub current_page: u64,
+    #[prost(uint64, tag = "5")]
+    pub total_pages: u64,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateUserPreferencesRequest {
@@ -636,6 +1454,8 @@ pub struct UpdateUserPreferencesRequest {
     pub preferences: ::core::option::Option<UserPreferences>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateUserPreferencesResponse {
@@ -646,8 +1466,37 @@ pub struct UpdateUserPreferencesResponse {
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
 }
+/// Admin-only: move every task assigned to one user onto another
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReassignUserTasksRequest {
+    #[prost(string, tag = "1")]
+    pub from_user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub to_user_id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReassignUserTasksResponse {
+    #[prost(uint64, tag = "1")]
+    pub reassigned_count: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub task_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "3")]
+    pub success: bool,
+    #[prost(string, tag = "4")]
+    pub message: ::prost::alloc::string::String,
+}
 /// Legacy login messages (keeping for backward compatibility)
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoginRequest {
@@ -657,6 +1506,8 @@ pub struct LoginRequest {
     pub password: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoginResponse {
@@ -670,6 +1521,8 @@ pub struct LoginResponse {
     pub expires_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RefreshTokenRequest {
@@ -677,6 +1530,8 @@ pub struct RefreshTokenRequest {
     pub refresh_token: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RefreshTokenResponse {
@@ -686,6 +1541,8 @@ pub struct RefreshTokenResponse {
     pub expires_at: ::core::option::Option<crate::types::SerdeTimestamp>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LogoutRequest {
@@ -693,32 +1550,158 @@ pub struct LogoutRequest {
     pub access_token: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct HealthResponse {
-    #[prost(bool, tag = "1")]
-    pub healthy: bool,
+pub struct CreateProjectRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
-    pub version: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "3")]
-    pub timestamp: ::core::option::Option<crate::types::SerdeTimestamp>,
+    pub description: ::prost::alloc::string::String,
 }
-/// Enums for better type safety
 #[derive(serde::Serialize, serde::Deserialize)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum TaskStatus {
-    Unspecified = 0,
-    Todo = 1,
-    InProgress = 2,
-    Review = 3,
-    Done = 4,
-    Cancelled = 5,
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectResponse {
+    #[prost(message, optional, tag = "1")]
+    pub project: ::core::option::Option<Project>,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
 }
-impl TaskStatus {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProjectRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProjectResponse {
+    #[prost(message, optional, tag = "1")]
+    pub project: ::core::option::Option<Project>,
+    #[prost(bool, tag = "2")]
+    pub found: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProjectsRequest {
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+    #[prost(string, tag = "2")]
+    pub page_token: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProjectsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub projects: ::prost::alloc::vec::Vec<Project>,
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub total_count: u64,
+    /// One-based
+    #[prost(uint64, tag = "4")]
+    pub current_page: u64,
+    #[prost(uint64, tag = "5")]
+    pub total_pages: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateProjectRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteProjectRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteProjectResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// Tasks unassigned or deleted, depending on the server's policy
+    #[prost(uint64, tag = "3")]
+    pub affected_tasks: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProjectTasksRequest {
+    #[prost(string, tag = "1")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HealthResponse {
+    #[prost(bool, tag = "1")]
+    pub healthy: bool,
+    #[prost(string, tag = "2")]
+    pub version: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<crate::types::SerdeTimestamp>,
+}
+/// Enums for better type safety
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskStatus {
+    Unspecified = 0,
+    Todo = 1,
+    InProgress = 2,
+    Review = 3,
+    Done = 4,
+    Cancelled = 5,
+    Backlog = 6,
+}
+impl TaskStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
@@ -728,6 +1711,7 @@ impl TaskStatus {
             TaskStatus::Review => "TASK_STATUS_REVIEW",
             TaskStatus::Done => "TASK_STATUS_DONE",
             TaskStatus::Cancelled => "TASK_STATUS_CANCELLED",
+            TaskStatus::Backlog => "TASK_STATUS_BACKLOG",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -739,11 +1723,13 @@ impl TaskStatus {
             "TASK_STATUS_REVIEW" => Some(Self::Review),
             "TASK_STATUS_DONE" => Some(Self::Done),
             "TASK_STATUS_CANCELLED" => Some(Self::Cancelled),
+            "TASK_STATUS_BACKLOG" => Some(Self::Backlog),
             _ => None,
         }
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TaskPriority {
@@ -780,6 +1766,7 @@ impl TaskPriority {
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum UserRole {
@@ -813,6 +1800,7 @@ impl UserRole {
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum UserStatus {
@@ -846,6 +1834,7 @@ impl UserStatus {
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TaskSortField {
@@ -855,6 +1844,8 @@ pub enum TaskSortField {
     DueDate = 3,
     Priority = 4,
     Title = 5,
+    CommentCount = 6,
+    AttachmentCount = 7,
 }
 impl TaskSortField {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -869,6 +1860,8 @@ impl TaskSortField {
             TaskSortField::DueDate => "TASK_SORT_FIELD_DUE_DATE",
             TaskSortField::Priority => "TASK_SORT_FIELD_PRIORITY",
             TaskSortField::Title => "TASK_SORT_FIELD_TITLE",
+            TaskSortField::CommentCount => "TASK_SORT_FIELD_COMMENT_COUNT",
+            TaskSortField::AttachmentCount => "TASK_SORT_FIELD_ATTACHMENT_COUNT",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -880,11 +1873,14 @@ impl TaskSortField {
             "TASK_SORT_FIELD_DUE_DATE" => Some(Self::DueDate),
             "TASK_SORT_FIELD_PRIORITY" => Some(Self::Priority),
             "TASK_SORT_FIELD_TITLE" => Some(Self::Title),
+            "TASK_SORT_FIELD_COMMENT_COUNT" => Some(Self::CommentCount),
+            "TASK_SORT_FIELD_ATTACHMENT_COUNT" => Some(Self::AttachmentCount),
             _ => None,
         }
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SortDirection {
@@ -915,6 +1911,50 @@ impl SortDirection {
     }
 }
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BulkUpdateError {
+    Unspecified = 0,
+    NotFound = 1,
+    Storage = 2,
+    /// The change would break a limit, e.g. too many tags
+    Invalid = 3,
+    /// The new assignee already holds their maximum number of tasks
+    QuotaExceeded = 4,
+    /// The workflow doesn't allow the task's move to the new status
+    InvalidTransition = 5,
+}
+impl BulkUpdateError {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BulkUpdateError::Unspecified => "BULK_UPDATE_ERROR_UNSPECIFIED",
+            BulkUpdateError::NotFound => "BULK_UPDATE_ERROR_NOT_FOUND",
+            BulkUpdateError::Storage => "BULK_UPDATE_ERROR_STORAGE",
+            BulkUpdateError::Invalid => "BULK_UPDATE_ERROR_INVALID",
+            BulkUpdateError::QuotaExceeded => "BULK_UPDATE_ERROR_QUOTA_EXCEEDED",
+            BulkUpdateError::InvalidTransition => "BULK_UPDATE_ERROR_INVALID_TRANSITION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BULK_UPDATE_ERROR_UNSPECIFIED" => Some(Self::Unspecified),
+            "BULK_UPDATE_ERROR_NOT_FOUND" => Some(Self::NotFound),
+            "BULK_UPDATE_ERROR_STORAGE" => Some(Self::Storage),
+            "BULK_UPDATE_ERROR_INVALID" => Some(Self::Invalid),
+            "BULK_UPDATE_ERROR_QUOTA_EXCEEDED" => Some(Self::QuotaExceeded),
+            "BULK_UPDATE_ERROR_INVALID_TRANSITION" => Some(Self::InvalidTransition),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TaskEventType {
@@ -925,6 +1965,18 @@ pub enum TaskEventType {
     Assigned = 4,
     Completed = 5,
     Commented = 6,
+    /// Last event before the server closes the stream
+    ServerShutdown = 7,
+    /// metadata carries mentioned_user_id and comment_id
+    Mentioned = 8,
+    /// Sent for the merge target; metadata carries source_id
+    Merged = 9,
+    /// metadata carries reminder_id, user_id and before_secs
+    Reminder = 10,
+    /// Moved out of the active tasks, by ArchiveTask or the archive job
+    Archived = 11,
+    /// Moved back from the archive by RestoreArchivedTask
+    Restored = 12,
 }
 impl TaskEventType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -940,6 +1992,12 @@ impl TaskEventType {
             TaskEventType::Assigned => "TASK_EVENT_TYPE_ASSIGNED",
             TaskEventType::Completed => "TASK_EVENT_TYPE_COMPLETED",
             TaskEventType::Commented => "TASK_EVENT_TYPE_COMMENTED",
+            TaskEventType::ServerShutdown => "TASK_EVENT_TYPE_SERVER_SHUTDOWN",
+            TaskEventType::Mentioned => "TASK_EVENT_TYPE_MENTIONED",
+            TaskEventType::Merged => "TASK_EVENT_TYPE_MERGED",
+            TaskEventType::Reminder => "TASK_EVENT_TYPE_REMINDER",
+            TaskEventType::Archived => "TASK_EVENT_TYPE_ARCHIVED",
+            TaskEventType::Restored => "TASK_EVENT_TYPE_RESTORED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -952,6 +2010,124 @@ impl TaskEventType {
             "TASK_EVENT_TYPE_ASSIGNED" => Some(Self::Assigned),
             "TASK_EVENT_TYPE_COMPLETED" => Some(Self::Completed),
             "TASK_EVENT_TYPE_COMMENTED" => Some(Self::Commented),
+            "TASK_EVENT_TYPE_SERVER_SHUTDOWN" => Some(Self::ServerShutdown),
+            "TASK_EVENT_TYPE_MENTIONED" => Some(Self::Mentioned),
+            "TASK_EVENT_TYPE_MERGED" => Some(Self::Merged),
+            "TASK_EVENT_TYPE_REMINDER" => Some(Self::Reminder),
+            "TASK_EVENT_TYPE_ARCHIVED" => Some(Self::Archived),
+            "TASK_EVENT_TYPE_RESTORED" => Some(Self::Restored),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TimeseriesMetric {
+    Unspecified = 0,
+    /// By created_at
+    Created = 1,
+    /// Done tasks, by updated_at
+    Completed = 2,
+}
+impl TimeseriesMetric {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeseriesMetric::Unspecified => "TIMESERIES_METRIC_UNSPECIFIED",
+            TimeseriesMetric::Created => "TIMESERIES_METRIC_CREATED",
+            TimeseriesMetric::Completed => "TIMESERIES_METRIC_COMPLETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TIMESERIES_METRIC_UNSPECIFIED" => Some(Self::Unspecified),
+            "TIMESERIES_METRIC_CREATED" => Some(Self::Created),
+            "TIMESERIES_METRIC_COMPLETED" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TimeseriesBucket {
+    /// Same as DAY
+    Unspecified = 0,
+    Day = 1,
+    /// Seven days from `from`
+    Week = 2,
+}
+impl TimeseriesBucket {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeseriesBucket::Unspecified => "TIMESERIES_BUCKET_UNSPECIFIED",
+            TimeseriesBucket::Day => "TIMESERIES_BUCKET_DAY",
+            TimeseriesBucket::Week => "TIMESERIES_BUCKET_WEEK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TIMESERIES_BUCKET_UNSPECIFIED" => Some(Self::Unspecified),
+            "TIMESERIES_BUCKET_DAY" => Some(Self::Day),
+            "TIMESERIES_BUCKET_WEEK" => Some(Self::Week),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BatchCreateUserError {
+    Unspecified = 0,
+    /// Bad email, weak password, unknown role, ...
+    Invalid = 1,
+    /// Email or username is already taken
+    AlreadyExists = 2,
+    /// Email or username repeats an earlier entry in the same batch
+    Duplicate = 3,
+    Storage = 4,
+    /// The server already holds its maximum number of users
+    LimitReached = 5,
+}
+impl BatchCreateUserError {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BatchCreateUserError::Unspecified => "BATCH_CREATE_USER_ERROR_UNSPECIFIED",
+            BatchCreateUserError::Invalid => "BATCH_CREATE_USER_ERROR_INVALID",
+            BatchCreateUserError::AlreadyExists => {
+                "BATCH_CREATE_USER_ERROR_ALREADY_EXISTS"
+            }
+            BatchCreateUserError::Duplicate => "BATCH_CREATE_USER_ERROR_DUPLICATE",
+            BatchCreateUserError::Storage => "BATCH_CREATE_USER_ERROR_STORAGE",
+            BatchCreateUserError::LimitReached => "BATCH_CREATE_USER_ERROR_LIMIT_REACHED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BATCH_CREATE_USER_ERROR_UNSPECIFIED" => Some(Self::Unspecified),
+            "BATCH_CREATE_USER_ERROR_INVALID" => Some(Self::Invalid),
+            "BATCH_CREATE_USER_ERROR_ALREADY_EXISTS" => Some(Self::AlreadyExists),
+            "BATCH_CREATE_USER_ERROR_DUPLICATE" => Some(Self::Duplicate),
+            "BATCH_CREATE_USER_ERROR_STORAGE" => Some(Self::Storage),
+            "BATCH_CREATE_USER_ERROR_LIMIT_REACHED" => Some(Self::LimitReached),
             _ => None,
         }
     }
@@ -1066,11 +2242,13 @@ pub mod task_service_client {
                 .insert(GrpcMethod::new("example.TaskService", "CreateTask"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_task(
+        /// Create a task, or return the existing one with the same natural key
+        /// (by default title and assignee)
+        pub async fn upsert_task(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+            request: impl tonic::IntoRequest<super::CreateTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetTaskResponse>,
+            tonic::Response<super::UpsertTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1084,18 +2262,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/GetTask",
+                "/example.TaskService/UpsertTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "GetTask"));
+                .insert(GrpcMethod::new("example.TaskService", "UpsertTask"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_task(
+        pub async fn get_task(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateTaskRequest>,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Response<super::GetTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1109,18 +2287,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/UpdateTask",
+                "/example.TaskService/GetTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "UpdateTask"));
+                .insert(GrpcMethod::new("example.TaskService", "GetTask"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_task(
+        pub async fn get_task_by_number(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeleteTaskRequest>,
+            request: impl tonic::IntoRequest<super::GetTaskByNumberRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteTaskResponse>,
+            tonic::Response<super::GetTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1134,18 +2312,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/DeleteTask",
+                "/example.TaskService/GetTaskByNumber",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "DeleteTask"));
+                .insert(GrpcMethod::new("example.TaskService", "GetTaskByNumber"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_tasks(
+        pub async fn update_task(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListTasksRequest>,
+            request: impl tonic::IntoRequest<super::UpdateTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListTasksResponse>,
+            tonic::Response<super::UpdateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1159,18 +2337,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/ListTasks",
+                "/example.TaskService/UpdateTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "ListTasks"));
+                .insert(GrpcMethod::new("example.TaskService", "UpdateTask"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn search_tasks(
+        pub async fn update_task_status(
             &mut self,
-            request: impl tonic::IntoRequest<super::SearchTasksRequest>,
+            request: impl tonic::IntoRequest<super::UpdateTaskStatusRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SearchTasksResponse>,
+            tonic::Response<super::UpdateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1184,18 +2362,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/SearchTasks",
+                "/example.TaskService/UpdateTaskStatus",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "SearchTasks"));
+                .insert(GrpcMethod::new("example.TaskService", "UpdateTaskStatus"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn bulk_update_tasks(
+        pub async fn delete_task(
             &mut self,
-            request: impl tonic::IntoRequest<super::BulkUpdateTasksRequest>,
+            request: impl tonic::IntoRequest<super::DeleteTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::BulkUpdateTasksResponse>,
+            tonic::Response<super::DeleteTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1209,18 +2387,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/BulkUpdateTasks",
+                "/example.TaskService/DeleteTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "BulkUpdateTasks"));
+                .insert(GrpcMethod::new("example.TaskService", "DeleteTask"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn stream_task_events(
+        pub async fn archive_task(
             &mut self,
-            request: impl tonic::IntoRequest<super::StreamTaskEventsRequest>,
+            request: impl tonic::IntoRequest<super::ArchiveTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::TaskEvent>>,
+            tonic::Response<super::UpdateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1234,18 +2412,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/StreamTaskEvents",
+                "/example.TaskService/ArchiveTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "StreamTaskEvents"));
-            self.inner.server_streaming(req, path, codec).await
+                .insert(GrpcMethod::new("example.TaskService", "ArchiveTask"));
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn import_tasks(
+        pub async fn list_archived_tasks(
             &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::CreateTaskRequest>,
+            request: impl tonic::IntoRequest<super::ListArchivedTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::CreateTaskResponse>>,
+            tonic::Response<super::ListTasksResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1259,18 +2437,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/ImportTasks",
+                "/example.TaskService/ListArchivedTasks",
             );
-            let mut req = request.into_streaming_request();
+            let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "ImportTasks"));
-            self.inner.streaming(req, path, codec).await
+                .insert(GrpcMethod::new("example.TaskService", "ListArchivedTasks"));
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn collaborate_on_tasks(
+        pub async fn restore_archived_task(
             &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::TaskEvent>,
+            request: impl tonic::IntoRequest<super::RestoreArchivedTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::TaskEvent>>,
+            tonic::Response<super::UpdateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1284,20 +2462,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/CollaborateOnTasks",
+                "/example.TaskService/RestoreArchivedTask",
             );
-            let mut req = request.into_streaming_request();
+            let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "CollaborateOnTasks"));
-            self.inner.streaming(req, path, codec).await
+                .insert(GrpcMethod::new("example.TaskService", "RestoreArchivedTask"));
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn upload_task_attachment(
+        pub async fn clone_task(
             &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::UploadTaskAttachmentRequest,
-            >,
+            request: impl tonic::IntoRequest<super::CloneTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UploadTaskAttachmentResponse>,
+            tonic::Response<super::CreateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1311,18 +2487,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/UploadTaskAttachment",
+                "/example.TaskService/CloneTask",
             );
-            let mut req = request.into_streaming_request();
+            let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "UploadTaskAttachment"));
-            self.inner.client_streaming(req, path, codec).await
+                .insert(GrpcMethod::new("example.TaskService", "CloneTask"));
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn get_task_analytics(
+        pub async fn merge_tasks(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetTaskAnalyticsRequest>,
+            request: impl tonic::IntoRequest<super::MergeTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetTaskAnalyticsResponse>,
+            tonic::Response<super::UpdateTaskResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1336,17 +2512,20 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/GetTaskAnalytics",
+                "/example.TaskService/MergeTasks",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "GetTaskAnalytics"));
+                .insert(GrpcMethod::new("example.TaskService", "MergeTasks"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn health(
+        pub async fn snooze_task(
             &mut self,
-            request: impl tonic::IntoRequest<()>,
-        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::SnoozeTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1358,105 +2537,18 @@ pub mod task_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.TaskService/Health",
+                "/example.TaskService/SnoozeTask",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.TaskService", "Health"));
+                .insert(GrpcMethod::new("example.TaskService", "SnoozeTask"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated client implementations.
-pub mod user_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    #[derive(Debug, Clone)]
-    pub struct UserServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl UserServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> UserServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> UserServiceClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
-        {
-            UserServiceClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        pub async fn create_user(
+        pub async fn set_reminder(
             &mut self,
-            request: impl tonic::IntoRequest<super::CreateUserRequest>,
+            request: impl tonic::IntoRequest<super::SetReminderRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateUserResponse>,
+            tonic::Response<super::SetReminderResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1470,18 +2562,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/CreateUser",
+                "/example.TaskService/SetReminder",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "CreateUser"));
+                .insert(GrpcMethod::new("example.TaskService", "SetReminder"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_user(
+        pub async fn add_comment(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetUserRequest>,
+            request: impl tonic::IntoRequest<super::AddCommentRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
+            tonic::Response<super::CommentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1495,18 +2587,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/GetUser",
+                "/example.TaskService/AddComment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "GetUser"));
+                .insert(GrpcMethod::new("example.TaskService", "AddComment"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_users(
+        pub async fn edit_comment(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListUsersRequest>,
+            request: impl tonic::IntoRequest<super::EditCommentRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListUsersResponse>,
+            tonic::Response<super::CommentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1520,18 +2612,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/ListUsers",
+                "/example.TaskService/EditComment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "ListUsers"));
+                .insert(GrpcMethod::new("example.TaskService", "EditComment"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_user(
+        pub async fn delete_comment(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateUserRequest>,
+            request: impl tonic::IntoRequest<super::DeleteCommentRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateUserResponse>,
+            tonic::Response<super::DeleteCommentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1545,18 +2637,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/UpdateUser",
+                "/example.TaskService/DeleteComment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "UpdateUser"));
+                .insert(GrpcMethod::new("example.TaskService", "DeleteComment"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_user(
+        pub async fn list_task_comments(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeleteUserRequest>,
+            request: impl tonic::IntoRequest<super::ListTaskCommentsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteUserResponse>,
+            tonic::Response<super::ListTaskCommentsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1570,17 +2662,20 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/DeleteUser",
+                "/example.TaskService/ListTaskComments",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "DeleteUser"));
+                .insert(GrpcMethod::new("example.TaskService", "ListTaskComments"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn login(
+        pub async fn list_tasks(
             &mut self,
-            request: impl tonic::IntoRequest<super::LoginRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::ListTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1592,17 +2687,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/Login",
+                "/example.TaskService/ListTasks",
             );
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("example.UserService", "Login"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "ListTasks"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn refresh_token(
+        pub async fn search_tasks(
             &mut self,
-            request: impl tonic::IntoRequest<super::RefreshTokenRequest>,
+            request: impl tonic::IntoRequest<super::SearchTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Response<super::SearchTasksResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1616,17 +2712,20 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/RefreshToken",
+                "/example.TaskService/SearchTasks",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "RefreshToken"));
+                .insert(GrpcMethod::new("example.TaskService", "SearchTasks"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn logout(
+        pub async fn create_saved_view(
             &mut self,
-            request: impl tonic::IntoRequest<super::LogoutRequest>,
-        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::CreateSavedViewRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateSavedViewResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1638,18 +2737,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/Logout",
+                "/example.TaskService/CreateSavedView",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "Logout"));
+                .insert(GrpcMethod::new("example.TaskService", "CreateSavedView"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn authenticate_user(
+        pub async fn list_saved_views(
             &mut self,
-            request: impl tonic::IntoRequest<super::AuthenticateUserRequest>,
+            request: impl tonic::IntoRequest<super::ListSavedViewsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AuthenticateUserResponse>,
+            tonic::Response<super::ListSavedViewsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1663,18 +2762,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/AuthenticateUser",
+                "/example.TaskService/ListSavedViews",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "AuthenticateUser"));
+                .insert(GrpcMethod::new("example.TaskService", "ListSavedViews"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_user_tasks(
+        pub async fn apply_saved_view(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetUserTasksRequest>,
+            request: impl tonic::IntoRequest<super::ApplySavedViewRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetUserTasksResponse>,
+            tonic::Response<super::ListTasksResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1688,18 +2787,18 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/GetUserTasks",
+                "/example.TaskService/ApplySavedView",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "GetUserTasks"));
+                .insert(GrpcMethod::new("example.TaskService", "ApplySavedView"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_user_preferences(
+        pub async fn bulk_update_tasks(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateUserPreferencesRequest>,
+            request: impl tonic::IntoRequest<super::BulkUpdateTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateUserPreferencesResponse>,
+            tonic::Response<super::BulkUpdateTasksResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1713,128 +2812,3196 @@ pub mod user_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/example.UserService/UpdateUserPreferences",
+                "/example.TaskService/BulkUpdateTasks",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("example.UserService", "UpdateUserPreferences"));
+                .insert(GrpcMethod::new("example.TaskService", "BulkUpdateTasks"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod task_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with TaskServiceServer.
-    #[async_trait]
-    pub trait TaskService: Send + Sync + 'static {
-        async fn create_task(
-            &self,
-            request: tonic::Request<super::CreateTaskRequest>,
+        pub async fn bulk_update_tasks_by_filter(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkUpdateTasksByFilterRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateTaskResponse>,
+            tonic::Response<super::BulkUpdateTasksResponse>,
             tonic::Status,
-        >;
-        async fn get_task(
-            &self,
-            request: tonic::Request<super::GetTaskRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetTaskResponse>, tonic::Status>;
-        async fn update_task(
-            &self,
-            request: tonic::Request<super::UpdateTaskRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/BulkUpdateTasksByFilter",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("example.TaskService", "BulkUpdateTasksByFilter"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn bulk_tag_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkTagTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Response<super::BulkUpdateTasksResponse>,
             tonic::Status,
-        >;
-        async fn delete_task(
-            &self,
-            request: tonic::Request<super::DeleteTaskRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/BulkTagTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "BulkTagTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_tags(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTagsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteTaskResponse>,
+            tonic::Response<super::ListTagsResponse>,
             tonic::Status,
-        >;
-        async fn list_tasks(
-            &self,
-            request: tonic::Request<super::ListTasksRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListTasksResponse>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/ListTags",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "ListTags"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn rename_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenameTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenameTagResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/RenameTag",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "RenameTag"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn stream_task_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamTaskEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TaskEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/StreamTaskEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "StreamTaskEvents"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn stream_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Task>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/StreamTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "StreamTasks"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn import_tasks(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::CreateTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::CreateTaskResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/ImportTasks",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "ImportTasks"));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn collaborate_on_tasks(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::TaskEvent>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TaskEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/CollaborateOnTasks",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "CollaborateOnTasks"));
+            self.inner.streaming(req, path, codec).await
+        }
+        pub async fn upload_task_attachment(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::UploadTaskAttachmentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::UploadTaskAttachmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/UploadTaskAttachment",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "UploadTaskAttachment"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn get_task_analytics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskAnalyticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTaskAnalyticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/GetTaskAnalytics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "GetTaskAnalytics"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_workload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetWorkloadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWorkloadResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/GetWorkload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "GetWorkload"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task_timeseries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskTimeseriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTaskTimeseriesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/GetTaskTimeseries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "GetTaskTimeseries"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn health(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.TaskService/Health",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.TaskService", "Health"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod user_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct UserServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl UserServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> UserServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> UserServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            UserServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn create_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/CreateUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "CreateUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn batch_create_users(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchCreateUsersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchCreateUsersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/BatchCreateUsers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "BatchCreateUsers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/GetUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "GetUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_users(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListUsersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListUsersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/ListUsers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "ListUsers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/UpdateUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "UpdateUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/DeleteUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "DeleteUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn login(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoginRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/Login",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("example.UserService", "Login"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn refresh_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RefreshTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RefreshTokenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/RefreshToken",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "RefreshToken"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn logout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/Logout",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "Logout"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn authenticate_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthenticateUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthenticateUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/AuthenticateUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "AuthenticateUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetUserTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/GetUserTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "GetUserTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_user_preferences(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateUserPreferencesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateUserPreferencesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/UpdateUserPreferences",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "UpdateUserPreferences"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn reassign_user_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReassignUserTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReassignUserTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.UserService/ReassignUserTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.UserService", "ReassignUserTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod project_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct ProjectServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ProjectServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ProjectServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ProjectServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            ProjectServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn create_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/CreateProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "CreateProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/GetProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "GetProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_projects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProjectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProjectsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/ListProjects",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "ListProjects"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/UpdateProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "UpdateProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/DeleteProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "DeleteProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_project_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProjectTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/example.ProjectService/ListProjectTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("example.ProjectService", "ListProjectTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod task_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TaskServiceServer.
+    #[async_trait]
+    pub trait TaskService: Send + Sync + 'static {
+        async fn create_task(
+            &self,
+            request: tonic::Request<super::CreateTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTaskResponse>,
+            tonic::Status,
+        >;
+        /// Create a task, or return the existing one with the same natural key
+        /// (by default title and assignee)
+        async fn upsert_task(
+            &self,
+            request: tonic::Request<super::CreateTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpsertTaskResponse>,
+            tonic::Status,
+        >;
+        async fn get_task(
+            &self,
+            request: tonic::Request<super::GetTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTaskResponse>, tonic::Status>;
+        async fn get_task_by_number(
+            &self,
+            request: tonic::Request<super::GetTaskByNumberRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTaskResponse>, tonic::Status>;
+        async fn update_task(
+            &self,
+            request: tonic::Request<super::UpdateTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn update_task_status(
+            &self,
+            request: tonic::Request<super::UpdateTaskStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn delete_task(
+            &self,
+            request: tonic::Request<super::DeleteTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteTaskResponse>,
+            tonic::Status,
+        >;
+        async fn archive_task(
+            &self,
+            request: tonic::Request<super::ArchiveTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn list_archived_tasks(
+            &self,
+            request: tonic::Request<super::ListArchivedTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        >;
+        async fn restore_archived_task(
+            &self,
+            request: tonic::Request<super::RestoreArchivedTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn clone_task(
+            &self,
+            request: tonic::Request<super::CloneTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn merge_tasks(
+            &self,
+            request: tonic::Request<super::MergeTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn snooze_task(
+            &self,
+            request: tonic::Request<super::SnoozeTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateTaskResponse>,
+            tonic::Status,
+        >;
+        async fn set_reminder(
+            &self,
+            request: tonic::Request<super::SetReminderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetReminderResponse>,
+            tonic::Status,
+        >;
+        async fn add_comment(
+            &self,
+            request: tonic::Request<super::AddCommentRequest>,
+        ) -> std::result::Result<tonic::Response<super::CommentResponse>, tonic::Status>;
+        async fn edit_comment(
+            &self,
+            request: tonic::Request<super::EditCommentRequest>,
+        ) -> std::result::Result<tonic::Response<super::CommentResponse>, tonic::Status>;
+        async fn delete_comment(
+            &self,
+            request: tonic::Request<super::DeleteCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteCommentResponse>,
+            tonic::Status,
+        >;
+        async fn list_task_comments(
+            &self,
+            request: tonic::Request<super::ListTaskCommentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTaskCommentsResponse>,
+            tonic::Status,
+        >;
+        async fn list_tasks(
+            &self,
+            request: tonic::Request<super::ListTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        >;
+        async fn search_tasks(
+            &self,
+            request: tonic::Request<super::SearchTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchTasksResponse>,
+            tonic::Status,
+        >;
+        async fn create_saved_view(
+            &self,
+            request: tonic::Request<super::CreateSavedViewRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateSavedViewResponse>,
+            tonic::Status,
+        >;
+        async fn list_saved_views(
+            &self,
+            request: tonic::Request<super::ListSavedViewsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSavedViewsResponse>,
+            tonic::Status,
+        >;
+        async fn apply_saved_view(
+            &self,
+            request: tonic::Request<super::ApplySavedViewRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        >;
+        async fn bulk_update_tasks(
+            &self,
+            request: tonic::Request<super::BulkUpdateTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkUpdateTasksResponse>,
+            tonic::Status,
+        >;
+        async fn bulk_update_tasks_by_filter(
+            &self,
+            request: tonic::Request<super::BulkUpdateTasksByFilterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkUpdateTasksResponse>,
+            tonic::Status,
+        >;
+        async fn bulk_tag_tasks(
+            &self,
+            request: tonic::Request<super::BulkTagTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkUpdateTasksResponse>,
+            tonic::Status,
+        >;
+        async fn list_tags(
+            &self,
+            request: tonic::Request<super::ListTagsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTagsResponse>,
+            tonic::Status,
+        >;
+        async fn rename_tag(
+            &self,
+            request: tonic::Request<super::RenameTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenameTagResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamTaskEvents method.
+        type StreamTaskEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::TaskEvent, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn stream_task_events(
+            &self,
+            request: tonic::Request<super::StreamTaskEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamTaskEventsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamTasks method.
+        type StreamTasksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Task, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn stream_tasks(
+            &self,
+            request: tonic::Request<super::StreamTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamTasksStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ImportTasks method.
+        type ImportTasksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::CreateTaskResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn import_tasks(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::CreateTaskRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ImportTasksStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the CollaborateOnTasks method.
+        type CollaborateOnTasksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::TaskEvent, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn collaborate_on_tasks(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::TaskEvent>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::CollaborateOnTasksStream>,
+            tonic::Status,
+        >;
+        async fn upload_task_attachment(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::UploadTaskAttachmentRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<super::UploadTaskAttachmentResponse>,
+            tonic::Status,
+        >;
+        async fn get_task_analytics(
+            &self,
+            request: tonic::Request<super::GetTaskAnalyticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTaskAnalyticsResponse>,
+            tonic::Status,
+        >;
+        async fn get_workload(
+            &self,
+            request: tonic::Request<super::GetWorkloadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWorkloadResponse>,
+            tonic::Status,
+        >;
+        async fn get_task_timeseries(
+            &self,
+            request: tonic::Request<super::GetTaskTimeseriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTaskTimeseriesResponse>,
+            tonic::Status,
+        >;
+        async fn health(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct TaskServiceServer<T: TaskService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: TaskService> TaskServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TaskServiceServer<T>
+    where
+        T: TaskService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/example.TaskService/CreateTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::CreateTaskRequest>
+                    for CreateTaskSvc<T> {
+                        type Response = super::CreateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::create_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/UpsertTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpsertTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::CreateTaskRequest>
+                    for UpsertTaskSvc<T> {
+                        type Response = super::UpsertTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::upsert_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpsertTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/GetTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::GetTaskRequest>
+                    for GetTaskSvc<T> {
+                        type Response = super::GetTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::get_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/GetTaskByNumber" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTaskByNumberSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::GetTaskByNumberRequest>
+                    for GetTaskByNumberSvc<T> {
+                        type Response = super::GetTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTaskByNumberRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::get_task_by_number(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTaskByNumberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/UpdateTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::UpdateTaskRequest>
+                    for UpdateTaskSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::update_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/UpdateTaskStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateTaskStatusSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::UpdateTaskStatusRequest>
+                    for UpdateTaskStatusSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateTaskStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::update_task_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateTaskStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/DeleteTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::DeleteTaskRequest>
+                    for DeleteTaskSvc<T> {
+                        type Response = super::DeleteTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::delete_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ArchiveTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct ArchiveTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ArchiveTaskRequest>
+                    for ArchiveTaskSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ArchiveTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::archive_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ArchiveTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ListArchivedTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListArchivedTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ListArchivedTasksRequest>
+                    for ListArchivedTasksSvc<T> {
+                        type Response = super::ListTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListArchivedTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::list_archived_tasks(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListArchivedTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/RestoreArchivedTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreArchivedTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::RestoreArchivedTaskRequest>
+                    for RestoreArchivedTaskSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreArchivedTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::restore_archived_task(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RestoreArchivedTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/CloneTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct CloneTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::CloneTaskRequest>
+                    for CloneTaskSvc<T> {
+                        type Response = super::CreateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CloneTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::clone_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CloneTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/MergeTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct MergeTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::MergeTasksRequest>
+                    for MergeTasksSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::merge_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MergeTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/SnoozeTask" => {
+                    #[allow(non_camel_case_types)]
+                    struct SnoozeTaskSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::SnoozeTaskRequest>
+                    for SnoozeTaskSvc<T> {
+                        type Response = super::UpdateTaskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SnoozeTaskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::snooze_task(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SnoozeTaskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/SetReminder" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetReminderSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::SetReminderRequest>
+                    for SetReminderSvc<T> {
+                        type Response = super::SetReminderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetReminderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::set_reminder(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetReminderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/AddComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddCommentSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::AddCommentRequest>
+                    for AddCommentSvc<T> {
+                        type Response = super::CommentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::add_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/EditComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditCommentSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::EditCommentRequest>
+                    for EditCommentSvc<T> {
+                        type Response = super::CommentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::edit_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EditCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/DeleteComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteCommentSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::DeleteCommentRequest>
+                    for DeleteCommentSvc<T> {
+                        type Response = super::DeleteCommentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::delete_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ListTaskComments" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTaskCommentsSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ListTaskCommentsRequest>
+                    for ListTaskCommentsSvc<T> {
+                        type Response = super::ListTaskCommentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTaskCommentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::list_task_comments(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTaskCommentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ListTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ListTasksRequest>
+                    for ListTasksSvc<T> {
+                        type Response = super::ListTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::list_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/SearchTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::SearchTasksRequest>
+                    for SearchTasksSvc<T> {
+                        type Response = super::SearchTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::search_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/CreateSavedView" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateSavedViewSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::CreateSavedViewRequest>
+                    for CreateSavedViewSvc<T> {
+                        type Response = super::CreateSavedViewResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateSavedViewRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::create_saved_view(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateSavedViewSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ListSavedViews" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSavedViewsSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ListSavedViewsRequest>
+                    for ListSavedViewsSvc<T> {
+                        type Response = super::ListSavedViewsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSavedViewsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::list_saved_views(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListSavedViewsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ApplySavedView" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApplySavedViewSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ApplySavedViewRequest>
+                    for ApplySavedViewSvc<T> {
+                        type Response = super::ListTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApplySavedViewRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::apply_saved_view(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ApplySavedViewSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/BulkUpdateTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkUpdateTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::BulkUpdateTasksRequest>
+                    for BulkUpdateTasksSvc<T> {
+                        type Response = super::BulkUpdateTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BulkUpdateTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::bulk_update_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BulkUpdateTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/BulkUpdateTasksByFilter" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkUpdateTasksByFilterSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::BulkUpdateTasksByFilterRequest>
+                    for BulkUpdateTasksByFilterSvc<T> {
+                        type Response = super::BulkUpdateTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::BulkUpdateTasksByFilterRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::bulk_update_tasks_by_filter(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BulkUpdateTasksByFilterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/BulkTagTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkTagTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::BulkTagTasksRequest>
+                    for BulkTagTasksSvc<T> {
+                        type Response = super::BulkUpdateTasksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BulkTagTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::bulk_tag_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BulkTagTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ListTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTagsSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::ListTagsRequest>
+                    for ListTagsSvc<T> {
+                        type Response = super::ListTagsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTagsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::list_tags(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTagsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/RenameTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenameTagSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::RenameTagRequest>
+                    for RenameTagSvc<T> {
+                        type Response = super::RenameTagResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenameTagRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::rename_tag(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RenameTagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/StreamTaskEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamTaskEventsSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::ServerStreamingService<
+                        super::StreamTaskEventsRequest,
+                    > for StreamTaskEventsSvc<T> {
+                        type Response = super::TaskEvent;
+                        type ResponseStream = T::StreamTaskEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StreamTaskEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::stream_task_events(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamTaskEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/StreamTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::ServerStreamingService<super::StreamTasksRequest>
+                    for StreamTasksSvc<T> {
+                        type Response = super::Task;
+                        type ResponseStream = T::StreamTasksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StreamTasksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::stream_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/ImportTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::StreamingService<super::CreateTaskRequest>
+                    for ImportTasksSvc<T> {
+                        type Response = super::CreateTaskResponse;
+                        type ResponseStream = T::ImportTasksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::CreateTaskRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::import_tasks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ImportTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/CollaborateOnTasks" => {
+                    #[allow(non_camel_case_types)]
+                    struct CollaborateOnTasksSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::StreamingService<super::TaskEvent>
+                    for CollaborateOnTasksSvc<T> {
+                        type Response = super::TaskEvent;
+                        type ResponseStream = T::CollaborateOnTasksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::TaskEvent>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::collaborate_on_tasks(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CollaborateOnTasksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/UploadTaskAttachment" => {
+                    #[allow(non_camel_case_types)]
+                    struct UploadTaskAttachmentSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::ClientStreamingService<
+                        super::UploadTaskAttachmentRequest,
+                    > for UploadTaskAttachmentSvc<T> {
+                        type Response = super::UploadTaskAttachmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::UploadTaskAttachmentRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::upload_task_attachment(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UploadTaskAttachmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/GetTaskAnalytics" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTaskAnalyticsSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::GetTaskAnalyticsRequest>
+                    for GetTaskAnalyticsSvc<T> {
+                        type Response = super::GetTaskAnalyticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTaskAnalyticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::get_task_analytics(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTaskAnalyticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/GetWorkload" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetWorkloadSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::GetWorkloadRequest>
+                    for GetWorkloadSvc<T> {
+                        type Response = super::GetWorkloadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetWorkloadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::get_workload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetWorkloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/GetTaskTimeseries" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTaskTimeseriesSvc<T: TaskService>(pub Arc<T>);
+                    impl<
+                        T: TaskService,
+                    > tonic::server::UnaryService<super::GetTaskTimeseriesRequest>
+                    for GetTaskTimeseriesSvc<T> {
+                        type Response = super::GetTaskTimeseriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTaskTimeseriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::get_task_timeseries(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTaskTimeseriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/example.TaskService/Health" => {
+                    #[allow(non_camel_case_types)]
+                    struct HealthSvc<T: TaskService>(pub Arc<T>);
+                    impl<T: TaskService> tonic::server::UnaryService<()>
+                    for HealthSvc<T> {
+                        type Response = super::HealthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaskService>::health(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = HealthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: TaskService> Clone for TaskServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: TaskService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: TaskService> tonic::server::NamedService for TaskServiceServer<T> {
+        const NAME: &'static str = "example.TaskService";
+    }
+}
+/// Generated server implementations.
+pub mod user_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with UserServiceServer.
+    #[async_trait]
+    pub trait UserService: Send + Sync + 'static {
+        async fn create_user(
+            &self,
+            request: tonic::Request<super::CreateUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateUserResponse>,
             tonic::Status,
         >;
-        async fn search_tasks(
+        async fn batch_create_users(
             &self,
-            request: tonic::Request<super::SearchTasksRequest>,
+            request: tonic::Request<super::BatchCreateUsersRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SearchTasksResponse>,
+            tonic::Response<super::BatchCreateUsersResponse>,
             tonic::Status,
         >;
-        async fn bulk_update_tasks(
+        async fn get_user(
             &self,
-            request: tonic::Request<super::BulkUpdateTasksRequest>,
+            request: tonic::Request<super::GetUserRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn list_users(
+            &self,
+            request: tonic::Request<super::ListUsersRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::BulkUpdateTasksResponse>,
+            tonic::Response<super::ListUsersResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the StreamTaskEvents method.
-        type StreamTaskEventsStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::TaskEvent, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        async fn stream_task_events(
+        async fn update_user(
             &self,
-            request: tonic::Request<super::StreamTaskEventsRequest>,
+            request: tonic::Request<super::UpdateUserRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::StreamTaskEventsStream>,
+            tonic::Response<super::UpdateUserResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the ImportTasks method.
-        type ImportTasksStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::CreateTaskResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        async fn import_tasks(
+        async fn delete_user(
             &self,
-            request: tonic::Request<tonic::Streaming<super::CreateTaskRequest>>,
+            request: tonic::Request<super::DeleteUserRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::ImportTasksStream>,
+            tonic::Response<super::DeleteUserResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the CollaborateOnTasks method.
-        type CollaborateOnTasksStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::TaskEvent, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        async fn collaborate_on_tasks(
+        async fn login(
             &self,
-            request: tonic::Request<tonic::Streaming<super::TaskEvent>>,
+            request: tonic::Request<super::LoginRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status>;
+        async fn refresh_token(
+            &self,
+            request: tonic::Request<super::RefreshTokenRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::CollaborateOnTasksStream>,
+            tonic::Response<super::RefreshTokenResponse>,
             tonic::Status,
         >;
-        async fn upload_task_attachment(
+        async fn logout(
             &self,
-            request: tonic::Request<tonic::Streaming<super::UploadTaskAttachmentRequest>>,
+            request: tonic::Request<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn authenticate_user(
+            &self,
+            request: tonic::Request<super::AuthenticateUserRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UploadTaskAttachmentResponse>,
+            tonic::Response<super::AuthenticateUserResponse>,
             tonic::Status,
         >;
-        async fn get_task_analytics(
+        async fn get_user_tasks(
             &self,
-            request: tonic::Request<super::GetTaskAnalyticsRequest>,
+            request: tonic::Request<super::GetUserTasksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetTaskAnalyticsResponse>,
+            tonic::Response<super::GetUserTasksResponse>,
             tonic::Status,
         >;
-        async fn health(
+        async fn update_user_preferences(
             &self,
-            request: tonic::Request<()>,
-        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status>;
+            request: tonic::Request<super::UpdateUserPreferencesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateUserPreferencesResponse>,
+            tonic::Status,
+        >;
+        async fn reassign_user_tasks(
+            &self,
+            request: tonic::Request<super::ReassignUserTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReassignUserTasksResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
-    pub struct TaskServiceServer<T: TaskService> {
+    pub struct UserServiceServer<T: UserService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
@@ -1842,7 +6009,7 @@ pub mod task_service_server {
         max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: TaskService> TaskServiceServer<T> {
+    impl<T: UserService> UserServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -1894,9 +6061,9 @@ pub mod task_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for TaskServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for UserServiceServer<T>
     where
-        T: TaskService,
+        T: UserService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -1905,124 +6072,32 @@ pub mod task_service_server {
         type Future = BoxFuture<Self::Response, Self::Error>;
         fn poll_ready(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
-        }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/example.TaskService/CreateTask" => {
-                    #[allow(non_camel_case_types)]
-                    struct CreateTaskSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::CreateTaskRequest>
-                    for CreateTaskSvc<T> {
-                        type Response = super::CreateTaskResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CreateTaskRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TaskService>::create_task(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = CreateTaskSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/example.TaskService/GetTask" => {
-                    #[allow(non_camel_case_types)]
-                    struct GetTaskSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::GetTaskRequest>
-                    for GetTaskSvc<T> {
-                        type Response = super::GetTaskResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::GetTaskRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TaskService>::get_task(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = GetTaskSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/example.TaskService/UpdateTask" => {
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/example.UserService/CreateUser" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateTaskSvc<T: TaskService>(pub Arc<T>);
+                    struct CreateUserSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::UpdateTaskRequest>
-                    for UpdateTaskSvc<T> {
-                        type Response = super::UpdateTaskResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::CreateUserRequest>
+                    for CreateUserSvc<T> {
+                        type Response = super::CreateUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateTaskRequest>,
+                            request: tonic::Request<super::CreateUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::update_task(&inner, request).await
+                                <T as UserService>::create_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2034,7 +6109,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateTaskSvc(inner);
+                        let method = CreateUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2050,25 +6125,26 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/DeleteTask" => {
+                "/example.UserService/BatchCreateUsers" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteTaskSvc<T: TaskService>(pub Arc<T>);
+                    struct BatchCreateUsersSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::DeleteTaskRequest>
-                    for DeleteTaskSvc<T> {
-                        type Response = super::DeleteTaskResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::BatchCreateUsersRequest>
+                    for BatchCreateUsersSvc<T> {
+                        type Response = super::BatchCreateUsersResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteTaskRequest>,
+                            request: tonic::Request<super::BatchCreateUsersRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::delete_task(&inner, request).await
+                                <T as UserService>::batch_create_users(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2080,7 +6156,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteTaskSvc(inner);
+                        let method = BatchCreateUsersSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2096,25 +6172,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/ListTasks" => {
+                "/example.UserService/GetUser" => {
                     #[allow(non_camel_case_types)]
-                    struct ListTasksSvc<T: TaskService>(pub Arc<T>);
+                    struct GetUserSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::ListTasksRequest>
-                    for ListTasksSvc<T> {
-                        type Response = super::ListTasksResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::GetUserRequest>
+                    for GetUserSvc<T> {
+                        type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListTasksRequest>,
+                            request: tonic::Request<super::GetUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::list_tasks(&inner, request).await
+                                <T as UserService>::get_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2126,7 +6202,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListTasksSvc(inner);
+                        let method = GetUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2142,25 +6218,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/SearchTasks" => {
+                "/example.UserService/ListUsers" => {
                     #[allow(non_camel_case_types)]
-                    struct SearchTasksSvc<T: TaskService>(pub Arc<T>);
+                    struct ListUsersSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::SearchTasksRequest>
-                    for SearchTasksSvc<T> {
-                        type Response = super::SearchTasksResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::ListUsersRequest>
+                    for ListUsersSvc<T> {
+                        type Response = super::ListUsersResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SearchTasksRequest>,
+                            request: tonic::Request<super::ListUsersRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::search_tasks(&inner, request).await
+                                <T as UserService>::list_users(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2172,7 +6248,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SearchTasksSvc(inner);
+                        let method = ListUsersSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2188,25 +6264,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/BulkUpdateTasks" => {
+                "/example.UserService/UpdateUser" => {
                     #[allow(non_camel_case_types)]
-                    struct BulkUpdateTasksSvc<T: TaskService>(pub Arc<T>);
+                    struct UpdateUserSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::BulkUpdateTasksRequest>
-                    for BulkUpdateTasksSvc<T> {
-                        type Response = super::BulkUpdateTasksResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::UpdateUserRequest>
+                    for UpdateUserSvc<T> {
+                        type Response = super::UpdateUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BulkUpdateTasksRequest>,
+                            request: tonic::Request<super::UpdateUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::bulk_update_tasks(&inner, request).await
+                                <T as UserService>::update_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2218,7 +6294,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BulkUpdateTasksSvc(inner);
+                        let method = UpdateUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2234,175 +6310,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/StreamTaskEvents" => {
-                    #[allow(non_camel_case_types)]
-                    struct StreamTaskEventsSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::ServerStreamingService<
-                        super::StreamTaskEventsRequest,
-                    > for StreamTaskEventsSvc<T> {
-                        type Response = super::TaskEvent;
-                        type ResponseStream = T::StreamTaskEventsStream;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::StreamTaskEventsRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TaskService>::stream_task_events(&inner, request)
-                                    .await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = StreamTaskEventsSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.server_streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/example.TaskService/ImportTasks" => {
-                    #[allow(non_camel_case_types)]
-                    struct ImportTasksSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::StreamingService<super::CreateTaskRequest>
-                    for ImportTasksSvc<T> {
-                        type Response = super::CreateTaskResponse;
-                        type ResponseStream = T::ImportTasksStream;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::CreateTaskRequest>,
-                            >,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TaskService>::import_tasks(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = ImportTasksSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/example.TaskService/CollaborateOnTasks" => {
-                    #[allow(non_camel_case_types)]
-                    struct CollaborateOnTasksSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::StreamingService<super::TaskEvent>
-                    for CollaborateOnTasksSvc<T> {
-                        type Response = super::TaskEvent;
-                        type ResponseStream = T::CollaborateOnTasksStream;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<tonic::Streaming<super::TaskEvent>>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as TaskService>::collaborate_on_tasks(&inner, request)
-                                    .await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = CollaborateOnTasksSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.streaming(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/example.TaskService/UploadTaskAttachment" => {
+                "/example.UserService/DeleteUser" => {
                     #[allow(non_camel_case_types)]
-                    struct UploadTaskAttachmentSvc<T: TaskService>(pub Arc<T>);
+                    struct DeleteUserSvc<T: UserService>(pub Arc<T>);
                     impl<
-                        T: TaskService,
-                    > tonic::server::ClientStreamingService<
-                        super::UploadTaskAttachmentRequest,
-                    > for UploadTaskAttachmentSvc<T> {
-                        type Response = super::UploadTaskAttachmentResponse;
+                        T: UserService,
+                    > tonic::server::UnaryService<super::DeleteUserRequest>
+                    for DeleteUserSvc<T> {
+                        type Response = super::DeleteUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::UploadTaskAttachmentRequest>,
-                            >,
+                            request: tonic::Request<super::DeleteUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::upload_task_attachment(&inner, request)
-                                    .await
+                                <T as UserService>::delete_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2414,7 +6340,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UploadTaskAttachmentSvc(inner);
+                        let method = DeleteUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2425,31 +6351,28 @@ pub mod task_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.client_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/GetTaskAnalytics" => {
+                "/example.UserService/Login" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTaskAnalyticsSvc<T: TaskService>(pub Arc<T>);
-                    impl<
-                        T: TaskService,
-                    > tonic::server::UnaryService<super::GetTaskAnalyticsRequest>
-                    for GetTaskAnalyticsSvc<T> {
-                        type Response = super::GetTaskAnalyticsResponse;
+                    struct LoginSvc<T: UserService>(pub Arc<T>);
+                    impl<T: UserService> tonic::server::UnaryService<super::LoginRequest>
+                    for LoginSvc<T> {
+                        type Response = super::LoginResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetTaskAnalyticsRequest>,
+                            request: tonic::Request<super::LoginRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::get_task_analytics(&inner, request)
-                                    .await
+                                <T as UserService>::login(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2461,7 +6384,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTaskAnalyticsSvc(inner);
+                        let method = LoginSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2477,20 +6400,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.TaskService/Health" => {
+                "/example.UserService/RefreshToken" => {
                     #[allow(non_camel_case_types)]
-                    struct HealthSvc<T: TaskService>(pub Arc<T>);
-                    impl<T: TaskService> tonic::server::UnaryService<()>
-                    for HealthSvc<T> {
-                        type Response = super::HealthResponse;
+                    struct RefreshTokenSvc<T: UserService>(pub Arc<T>);
+                    impl<
+                        T: UserService,
+                    > tonic::server::UnaryService<super::RefreshTokenRequest>
+                    for RefreshTokenSvc<T> {
+                        type Response = super::RefreshTokenResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
-                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RefreshTokenRequest>,
+                        ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TaskService>::health(&inner, request).await
+                                <T as UserService>::refresh_token(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2502,7 +6430,7 @@ pub mod task_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = HealthSvc(inner);
+                        let method = RefreshTokenSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2518,221 +6446,25 @@ pub mod task_service_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
-                }
-            }
-        }
-    }
-    impl<T: TaskService> Clone for TaskServiceServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    impl<T: TaskService> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
-            Self(Arc::clone(&self.0))
-        }
-    }
-    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{:?}", self.0)
-        }
-    }
-    impl<T: TaskService> tonic::server::NamedService for TaskServiceServer<T> {
-        const NAME: &'static str = "example.TaskService";
-    }
-}
-/// Generated server implementations.
-pub mod user_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with UserServiceServer.
-    #[async_trait]
-    pub trait UserService: Send + Sync + 'static {
-        async fn create_user(
-            &self,
-            request: tonic::Request<super::CreateUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateUserResponse>,
-            tonic::Status,
-        >;
-        async fn get_user(
-            &self,
-            request: tonic::Request<super::GetUserRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-        async fn list_users(
-            &self,
-            request: tonic::Request<super::ListUsersRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListUsersResponse>,
-            tonic::Status,
-        >;
-        async fn update_user(
-            &self,
-            request: tonic::Request<super::UpdateUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::UpdateUserResponse>,
-            tonic::Status,
-        >;
-        async fn delete_user(
-            &self,
-            request: tonic::Request<super::DeleteUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::DeleteUserResponse>,
-            tonic::Status,
-        >;
-        async fn login(
-            &self,
-            request: tonic::Request<super::LoginRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoginResponse>, tonic::Status>;
-        async fn refresh_token(
-            &self,
-            request: tonic::Request<super::RefreshTokenRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::RefreshTokenResponse>,
-            tonic::Status,
-        >;
-        async fn logout(
-            &self,
-            request: tonic::Request<super::LogoutRequest>,
-        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
-        async fn authenticate_user(
-            &self,
-            request: tonic::Request<super::AuthenticateUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::AuthenticateUserResponse>,
-            tonic::Status,
-        >;
-        async fn get_user_tasks(
-            &self,
-            request: tonic::Request<super::GetUserTasksRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserTasksResponse>,
-            tonic::Status,
-        >;
-        async fn update_user_preferences(
-            &self,
-            request: tonic::Request<super::UpdateUserPreferencesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::UpdateUserPreferencesResponse>,
-            tonic::Status,
-        >;
-    }
-    #[derive(Debug)]
-    pub struct UserServiceServer<T: UserService> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: UserService> UserServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
-        }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for UserServiceServer<T>
-    where
-        T: UserService,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
-            &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
-        }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/example.UserService/CreateUser" => {
+                "/example.UserService/Logout" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateUserSvc<T: UserService>(pub Arc<T>);
+                    struct LogoutSvc<T: UserService>(pub Arc<T>);
                     impl<
                         T: UserService,
-                    > tonic::server::UnaryService<super::CreateUserRequest>
-                    for CreateUserSvc<T> {
-                        type Response = super::CreateUserResponse;
+                    > tonic::server::UnaryService<super::LogoutRequest>
+                    for LogoutSvc<T> {
+                        type Response = ();
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateUserRequest>,
+                            request: tonic::Request<super::LogoutRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::create_user(&inner, request).await
+                                <T as UserService>::logout(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2744,7 +6476,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CreateUserSvc(inner);
+                        let method = LogoutSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2760,25 +6492,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/GetUser" => {
+                "/example.UserService/AuthenticateUser" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserSvc<T: UserService>(pub Arc<T>);
+                    struct AuthenticateUserSvc<T: UserService>(pub Arc<T>);
                     impl<
                         T: UserService,
-                    > tonic::server::UnaryService<super::GetUserRequest>
-                    for GetUserSvc<T> {
-                        type Response = super::GetUserResponse;
+                    > tonic::server::UnaryService<super::AuthenticateUserRequest>
+                    for AuthenticateUserSvc<T> {
+                        type Response = super::AuthenticateUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetUserRequest>,
+                            request: tonic::Request<super::AuthenticateUserRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::get_user(&inner, request).await
+                                <T as UserService>::authenticate_user(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2790,7 +6522,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserSvc(inner);
+                        let method = AuthenticateUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2806,25 +6538,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/ListUsers" => {
+                "/example.UserService/GetUserTasks" => {
                     #[allow(non_camel_case_types)]
-                    struct ListUsersSvc<T: UserService>(pub Arc<T>);
+                    struct GetUserTasksSvc<T: UserService>(pub Arc<T>);
                     impl<
                         T: UserService,
-                    > tonic::server::UnaryService<super::ListUsersRequest>
-                    for ListUsersSvc<T> {
-                        type Response = super::ListUsersResponse;
+                    > tonic::server::UnaryService<super::GetUserTasksRequest>
+                    for GetUserTasksSvc<T> {
+                        type Response = super::GetUserTasksResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListUsersRequest>,
+                            request: tonic::Request<super::GetUserTasksRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::list_users(&inner, request).await
+                                <T as UserService>::get_user_tasks(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2836,7 +6568,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListUsersSvc(inner);
+                        let method = GetUserTasksSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2852,25 +6584,26 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/UpdateUser" => {
+                "/example.UserService/UpdateUserPreferences" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateUserSvc<T: UserService>(pub Arc<T>);
+                    struct UpdateUserPreferencesSvc<T: UserService>(pub Arc<T>);
                     impl<
                         T: UserService,
-                    > tonic::server::UnaryService<super::UpdateUserRequest>
-                    for UpdateUserSvc<T> {
-                        type Response = super::UpdateUserResponse;
+                    > tonic::server::UnaryService<super::UpdateUserPreferencesRequest>
+                    for UpdateUserPreferencesSvc<T> {
+                        type Response = super::UpdateUserPreferencesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateUserRequest>,
+                            request: tonic::Request<super::UpdateUserPreferencesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::update_user(&inner, request).await
+                                <T as UserService>::update_user_preferences(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2882,7 +6615,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateUserSvc(inner);
+                        let method = UpdateUserPreferencesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2898,25 +6631,26 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/DeleteUser" => {
+                "/example.UserService/ReassignUserTasks" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteUserSvc<T: UserService>(pub Arc<T>);
+                    struct ReassignUserTasksSvc<T: UserService>(pub Arc<T>);
                     impl<
                         T: UserService,
-                    > tonic::server::UnaryService<super::DeleteUserRequest>
-                    for DeleteUserSvc<T> {
-                        type Response = super::DeleteUserResponse;
+                    > tonic::server::UnaryService<super::ReassignUserTasksRequest>
+                    for ReassignUserTasksSvc<T> {
+                        type Response = super::ReassignUserTasksResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteUserRequest>,
+                            request: tonic::Request<super::ReassignUserTasksRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::delete_user(&inner, request).await
+                                <T as UserService>::reassign_user_tasks(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2928,7 +6662,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteUserSvc(inner);
+                        let method = ReassignUserTasksSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2944,23 +6678,189 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/Login" => {
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: UserService> Clone for UserServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: UserService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: UserService> tonic::server::NamedService for UserServiceServer<T> {
+        const NAME: &'static str = "example.UserService";
+    }
+}
+/// Generated server implementations.
+pub mod project_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ProjectServiceServer.
+    #[async_trait]
+    pub trait ProjectService: Send + Sync + 'static {
+        async fn create_project(
+            &self,
+            request: tonic::Request<super::CreateProjectRequest>,
+        ) -> std::result::Result<tonic::Response<super::ProjectResponse>, tonic::Status>;
+        async fn get_project(
+            &self,
+            request: tonic::Request<super::GetProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetProjectResponse>,
+            tonic::Status,
+        >;
+        async fn list_projects(
+            &self,
+            request: tonic::Request<super::ListProjectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProjectsResponse>,
+            tonic::Status,
+        >;
+        async fn update_project(
+            &self,
+            request: tonic::Request<super::UpdateProjectRequest>,
+        ) -> std::result::Result<tonic::Response<super::ProjectResponse>, tonic::Status>;
+        async fn delete_project(
+            &self,
+            request: tonic::Request<super::DeleteProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteProjectResponse>,
+            tonic::Status,
+        >;
+        async fn list_project_tasks(
+            &self,
+            request: tonic::Request<super::ListProjectTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTasksResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct ProjectServiceServer<T: ProjectService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ProjectService> ProjectServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ProjectServiceServer<T>
+    where
+        T: ProjectService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/example.ProjectService/CreateProject" => {
                     #[allow(non_camel_case_types)]
-                    struct LoginSvc<T: UserService>(pub Arc<T>);
-                    impl<T: UserService> tonic::server::UnaryService<super::LoginRequest>
-                    for LoginSvc<T> {
-                        type Response = super::LoginResponse;
+                    struct CreateProjectSvc<T: ProjectService>(pub Arc<T>);
+                    impl<
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::CreateProjectRequest>
+                    for CreateProjectSvc<T> {
+                        type Response = super::ProjectResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LoginRequest>,
+                            request: tonic::Request<super::CreateProjectRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::login(&inner, request).await
+                                <T as ProjectService>::create_project(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2972,7 +6872,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LoginSvc(inner);
+                        let method = CreateProjectSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2988,25 +6888,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/RefreshToken" => {
+                "/example.ProjectService/GetProject" => {
                     #[allow(non_camel_case_types)]
-                    struct RefreshTokenSvc<T: UserService>(pub Arc<T>);
+                    struct GetProjectSvc<T: ProjectService>(pub Arc<T>);
                     impl<
-                        T: UserService,
-                    > tonic::server::UnaryService<super::RefreshTokenRequest>
-                    for RefreshTokenSvc<T> {
-                        type Response = super::RefreshTokenResponse;
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::GetProjectRequest>
+                    for GetProjectSvc<T> {
+                        type Response = super::GetProjectResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RefreshTokenRequest>,
+                            request: tonic::Request<super::GetProjectRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::refresh_token(&inner, request).await
+                                <T as ProjectService>::get_project(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3018,7 +6918,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = RefreshTokenSvc(inner);
+                        let method = GetProjectSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3034,25 +6934,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/Logout" => {
+                "/example.ProjectService/ListProjects" => {
                     #[allow(non_camel_case_types)]
-                    struct LogoutSvc<T: UserService>(pub Arc<T>);
+                    struct ListProjectsSvc<T: ProjectService>(pub Arc<T>);
                     impl<
-                        T: UserService,
-                    > tonic::server::UnaryService<super::LogoutRequest>
-                    for LogoutSvc<T> {
-                        type Response = ();
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::ListProjectsRequest>
+                    for ListProjectsSvc<T> {
+                        type Response = super::ListProjectsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LogoutRequest>,
+                            request: tonic::Request<super::ListProjectsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::logout(&inner, request).await
+                                <T as ProjectService>::list_projects(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3064,7 +6964,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LogoutSvc(inner);
+                        let method = ListProjectsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3080,25 +6980,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/AuthenticateUser" => {
+                "/example.ProjectService/UpdateProject" => {
                     #[allow(non_camel_case_types)]
-                    struct AuthenticateUserSvc<T: UserService>(pub Arc<T>);
+                    struct UpdateProjectSvc<T: ProjectService>(pub Arc<T>);
                     impl<
-                        T: UserService,
-                    > tonic::server::UnaryService<super::AuthenticateUserRequest>
-                    for AuthenticateUserSvc<T> {
-                        type Response = super::AuthenticateUserResponse;
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::UpdateProjectRequest>
+                    for UpdateProjectSvc<T> {
+                        type Response = super::ProjectResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AuthenticateUserRequest>,
+                            request: tonic::Request<super::UpdateProjectRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::authenticate_user(&inner, request).await
+                                <T as ProjectService>::update_project(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3110,7 +7010,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AuthenticateUserSvc(inner);
+                        let method = UpdateProjectSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3126,25 +7026,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/GetUserTasks" => {
+                "/example.ProjectService/DeleteProject" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserTasksSvc<T: UserService>(pub Arc<T>);
+                    struct DeleteProjectSvc<T: ProjectService>(pub Arc<T>);
                     impl<
-                        T: UserService,
-                    > tonic::server::UnaryService<super::GetUserTasksRequest>
-                    for GetUserTasksSvc<T> {
-                        type Response = super::GetUserTasksResponse;
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::DeleteProjectRequest>
+                    for DeleteProjectSvc<T> {
+                        type Response = super::DeleteProjectResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetUserTasksRequest>,
+                            request: tonic::Request<super::DeleteProjectRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::get_user_tasks(&inner, request).await
+                                <T as ProjectService>::delete_project(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3156,7 +7056,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserTasksSvc(inner);
+                        let method = DeleteProjectSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3172,25 +7072,25 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/example.UserService/UpdateUserPreferences" => {
+                "/example.ProjectService/ListProjectTasks" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateUserPreferencesSvc<T: UserService>(pub Arc<T>);
+                    struct ListProjectTasksSvc<T: ProjectService>(pub Arc<T>);
                     impl<
-                        T: UserService,
-                    > tonic::server::UnaryService<super::UpdateUserPreferencesRequest>
-                    for UpdateUserPreferencesSvc<T> {
-                        type Response = super::UpdateUserPreferencesResponse;
+                        T: ProjectService,
+                    > tonic::server::UnaryService<super::ListProjectTasksRequest>
+                    for ListProjectTasksSvc<T> {
+                        type Response = super::ListTasksResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateUserPreferencesRequest>,
+                            request: tonic::Request<super::ListProjectTasksRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as UserService>::update_user_preferences(&inner, request)
+                                <T as ProjectService>::list_project_tasks(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -3203,7 +7103,7 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateUserPreferencesSvc(inner);
+                        let method = ListProjectTasksSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3234,7 +7134,7 @@ pub mod user_service_server {
             }
         }
     }
-    impl<T: UserService> Clone for UserServiceServer<T> {
+    impl<T: ProjectService> Clone for ProjectServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -3246,7 +7146,7 @@ pub mod user_service_server {
             }
         }
     }
-    impl<T: UserService> Clone for _Inner<T> {
+    impl<T: ProjectService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(Arc::clone(&self.0))
         }
@@ -3256,7 +7156,7 @@ pub mod user_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: UserService> tonic::server::NamedService for UserServiceServer<T> {
-        const NAME: &'static str = "example.UserService";
+    impl<T: ProjectService> tonic::server::NamedService for ProjectServiceServer<T> {
+        const NAME: &'static str = "example.ProjectService";
     }
 }
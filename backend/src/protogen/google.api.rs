@@ -3,6 +3,8 @@
 /// [HttpRule][google.api.HttpRule], each specifying the mapping of an RPC method
 /// to one or more HTTP REST API methods.
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Http {
@@ -285,6 +287,8 @@ pub struct Http {
 /// the request or response body to a repeated field. However, some gRPC
 /// Transcoding implementations may not support this feature.
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HttpRule {
@@ -327,6 +331,7 @@ pub mod http_rule {
     /// used with any of the {get|put|post|delete|patch} methods. A custom method
     /// can be defined using the 'custom' field.
     #[derive(serde::Serialize, serde::Deserialize)]
+    #[derive(utoipa::ToSchema)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Pattern {
@@ -356,6 +361,8 @@ pub mod http_rule {
 }
 /// A custom pattern is used for defining custom HTTP verb.
 #[derive(serde::Serialize, serde::Deserialize)]
+#[derive(utoipa::ToSchema)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CustomHttpPattern {
@@ -0,0 +1,150 @@
+// src/read_only.rs
+use std::collections::HashSet;
+use std::task::{Context, Poll};
+
+use once_cell::sync::Lazy;
+use prost::Message;
+use prost_types::method_options::IdempotencyLevel;
+use prost_types::FileDescriptorSet;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::Status;
+use tower::Layer;
+
+use crate::protogen::DESCRIPTOR_SET;
+use crate::storage::Storage;
+
+/// Auth calls an operator needs to sign in and switch read-only mode back off
+const AUTH_RPCS: [&str; 4] = [
+    "/example.UserService/Login",
+    "/example.UserService/RefreshToken",
+    "/example.UserService/Logout",
+    "/example.UserService/AuthenticateUser",
+];
+
+/// Paths of the RPCs the proto marks `idempotency_level = NO_SIDE_EFFECTS`
+static READ_RPCS: Lazy<HashSet<String>> = Lazy::new(|| {
+    let descriptors = FileDescriptorSet::decode(DESCRIPTOR_SET).expect("descriptor set is generated at build time");
+    let mut paths = HashSet::new();
+    for file in &descriptors.file {
+        for service in &file.service {
+            let service_name = format!("{}.{}", file.package(), service.name());
+            for method in &service.method {
+                let reads_only = method.options.as_ref()
+                    .is_some_and(|options| options.idempotency_level() == IdempotencyLevel::NoSideEffects);
+                if reads_only {
+                    paths.insert(format!("/{}/{}", service_name, method.name()));
+                }
+            }
+        }
+    }
+    paths
+});
+
+/// RPCs that keep working in read-only mode: those marked as only reading,
+/// reflection and health probes, and the auth calls in `AUTH_RPCS`.
+pub fn allowed_rpc(path: &str) -> bool {
+    path.starts_with("/grpc.reflection.")
+        || health_rpc(path)
+        || READ_RPCS.contains(path)
+        || AUTH_RPCS.contains(&path)
+}
+
+/// Probes that must answer while the server is still loading.
+fn health_rpc(path: &str) -> bool {
+    path.starts_with("/grpc.health.") || path.ends_with("/Health")
+}
+
+/// Rejects mutating gRPC calls with `FAILED_PRECONDITION` while the storage
+/// is in read-only mode, and everything but health checks with `UNAVAILABLE`
+/// until the storage has finished loading.
+#[derive(Clone)]
+pub struct ReadOnlyLayer {
+    storage: Storage,
+}
+
+impl ReadOnlyLayer {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S> Layer<S> for ReadOnlyLayer {
+    type Service = ReadOnlyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ReadOnlyService { inner, storage: self.storage.clone() }
+    }
+}
+
+#[derive(Clone)]
+pub struct ReadOnlyService<S> {
+    inner: S,
+    storage: Storage,
+}
+
+impl<S, B> Service<http::Request<B>> for ReadOnlyService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        if !self.storage.is_loaded() && !health_rpc(request.uri().path()) {
+            let response = Status::unavailable("Server is still loading its data").to_http();
+            return Box::pin(async move { Ok(response) });
+        }
+        if self.storage.is_read_only() && !allowed_rpc(request.uri().path()) {
+            let response = Status::failed_precondition("Server is in read-only mode").to_http();
+            return Box::pin(async move { Ok(response) });
+        }
+        Box::pin(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_rpcs_come_from_the_descriptor() {
+        for path in [
+            "/example.TaskService/GetTask",
+            "/example.TaskService/ApplySavedView",
+            "/example.TaskService/StreamTasks",
+            "/example.UserService/ListUsers",
+            "/example.ProjectService/ListProjectTasks",
+            "/example.TaskService/Health",
+            "/grpc.health.v1.Health/Check",
+            "/example.UserService/Login",
+        ] {
+            assert!(allowed_rpc(path), "{} should be allowed", path);
+        }
+        for path in [
+            "/example.TaskService/CreateTask",
+            "/example.TaskService/StreamlineTasks",
+            "/example.TaskService/ImportTasks",
+            "/example.UserService/GetOrCreateUser",
+            "/example.ProjectService/DeleteProject",
+        ] {
+            assert!(!allowed_rpc(path), "{} should be refused", path);
+        }
+    }
+
+    #[test]
+    fn no_mutating_rpc_is_marked_read_only() {
+        let writes = ["Create", "Update", "Delete", "Upsert", "Archive", "Restore", "Bulk", "Rename", "Import", "Upload"];
+        for path in READ_RPCS.iter() {
+            let method = path.rsplit('/').next().unwrap();
+            assert!(!writes.iter().any(|verb| method.starts_with(verb)), "{} is marked read-only", path);
+        }
+        assert_eq!(READ_RPCS.len(), 21);
+    }
+}
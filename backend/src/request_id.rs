@@ -0,0 +1,153 @@
+// src/request_id.rs
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use tonic::codegen::{http, BoxFuture, Service};
+use tower::Layer;
+use tracing::{info, info_span, Instrument};
+use uuid::Uuid;
+
+use http::header::{HeaderName, HeaderValue};
+
+/// Longest client-supplied request id that's passed through; longer ones are
+/// replaced so a client can't bloat every log line.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Tags each request with an id, taken from the configured header or
+/// generated when absent, and echoes it on the response. The id is also
+/// written back into the request headers, so gRPC handlers see it in their
+/// metadata, and every log line for the request carries it through the span.
+#[derive(Clone)]
+pub struct RequestIdLayer {
+    header: HeaderName,
+}
+
+impl RequestIdLayer {
+    pub fn new(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner, header: self.header.clone() }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+    header: HeaderName,
+}
+
+impl<S, B, R> Service<http::Request<B>> for RequestIdService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<R>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<R>;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let supplied = request.headers()
+            .get(&self.header)
+            .filter(|value| !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let request_id = supplied.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let value = HeaderValue::from_str(&request_id).expect("request ids are visible ASCII");
+        request.headers_mut().insert(self.header.clone(), value.clone());
+
+        let span = info_span!(
+            "request",
+            request_id = %request_id,
+            method = %request.method(),
+            path = %request.uri().path(),
+        );
+        let started = Instant::now();
+        let response = span.in_scope(|| self.inner.call(request));
+        let header = self.header.clone();
+        Box::pin(async move {
+            let mut response = response.await?;
+            info!(
+                status = response.status().as_u16(),
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "Request finished"
+            );
+            response.headers_mut().insert(header, value);
+            Ok(response)
+        }.instrument(span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Answers with the request id the handler saw, as a gRPC handler would
+    /// find it in its metadata
+    async fn echo_handler(request: http::Request<()>) -> Result<http::Response<String>, std::convert::Infallible> {
+        let seen = request.headers().get("x-request-id").unwrap().to_str().unwrap().to_string();
+        Ok(http::Response::new(seen))
+    }
+
+    async fn call(request_id: Option<&str>) -> http::Response<String> {
+        let service = RequestIdLayer::new(HeaderName::from_static("x-request-id")).layer(tower::service_fn(echo_handler));
+        let mut request = http::Request::builder().uri("/api/tasks");
+        if let Some(request_id) = request_id {
+            request = request.header("x-request-id", request_id);
+        }
+        service.oneshot(request.body(()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn a_supplied_request_id_is_echoed_and_logged() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let response = call(Some("client-42")).await;
+        assert_eq!(response.headers()["x-request-id"], "client-42");
+        assert_eq!(response.body(), "client-42");
+        let logged = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(logged.contains("request_id=client-42") && logged.contains("Request finished"), "{}", logged);
+    }
+
+    #[tokio::test]
+    async fn missing_or_oversized_ids_are_replaced() {
+        for supplied in [None, Some("x".repeat(MAX_REQUEST_ID_LEN + 1))] {
+            let response = call(supplied.as_deref()).await;
+            let echoed = response.headers()["x-request-id"].to_str().unwrap();
+            assert!(Uuid::parse_str(echoed).is_ok(), "{}", echoed);
+            assert_eq!(response.body(), echoed);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// src/search.rs
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Queries shorter than this are matched exactly; a typo budget on two
+/// characters matches nearly everything.
+pub const MIN_FUZZY_QUERY_LEN: usize = 3;
+
+/// Normalize text for matching: decompose (NFD), drop combining marks so
+/// "café" and "cafe" compare equal, then lowercase.
+pub fn fold(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Whether `text` contains `query` once both are folded. Pass an already
+/// folded query when matching many texts against it.
+pub fn contains_folded(text: &str, folded_query: &str) -> bool {
+    fold(text).contains(folded_query)
+}
+
+/// Lowest edit distance between `query` and any run of words in `text` with
+/// the same word count, or `None` when nothing is within `max_distance`.
+/// Swapping two adjacent characters counts as one edit (optimal string
+/// alignment), so "bgu" is one typo away from "bug". Exact substring
+/// matches score 0.
+pub fn fuzzy_distance(query: &str, text: &str, max_distance: usize) -> Option<usize> {
+    let query = fold(query);
+    let text = fold(text);
+
+    if text.contains(&query) {
+        return Some(0);
+    }
+
+    // Never allow more edits than half the query, or short queries match noise
+    let max_distance = max_distance.min(query.chars().count() / 2);
+    let query_words = query.split_whitespace().count().max(1);
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    words.windows(query_words.min(words.len()).max(1))
+        .map(|window| strsim::osa_distance(&query, &window.join(" ")))
+        .filter(|distance| *distance <= max_distance)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_transposed_character_still_matches() {
+        assert_eq!(fuzzy_distance("bgu", "Fix the login bug", 2), Some(1));
+        assert_eq!(fuzzy_distance("lgoin bug", "Fix the login bug", 2), Some(1));
+    }
+
+    #[test]
+    fn short_queries_get_a_small_budget() {
+        assert_eq!(fuzzy_distance("bxx", "Fix the login bug", 2), None);
+        assert_eq!(fuzzy_distance("Café", "cafe menu", 0), Some(0));
+    }
+
+    #[test]
+    fn matching_ignores_case_and_accents() {
+        assert_eq!(fold("Crème BRÛLÉE"), "creme brulee");
+        // Precomposed and decomposed forms of the same text fold alike
+        assert_eq!(fold("Zo\u{e9}"), fold("Zoe\u{301}"));
+        assert!(contains_folded("Résumé review", &fold("RESUME")));
+        assert!(!contains_folded("Resume review", &fold("resumes")));
+    }
+}
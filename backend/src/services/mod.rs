@@ -1,6 +1,9 @@
 // src/services/mod.rs
+mod pagination;
+mod project_service;
 mod task_service;
 mod user_service;
 
+pub use project_service::ProjectServiceImpl;
 pub use task_service::TaskServiceImpl;
-pub use user_service::UserServiceImpl;
\ No newline at end of file
+pub use user_service::UserServiceImpl;
@@ -0,0 +1,69 @@
+// src/services/pagination.rs
+
+/// Parse a `page_N` token into a zero-based page number.
+pub fn page_number(page_token: &str) -> usize {
+    page_token.strip_prefix("page_")
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Slice one page out of a fully materialized result set.
+pub fn paginate<T>(items: Vec<T>, page_size: i32, page_token: &str) -> Vec<T> {
+    let page_size = page_size.max(0) as usize;
+    let start = page_number(page_token) * page_size;
+
+    items.into_iter()
+        .skip(start)
+        .take(page_size)
+        .collect()
+}
+
+/// Token for the page after `page_token`, or empty when `returned` shows this was the last page.
+pub fn next_page_token(page_token: &str, returned: usize, page_size: i32) -> String {
+    if page_size > 0 && returned >= page_size as usize {
+        format!("page_{}", page_number(page_token) + 1)
+    } else {
+        String::new()
+    }
+}
+
+/// Warning for a result set of `total` items, or empty when it's within `soft_limit` (0 = no limit).
+pub fn soft_limit_warning(total: u64, soft_limit: u64) -> String {
+    if soft_limit > 0 && total > soft_limit {
+        format!(
+            "{} results exceed the soft limit of {}; narrow the query with filters or page through it",
+            total, soft_limit
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// One-based number of the page `page_token` points at, and how many pages
+/// `total` items make at `page_size`.
+pub fn page_info(page_token: &str, page_size: i32, total: u64) -> (u64, u64) {
+    let total_pages = if page_size > 0 {
+        total.div_ceil(page_size as u64)
+    } else {
+        0
+    };
+    (page_number(page_token) as u64 + 1, total_pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protogen::ListTasksResponse;
+
+    #[test]
+    fn counts_past_i32_max_are_not_truncated() {
+        let total = i32::MAX as u64 * 3;
+        assert_eq!(page_info("page_4", 1000, total), (5, total.div_ceil(1000)));
+        assert!(soft_limit_warning(total, 1000).starts_with(&total.to_string()));
+
+        let response = ListTasksResponse { total_count: total, grand_total: total, ..Default::default() };
+        let json = serde_json::to_value(&response).unwrap();
+        let parsed: ListTasksResponse = serde_json::from_value(json).unwrap();
+        assert_eq!((parsed.total_count, parsed.grand_total), (total, total));
+    }
+}
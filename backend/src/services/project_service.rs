@@ -0,0 +1,273 @@
+// src/services/project_service.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::protogen::{
+    project_service_server::ProjectService,
+    *,
+};
+use crate::auth;
+use crate::config::{Config, ProjectDeletePolicy};
+use crate::storage::{ProjectDeletion, Storage};
+use crate::types::timestamp::SerdeTimestamp;
+use super::pagination::{next_page_token, page_info, paginate, soft_limit_warning};
+
+pub struct ProjectServiceImpl {
+    storage: Arc<Storage>,
+    config: Arc<Config>,
+}
+
+impl ProjectServiceImpl {
+    pub fn new(storage: Arc<Storage>, config: Arc<Config>) -> Self {
+        Self { storage, config }
+    }
+}
+
+#[tonic::async_trait]
+impl ProjectService for ProjectServiceImpl {
+    async fn create_project(
+        &self,
+        request: Request<CreateProjectRequest>,
+    ) -> Result<Response<ProjectResponse>, Status> {
+        let owner_id = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default();
+        let req = request.into_inner();
+
+        let name = req.name.trim();
+        if name.is_empty() {
+            return Err(Status::invalid_argument("Project name is required"));
+        }
+
+        let now = SerdeTimestamp::now();
+        let project = Project {
+            id: self.config.id_format.generate(),
+            name: name.to_string(),
+            description: req.description,
+            owner_id,
+            created_at: Some(now.clone()),
+            updated_at: Some(now),
+        };
+
+        self.storage
+            .create_project(project.clone())
+            .await
+            .map_err(|e| Status::internal(format!("Failed to create project: {}", e)))?;
+
+        let response = ProjectResponse {
+            project: Some(project),
+            success: true,
+            message: "Project created successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn get_project(
+        &self,
+        request: Request<GetProjectRequest>,
+    ) -> Result<Response<GetProjectResponse>, Status> {
+        let req = request.into_inner();
+
+        let project = self.storage.get_project(&req.id).await;
+        let response = GetProjectResponse {
+            found: project.is_some(),
+            project,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn list_projects(
+        &self,
+        request: Request<ListProjectsRequest>,
+    ) -> Result<Response<ListProjectsResponse>, Status> {
+        let req = request.into_inner();
+
+        let projects = self.storage.list_projects().await;
+        let total_count = projects.len() as u64;
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
+        let projects = paginate(projects, req.page_size, &req.page_token);
+
+        let response = ListProjectsResponse {
+            next_page_token: next_page_token(&req.page_token, projects.len(), req.page_size),
+            projects,
+            total_count,
+            current_page,
+            total_pages,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn update_project(
+        &self,
+        request: Request<UpdateProjectRequest>,
+    ) -> Result<Response<ProjectResponse>, Status> {
+        let req = request.into_inner();
+
+        let mut project = self.storage
+            .get_project(&req.id)
+            .await
+            .ok_or_else(|| Status::not_found("Project not found"))?;
+
+        // Empty fields are left as they were
+        let name = req.name.trim();
+        if !name.is_empty() {
+            project.name = name.to_string();
+        }
+        if !req.description.is_empty() {
+            project.description = req.description;
+        }
+        project.updated_at = Some(SerdeTimestamp::now());
+
+        self.storage
+            .update_project(project.clone())
+            .await
+            .map_err(|e| Status::internal(format!("Failed to update project: {}", e)))?;
+
+        let response = ProjectResponse {
+            project: Some(project),
+            success: true,
+            message: "Project updated successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn delete_project(
+        &self,
+        request: Request<DeleteProjectRequest>,
+    ) -> Result<Response<DeleteProjectResponse>, Status> {
+        let actor = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default();
+        let req = request.into_inner();
+
+        let deletion = self.storage
+            .delete_project(&req.id, self.config.project_delete_policy)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to delete project: {}", e)))?;
+
+        let tasks = match deletion {
+            ProjectDeletion::NotFound => return Err(Status::not_found("Project not found")),
+            ProjectDeletion::Blocked { task_count } => {
+                return Err(Status::failed_precondition(format!(
+                    "Project still has {} tasks; move or delete them first",
+                    task_count
+                )));
+            }
+            ProjectDeletion::Deleted { tasks } => tasks,
+        };
+
+        let event_type = match self.config.project_delete_policy {
+            ProjectDeletePolicy::Cascade => TaskEventType::Deleted,
+            _ => TaskEventType::Updated,
+        };
+        for task in &tasks {
+            self.storage.events().publish(event_type, task, &actor, HashMap::new());
+        }
+
+        let response = DeleteProjectResponse {
+            success: true,
+            message: "Project deleted successfully".to_string(),
+            affected_tasks: tasks.len() as u64,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn list_project_tasks(
+        &self,
+        request: Request<ListProjectTasksRequest>,
+    ) -> Result<Response<ListTasksResponse>, Status> {
+        let req = request.into_inner();
+
+        if self.storage.get_project(&req.project_id).await.is_none() {
+            return Err(Status::not_found("Project not found"));
+        }
+        let tasks = self.storage.get_project_tasks(&req.project_id).await;
+        let total_count = tasks.len() as u64;
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
+        let warning = soft_limit_warning(total_count, self.config.result_soft_limit);
+        let tasks = paginate(tasks, req.page_size, &req.page_token);
+
+        let response = ListTasksResponse {
+            next_page_token: next_page_token(&req.page_token, tasks.len(), req.page_size),
+            tasks,
+            total_count,
+            grand_total: self.storage.count_tasks().await,
+            current_page,
+            total_pages,
+            warning,
+        };
+
+        Ok(Response::new(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::task_service::TaskServiceImpl;
+    use crate::protogen::task_service_server::TaskService;
+
+    async fn setup(policy: ProjectDeletePolicy) -> (ProjectServiceImpl, TaskServiceImpl, String) {
+        let storage = Arc::new(Storage::new());
+        let config = Arc::new(Config { project_delete_policy: policy, ..Default::default() });
+        let projects = ProjectServiceImpl::new(storage.clone(), config.clone());
+        let tasks = TaskServiceImpl::new(storage, config);
+        let request = CreateProjectRequest { name: "Launch".to_string(), ..Default::default() };
+        let project = projects.create_project(Request::new(request)).await.unwrap().into_inner().project.unwrap();
+        (projects, tasks, project.id)
+    }
+
+    async fn create_in(tasks: &TaskServiceImpl, title: &str, project_id: &str) -> Result<Task, Status> {
+        let request = CreateTaskRequest { title: title.to_string(), project_id: project_id.to_string(), ..Default::default() };
+        Ok(tasks.create_task(Request::new(request)).await?.into_inner().task.unwrap())
+    }
+
+    async fn project_task_ids(projects: &ProjectServiceImpl, project_id: &str) -> Vec<String> {
+        let request = ListProjectTasksRequest { project_id: project_id.to_string(), page_size: 50, ..Default::default() };
+        let response = projects.list_project_tasks(Request::new(request)).await.unwrap().into_inner();
+        let mut ids: Vec<String> = response.tasks.into_iter().map(|task| task.id).collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn tasks_created_in_a_project_are_listed_under_it() {
+        let (projects, tasks, project_id) = setup(ProjectDeletePolicy::Block).await;
+        let first = create_in(&tasks, "First", &project_id).await.unwrap();
+        let second = create_in(&tasks, "Second", &project_id).await.unwrap();
+        create_in(&tasks, "Elsewhere", "").await.unwrap();
+
+        let mut expected = vec![first.id, second.id];
+        expected.sort();
+        assert_eq!(project_task_ids(&projects, &project_id).await, expected);
+
+        let status = create_in(&tasks, "Lost", "no-such-project").await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_project_follows_the_configured_policy() {
+        let (projects, tasks, project_id) = setup(ProjectDeletePolicy::Block).await;
+        create_in(&tasks, "Pinned", &project_id).await.unwrap();
+        let delete = || Request::new(DeleteProjectRequest { id: project_id.clone() });
+        let status = projects.delete_project(delete()).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        let (projects, tasks, project_id) = setup(ProjectDeletePolicy::Unassign).await;
+        let task = create_in(&tasks, "Kept", &project_id).await.unwrap();
+        let response = projects.delete_project(Request::new(DeleteProjectRequest { id: project_id })).await.unwrap();
+        assert_eq!(response.into_inner().affected_tasks, 1);
+        let request = GetTaskRequest { id: task.id, ..Default::default() };
+        let kept = tasks.get_task(Request::new(request)).await.unwrap().into_inner().task.unwrap();
+        assert!(kept.project_id.is_empty());
+    }
+}
@@ -1,55 +1,103 @@
 // src/services/task_service.rs
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::pin::Pin;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use futures::Stream;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio_stream::{wrappers::ReceiverStream, StreamExt};
 use tonic::{Request, Response, Status, Streaming};
 use uuid::Uuid;
 use prost_types::Timestamp;
+use tracing::warn;
 
 use crate::protogen::{
     task_service_server::TaskService,
     *,
 };
+use crate::attachments::AttachmentStore;
+use crate::auth;
+use crate::config::Config;
+use crate::error::TaskerError;
+use crate::events::{EventBus, StreamFilters, StreamRegistration};
+use crate::mentions;
+use crate::moderation::{CommentModeration, ContentFilter};
+use crate::search::MIN_FUZZY_QUERY_LEN;
 use crate::types::timestamp::SerdeTimestamp;
-use crate::storage::Storage;
+use crate::workflow;
+use crate::storage::{apply_custom_field_mask, Storage, PRIORITY_SCORE_RANGE};
+use super::pagination::{next_page_token, page_info, paginate, soft_limit_warning};
+
+/// Comments `get_task` returns inline when the caller didn't ask for all of them
+const RECENT_COMMENTS: usize = 3;
+
+/// Most buckets one time series may span
+const MAX_TIMESERIES_BUCKETS: i64 = 366;
+
+/// Days covered when a time series doesn't say where to start
+const DEFAULT_TIMESERIES_DAYS: i64 = 30;
+
+/// Most reminders one task may carry, fired or not
+const MAX_REMINDERS_PER_TASK: usize = 10;
+
+/// `TaskMetrics` fields that can be patched individually as `metrics.<field>`
+const METRICS_FIELDS: [&str; 3] = ["estimated_hours", "actual_hours", "completion_percentage"];
 
 pub struct TaskServiceImpl {
     storage: Arc<Storage>,
+    config: Arc<Config>,
+    moderation: CommentModeration,
 }
 
 impl TaskServiceImpl {
-    pub fn new(storage: Arc<Storage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<Storage>, config: Arc<Config>) -> Self {
+        let moderation = CommentModeration::from_config(&config);
+        Self { storage, config, moderation }
     }
 
-    fn system_time_to_timestamp(time: SystemTime) -> SerdeTimestamp {
-        let duration = time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
-        let timestamp = Timestamp {
-            seconds: duration.as_secs() as i64,
-            nanos: duration.subsec_nanos() as i32,
-        };
-        SerdeTimestamp(timestamp)
+    /// Run comments through `filter` after the configured ones
+    pub fn with_comment_filter(mut self, filter: impl ContentFilter + 'static) -> Self {
+        self.moderation = self.moderation.with_filter(filter);
+        self
     }
-}
 
-#[tonic::async_trait]
-impl TaskService for TaskServiceImpl {
-    async fn create_task(
-        &self,
-        request: Request<CreateTaskRequest>,
-    ) -> Result<Response<CreateTaskResponse>, Status> {
-        let req = request.into_inner();
-        
-        let task = Task {
-            id: Uuid::new_v4().to_string(),
+    /// Id of the authenticated caller, empty for anonymous requests.
+    async fn caller_id<T>(&self, request: &Request<T>) -> String {
+        auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default()
+    }
+
+    /// The authenticated caller, for RPCs that refuse anonymous requests.
+    async fn require_caller<T>(&self, request: &Request<T>) -> Result<User, Status> {
+        auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .ok_or_else(|| Status::unauthenticated("Missing or invalid access token"))
+    }
+
+    /// Validate a create request and build the task it describes, not yet stored
+    async fn new_task(&self, mut req: CreateTaskRequest, actor: &str) -> Result<Task, Status> {
+        // Anonymous requests have no creator to fall back to
+        if self.config.auto_assign_to_creator && req.assigned_to.is_empty() {
+            req.assigned_to = actor.to_string();
+        }
+        validate_text_lengths(&self.config, Some(&req.title), Some(&req.description))?;
+        check_due_date_policy(&self.config, priority_or_default(&self.config, req.priority), req.due_date.as_ref())?;
+        check_due_date_not_past(&self.config, req.due_date.as_ref(), req.allow_past_due, SystemTime::now())?;
+        validate_priority_score(req.priority_score)?;
+        normalize_tags(&self.config, &mut req.tags)?;
+        validate_custom_fields(&self.config, &req.custom_fields)?;
+        check_project(&self.storage, &req.project_id).await?;
+        check_parent(&self.storage, &req.parent_id).await?;
+
+        Ok(Task {
+            id: self.config.id_format.generate(),
             title: req.title,
             description: req.description,
-            status: TaskStatus::Todo as i32,
-            priority: req.priority,
+            status: self.config.initial_status as i32,
+            priority: priority_or_default(&self.config, req.priority),
             tags: req.tags,
             assigned_to: req.assigned_to,
             created_at: Some(Self::system_time_to_timestamp(SystemTime::now())),
@@ -62,9 +110,518 @@ impl TaskService for TaskServiceImpl {
             }),
             comments: vec![],
             attachments: vec![],
+            priority_score: req.priority_score,
+            watchers: vec![],
+            project_id: req.project_id,
+            snooze_count: 0,
+            reminders: vec![],
+            number: 0,
+            parent_id: req.parent_id,
+            custom_fields: req.custom_fields,
+        })
+    }
+
+    /// The authenticated caller, refusing anyone but `user_id` itself or an
+    /// admin; saved views are private to the user who made them.
+    async fn require_self_or_admin<T>(&self, request: &Request<T>, user_id: &str) -> Result<User, Status> {
+        let caller = self.require_caller(request).await?;
+        if caller.id != user_id && !auth::is_admin(&caller) {
+            return Err(Status::permission_denied("Only the user or an admin can use these saved views"));
+        }
+        Ok(caller)
+    }
+
+    /// Resolves with the shutdown event, ignoring everything else on the bus.
+    async fn next_shutdown(events: &mut broadcast::Receiver<TaskEvent>) -> TaskEvent {
+        loop {
+            match events.recv().await {
+                Ok(event) if event.event_type == TaskEventType::ServerShutdown as i32 => return event,
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                // The bus lives as long as storage, so this only happens at teardown
+                Err(broadcast::error::RecvError::Closed) => std::future::pending::<()>().await,
+            }
+        }
+    }
+
+    /// Register a new stream, refusing it when the client is at its stream limit.
+    #[allow(clippy::result_large_err)]
+    fn register_stream(
+        &self,
+        bus: &EventBus,
+        kind: &'static str,
+        caller: &str,
+        peer_ip: Option<&str>,
+        filters: StreamFilters,
+    ) -> Result<StreamRegistration, Status> {
+        bus.register_stream(kind, caller, peer_ip.unwrap_or_default(), filters, self.config.max_streams_per_client)
+            .ok_or_else(|| {
+                Status::resource_exhausted(format!(
+                    "Too many open streams; at most {} per client",
+                    self.config.max_streams_per_client
+                ))
+            })
+    }
+
+    fn publish(&self, event_type: TaskEventType, task: &Task, actor: &str) {
+        self.storage.events().publish(event_type, task, actor, HashMap::new());
+    }
+
+    /// With `auto_complete_parents`, walk up from a task that just became Done,
+    /// completing each ancestor whose subtasks are now all Done. Each ancestor
+    /// is visited at most once, so even a cycle of parents can't loop forever.
+    async fn complete_parents(&self, task: &Task, actor: &str) {
+        if !self.config.auto_complete_parents || task.status != TaskStatus::Done as i32 {
+            return;
+        }
+        let mut visited = HashSet::from([task.id.clone()]);
+        let mut child_id = task.id.clone();
+        let mut parent_id = task.parent_id.clone();
+        while !parent_id.is_empty() && visited.insert(parent_id.clone()) {
+            let now = Self::system_time_to_timestamp(SystemTime::now());
+            let Some(parent) = self.storage.complete_parent_if_children_done(&parent_id, now).await else {
+                break;
+            };
+            let metadata = HashMap::from([("completed_by_subtask".to_string(), child_id)]);
+            self.storage.events().publish(TaskEventType::Completed, &parent, actor, metadata);
+            child_id = parent.id;
+            parent_id = parent.parent_id;
+        }
+    }
+
+    fn system_time_to_timestamp(time: SystemTime) -> SerdeTimestamp {
+        let duration = time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        let timestamp = Timestamp {
+            seconds: duration.as_secs() as i64,
+            nanos: duration.subsec_nanos() as i32,
         };
+        SerdeTimestamp(timestamp)
+    }
+
+    async fn compute_analytics(&self, include_archived: bool) -> TaskAnalytics {
+        // Simulate analytics calculation
+        let mut total_tasks = self.storage.count_tasks().await;
+        let mut completed_tasks = self.storage.count_tasks_by_status(TaskStatus::Done).await;
+        let mut in_progress_tasks = self.storage.count_tasks_by_status(TaskStatus::InProgress).await;
+        let mut todo_tasks = self.storage.count_tasks_by_status(TaskStatus::Todo).await;
+        if include_archived {
+            let archived = self.storage.archived_status_counts().await;
+            let archived_with = |status: TaskStatus| archived.get(&(status as i32)).copied().unwrap_or(0);
+            total_tasks += archived.values().sum::<u64>();
+            completed_tasks += archived_with(TaskStatus::Done);
+            in_progress_tasks += archived_with(TaskStatus::InProgress);
+            todo_tasks += archived_with(TaskStatus::Todo);
+        }
+        
+        TaskAnalytics {
+            total_tasks,
+            completed_tasks,
+            in_progress_tasks,
+            todo_tasks,
+            completion_rate: if total_tasks > 0 {
+                (completed_tasks as f32 / total_tasks as f32) * 100.0
+            } else {
+                0.0
+            },
+            average_completion_time_hours: 24.5, // Simulated
+            overdue_tasks: self.storage.count_overdue_tasks().await,
+            due_soon_tasks: self.storage.count_due_soon_tasks().await,
+            tasks_by_priority: std::collections::HashMap::from([
+                (TaskPriority::High as i32, 15),
+                (TaskPriority::Medium as i32, 25),
+                (TaskPriority::Low as i32, 10),
+            ]),
+            tasks_created_this_week: 12,
+            tasks_completed_this_week: 8,
+        }
+    }
+
+    /// Apply one set of bulk changes to each listed task, or with `dry_run`
+    /// only report what would happen.
+    async fn bulk_update(&self, mut req: BulkUpdateTasksRequest, actor: &str) -> Result<BulkUpdateTasksResponse, Status> {
+        normalize_tags(&self.config, &mut req.tags_to_add)?;
+        let mut tags_to_remove = Vec::with_capacity(req.tags_to_remove.len());
+        for tag in &req.tags_to_remove {
+            tags_to_remove.push(normalize_tag(&self.config, tag)?);
+        }
+        
+        let mut updated_count = 0;
+        let mut failed_ids = Vec::new();
+        let mut results = Vec::with_capacity(req.task_ids.len());
+        // A dry run saves nothing, so count its assignments against the quota here
+        let mut pending_assignments = 0;
+        
+        for task_id in req.task_ids {
+            let outcome = match self.storage.get_task(&task_id).await {
+                Some(mut task) => {
+                    let reassigning = !req.assigned_to.is_empty() && task.assigned_to != req.assigned_to;
+                    let quota = if reassigning {
+                        check_quota(&self.storage, &self.config, &req.assigned_to, pending_assignments).await
+                    } else {
+                        Ok(())
+                    };
+                    let transition = if req.status != TaskStatus::Unspecified as i32 {
+                        check_transition(task.status, req.status)
+                    } else {
+                        Ok(())
+                    };
+                    if req.status != TaskStatus::Unspecified as i32 {
+                        task.status = req.status;
+                    }
+                    if !req.assigned_to.is_empty() {
+                        task.assigned_to = req.assigned_to.clone();
+                    }
+                    for tag in &req.tags_to_add {
+                        if !task.tags.contains(tag) {
+                            task.tags.push(tag.clone());
+                        }
+                    }
+                    // Compare normalized so tags stored before normalization still match
+                    task.tags.retain(|tag| {
+                        normalize_tag(&self.config, tag).map_or(true, |tag| !tags_to_remove.contains(&tag))
+                    });
+                    task.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
+
+                    if let Err(status) = transition {
+                        Err((BulkUpdateError::InvalidTransition, status.message().to_string()))
+                    } else if task.tags.len() > self.config.max_tags_per_task {
+                        Err((BulkUpdateError::Invalid, too_many_tags(&self.config).message().to_string()))
+                    } else if let Err(status) = quota {
+                        Err((BulkUpdateError::QuotaExceeded, status.message().to_string()))
+                    } else if req.dry_run {
+                        pending_assignments += u64::from(reassigning);
+                        Ok(())
+                    } else {
+                        match self.storage.update_task(task.clone()).await {
+                            Ok(()) => {
+                                self.publish(TaskEventType::Updated, &task, actor);
+                                self.complete_parents(&task, actor).await;
+                                Ok(())
+                            }
+                            Err(e) => Err((BulkUpdateError::Storage, e.to_string())),
+                        }
+                    }
+                }
+                None => Err((BulkUpdateError::NotFound, "Task not found".to_string())),
+            };
+
+            results.push(match outcome {
+                Ok(()) => {
+                    updated_count += 1;
+                    BulkUpdateResult { task_id, success: true, ..Default::default() }
+                }
+                Err((error, error_message)) => {
+                    failed_ids.push(task_id.clone());
+                    BulkUpdateResult {
+                        task_id,
+                        success: false,
+                        error: error as i32,
+                        error_message,
+                    }
+                }
+            });
+        }
+        
+        Ok(BulkUpdateTasksResponse {
+            updated_count,
+            failed_ids,
+            message: if req.dry_run {
+                format!("Would update {} tasks", updated_count)
+            } else {
+                format!("Updated {} tasks", updated_count)
+            },
+            results,
+            dry_run: req.dry_run,
+        })
+    }
+
+    fn sort_or_default<'a>(&'a self, sort: Option<&'a TaskSort>) -> &'a TaskSort {
+        sort.filter(|sort| sort.field != TaskSortField::Unspecified as i32)
+            .unwrap_or(&self.config.default_sort)
+    }
+
+    /// Run a filter/sort spec through the list pipeline and paginate the result.
+    async fn list_filtered(
+        &self,
+        filter: Option<&TaskFilter>,
+        sort: Option<&TaskSort>,
+        page_size: i32,
+        page_token: &str,
+    ) -> ListTasksResponse {
+        let matching = self.storage.query_tasks(filter, Some(self.sort_or_default(sort))).await;
+        let total_count = matching.len() as u64;
+        let grand_total = self.storage.count_tasks().await;
+        let (current_page, total_pages) = page_info(page_token, page_size, total_count);
+        let warning = soft_limit_warning(total_count, self.config.result_soft_limit);
+        let tasks = paginate(matching, page_size, page_token);
+
+        ListTasksResponse {
+            next_page_token: next_page_token(page_token, tasks.len(), page_size),
+            tasks,
+            total_count,
+            grand_total,
+            current_page,
+            total_pages,
+            warning,
+        }
+    }
+}
+
+/// Whether `event` passes a stream subscription's filters.
+fn event_matches(req: &StreamTaskEventsRequest, event: &TaskEvent) -> bool {
+    let task = event.task.as_ref();
+    let task_matches = req.task_ids.is_empty()
+        || task.is_some_and(|task| req.task_ids.contains(&task.id));
+    // A user follows events they caused, events on tasks assigned to or
+    // watched by them, and reminders they set
+    let reminded = event.event_type == TaskEventType::Reminder as i32
+        && event.metadata.get("user_id") == Some(&req.user_id);
+    let user_matches = req.user_id.is_empty()
+        || event.user_id == req.user_id
+        || reminded
+        || task.is_some_and(|task| task.assigned_to == req.user_id || task.watchers.contains(&req.user_id));
+    let type_matches = req.event_types.is_empty() || req.event_types.contains(&event.event_type);
+    task_matches && user_matches && type_matches
+}
+
+/// Shape a looked-up task for GetTask/GetTaskByNumber, keeping only the most
+/// recent comments unless all were asked for
+fn get_task_response(task: Option<Task>, include_comments: bool) -> GetTaskResponse {
+    let Some(mut task) = task else {
+        return GetTaskResponse { task: None, found: false, comment_count: 0 };
+    };
+    let comment_count = task.comments.len() as u64;
+    if !include_comments {
+        // The full list is paged through ListTaskComments
+        let skip = task.comments.len().saturating_sub(RECENT_COMMENTS);
+        task.comments.drain(..skip);
+    }
+    GetTaskResponse { task: Some(task), found: true, comment_count }
+}
+
+fn priority_or_default(config: &Config, requested: i32) -> i32 {
+    if requested == TaskPriority::Unspecified as i32 {
+        config.default_priority as i32
+    } else {
+        requested
+    }
+}
+
+// `Status` is large, but it is what every caller returns anyway
+#[allow(clippy::result_large_err)]
+fn validate_priority_score(score: Option<f64>) -> Result<(), Status> {
+    match score {
+        Some(score) if !PRIORITY_SCORE_RANGE.contains(&score) => Err(Status::invalid_argument(format!(
+            "priority_score must be between {} and {}",
+            PRIORITY_SCORE_RANGE.start(),
+            PRIORITY_SCORE_RANGE.end()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a title or description longer than its configured limit. Lengths
+/// are counted in characters, so non-ASCII text gets the same allowance.
+#[allow(clippy::result_large_err)]
+fn validate_text_lengths(config: &Config, title: Option<&str>, description: Option<&str>) -> Result<(), Status> {
+    let checks = [
+        ("title", title, config.max_title_length),
+        ("description", description, config.max_description_length),
+    ];
+    for (field, value, limit) in checks {
+        if value.is_some_and(|value| value.chars().count() > limit) {
+            return Err(Status::invalid_argument(format!(
+                "Task {} may be at most {} characters long",
+                field, limit
+            )));
+        }
+    }
+    Ok(())
+}
 
-        self.storage.create_task(task.clone()).await;
+/// Parse a `YYYY-MM-DD` request field, naming it in the error
+#[allow(clippy::result_large_err)]
+fn parse_date(name: &str, value: &str) -> Result<chrono::NaiveDate, Status> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| Status::invalid_argument(format!("{} must be a date like 2024-01-31", name)))
+}
+
+/// Check a task's custom fields against the configured count and size
+/// limits. Keys are restricted so they can be named in a `custom.<key>`
+/// query parameter or a `custom_fields.<key>` update mask path.
+#[allow(clippy::result_large_err)]
+fn validate_custom_fields(config: &Config, fields: &HashMap<String, String>) -> Result<(), Status> {
+    if fields.len() > config.max_custom_fields {
+        return Err(Status::invalid_argument(format!(
+            "A task may have at most {} custom fields",
+            config.max_custom_fields
+        )));
+    }
+    let limit = config.max_custom_field_length;
+    for (key, value) in fields {
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(Status::invalid_argument(format!(
+                "Invalid custom field name '{}': use letters, digits, '_' and '-'",
+                key
+            )));
+        }
+        if key.chars().count() > limit || value.chars().count() > limit {
+            return Err(Status::invalid_argument(format!(
+                "Custom field names and values may be at most {} characters long",
+                limit
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Enforce the due date policy: tasks at or above the configured priority
+/// must have a due date.
+#[allow(clippy::result_large_err)]
+fn check_due_date_policy(config: &Config, priority: i32, due_date: Option<&SerdeTimestamp>) -> Result<(), Status> {
+    match config.due_date_required_from {
+        Some(threshold) if priority >= threshold as i32 && due_date.is_none() => {
+            Err(Status::invalid_argument(format!(
+                "Tasks with priority {} or higher require a due date",
+                threshold.as_str_name()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Refuse a new task due before `now`, unless past due dates are allowed by
+/// config or, for backfills, by the request.
+#[allow(clippy::result_large_err)]
+fn check_due_date_not_past(
+    config: &Config,
+    due_date: Option<&SerdeTimestamp>,
+    allow_past_due: bool,
+    now: SystemTime,
+) -> Result<(), Status> {
+    match due_date {
+        Some(due) if config.reject_past_due_dates && !allow_past_due && due.to_system_time() < now => {
+            Err(Status::invalid_argument(
+                "due_date is in the past; set allow_past_due to create the task anyway",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Trim a tag and, if configured, lowercase it, rejecting empty or overlong tags.
+#[allow(clippy::result_large_err)]
+fn normalize_tag(config: &Config, tag: &str) -> Result<String, Status> {
+    let trimmed = tag.trim();
+    if trimmed.is_empty() {
+        return Err(Status::invalid_argument("Tags must not be empty"));
+    }
+    if trimmed.chars().count() > config.max_tag_length {
+        return Err(Status::invalid_argument(format!(
+            "Tags may be at most {} characters long",
+            config.max_tag_length
+        )));
+    }
+    Ok(if config.lowercase_tags { trimmed.to_lowercase() } else { trimmed.to_string() })
+}
+
+/// Normalize every tag, drop duplicates, and check the count against the limit.
+#[allow(clippy::result_large_err)]
+fn normalize_tags(config: &Config, tags: &mut Vec<String>) -> Result<(), Status> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags.iter() {
+        let tag = normalize_tag(config, tag)?;
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    if normalized.len() > config.max_tags_per_task {
+        return Err(too_many_tags(config));
+    }
+    *tags = normalized;
+    Ok(())
+}
+
+fn too_many_tags(config: &Config) -> Status {
+    Status::invalid_argument(format!("A task may have at most {} tags", config.max_tags_per_task))
+}
+
+/// Tasks may only join projects that exist; an empty id means no project.
+#[allow(clippy::result_large_err)]
+async fn check_project(storage: &Storage, project_id: &str) -> Result<(), Status> {
+    if project_id.is_empty() || storage.get_project(project_id).await.is_some() {
+        Ok(())
+    } else {
+        Err(Status::invalid_argument(format!("Project {} does not exist", project_id)))
+    }
+}
+
+/// Subtasks may only be created under tasks that exist; an empty id means none.
+#[allow(clippy::result_large_err)]
+async fn check_parent(storage: &Storage, parent_id: &str) -> Result<(), Status> {
+    if parent_id.is_empty() || storage.get_task(parent_id).await.is_some() {
+        Ok(())
+    } else {
+        Err(Status::invalid_argument(format!("Parent task {} does not exist", parent_id)))
+    }
+}
+
+/// Refuse to give `user_id` more tasks once they hold the configured maximum,
+/// counting `pending` assignments not yet saved. Unassigned tasks are exempt.
+#[allow(clippy::result_large_err)]
+async fn check_quota(storage: &Storage, config: &Config, user_id: &str, pending: u64) -> Result<(), Status> {
+    let limit = config.max_tasks_per_user;
+    if user_id.is_empty() || limit == 0 || storage.count_user_tasks(user_id).await + pending < limit {
+        Ok(())
+    } else {
+        Err(Status::resource_exhausted(format!("User already has the maximum of {} tasks", limit)))
+    }
+}
+
+/// The index of one of `task`'s comments, checking that `caller` wrote the
+/// comment or is an admin.
+#[allow(clippy::result_large_err)]
+fn own_comment(caller: &User, task: &Task, comment_id: &str) -> Result<usize, Status> {
+    let index = task.comments
+        .iter()
+        .position(|comment| comment.id == comment_id)
+        .ok_or_else(|| Status::not_found("Comment not found"))?;
+    if task.comments[index].author_id != caller.id && !auth::is_admin(caller) {
+        return Err(Status::permission_denied("Only the author or an admin can change this comment"));
+    }
+    Ok(index)
+}
+
+/// Reject a status change the workflow doesn't allow
+#[allow(clippy::result_large_err)]
+fn check_transition(from: i32, to: i32) -> Result<(), Status> {
+    let from = TaskStatus::try_from(from).unwrap_or(TaskStatus::Unspecified);
+    let to = TaskStatus::try_from(to).unwrap_or(TaskStatus::Unspecified);
+    if workflow::can_transition(from, to) {
+        Ok(())
+    } else {
+        Err(Status::failed_precondition(format!(
+            "Cannot move task from {} to {}",
+            from.as_str_name(),
+            to.as_str_name()
+        )))
+    }
+}
+
+#[tonic::async_trait]
+impl TaskService for TaskServiceImpl {
+    async fn create_task(
+        &self,
+        request: Request<CreateTaskRequest>,
+    ) -> Result<Response<CreateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let task = self.new_task(request.into_inner(), &actor).await?;
+        check_quota(&self.storage, &self.config, &task.assigned_to, 0).await?;
+
+        let task = self.storage
+            .create_task(task)
+            .await
+            .map_err(|e| Status::from(TaskerError::from(e.context("Failed to create task"))))?;
+        self.publish(TaskEventType::Created, &task, &actor);
 
         let response = CreateTaskResponse {
             task: Some(task),
@@ -75,45 +632,128 @@ impl TaskService for TaskServiceImpl {
         Ok(Response::new(response))
     }
 
+    async fn upsert_task(
+        &self,
+        request: Request<CreateTaskRequest>,
+    ) -> Result<Response<UpsertTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let task = self.new_task(request.into_inner(), &actor).await?;
+        let key = &self.config.upsert_natural_key;
+        // A match needs no room for a new task, so look before the quota checks;
+        // the upsert itself checks again under the write lock
+        if let Some(existing) = self.storage.find_by_natural_key(&task, key).await {
+            return Ok(Response::new(UpsertTaskResponse { task: Some(existing), created: false }));
+        }
+        check_quota(&self.storage, &self.config, &task.assigned_to, 0).await?;
+
+        let (task, created) = self.storage
+            .upsert_task(task, key)
+            .await
+            .map_err(|e| Status::from(TaskerError::from(e.context("Failed to create task"))))?;
+        if created {
+            self.publish(TaskEventType::Created, &task, &actor);
+        }
+
+        Ok(Response::new(UpsertTaskResponse { task: Some(task), created }))
+    }
+
     async fn get_task(
         &self,
         request: Request<GetTaskRequest>,
     ) -> Result<Response<GetTaskResponse>, Status> {
         let req = request.into_inner();
-        
-        if let Some(task) = self.storage.get_task(&req.id).await {
-            let response = GetTaskResponse {
-                task: Some(task),
-                found: true,
-            };
-            Ok(Response::new(response))
-        } else {
-            let response = GetTaskResponse {
-                task: None,
-                found: false,
-            };
-            Ok(Response::new(response))
-        }
+        let task = self.storage.get_task(&req.id).await;
+        Ok(Response::new(get_task_response(task, req.include_comments)))
+    }
+
+    async fn get_task_by_number(
+        &self,
+        request: Request<GetTaskByNumberRequest>,
+    ) -> Result<Response<GetTaskResponse>, Status> {
+        let req = request.into_inner();
+        let task = self.storage.get_task_by_number(req.number).await;
+        Ok(Response::new(get_task_response(task, req.include_comments)))
     }
 
     async fn update_task(
         &self,
         request: Request<UpdateTaskRequest>,
     ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
         let req = request.into_inner();
     
         // Ensure task data is provided
         if let Some(mut patch) = req.task {
+            let unknown_metric = req.update_mask
+                .iter()
+                .filter_map(|field| field.strip_prefix("metrics."))
+                .find(|sub_field| !METRICS_FIELDS.contains(sub_field));
+            if let Some(sub_field) = unknown_metric {
+                return Err(Status::invalid_argument(format!("Unknown metrics field in update mask: {}", sub_field)));
+            }
+            let masked = |name: &str| req.update_mask.iter().any(|field| field == name);
+            validate_text_lengths(
+                &self.config,
+                masked("title").then_some(patch.title.as_str()),
+                masked("description").then_some(patch.description.as_str()),
+            )?;
+            if masked("priority") || masked("due_date") {
+                let current = self.storage
+                    .get_task(&req.id)
+                    .await
+                    .ok_or_else(|| Status::not_found("Task not found"))?;
+                let priority = if masked("priority") { patch.priority } else { current.priority };
+                let due_date = if masked("due_date") { patch.due_date.as_ref() } else { current.due_date.as_ref() };
+                check_due_date_policy(&self.config, priority, due_date)?;
+            }
+            // The same workflow PATCH /status enforces
+            if masked("status") {
+                let current = self.storage
+                    .get_task(&req.id)
+                    .await
+                    .ok_or_else(|| Status::not_found("Task not found"))?;
+                check_transition(current.status, patch.status)?;
+            }
+            if masked("priority_score") {
+                validate_priority_score(patch.priority_score)?;
+            }
+            if masked("tags") {
+                normalize_tags(&self.config, &mut patch.tags)?;
+            }
+            if req.update_mask.iter().any(|field| field == "custom_fields" || field.starts_with("custom_fields.")) {
+                let current = self.storage
+                    .get_task(&req.id)
+                    .await
+                    .ok_or_else(|| Status::not_found("Task not found"))?;
+                let mut fields = current.custom_fields;
+                apply_custom_field_mask(&mut fields, &patch.custom_fields, &req.update_mask);
+                validate_custom_fields(&self.config, &fields)?;
+            }
+            if masked("project_id") {
+                check_project(&self.storage, &patch.project_id).await?;
+            }
+            if masked("assignedTo") {
+                let current = self.storage.get_task(&req.id).await.map(|task| task.assigned_to);
+                if current.as_deref() != Some(patch.assigned_to.as_str()) {
+                    check_quota(&self.storage, &self.config, &patch.assigned_to, 0).await?;
+                }
+            }
             // Ensure ID is set
             patch.id = req.id.clone();
             patch.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
     
             // Apply patch via storage, mapping errors to tonic::Status
-            let updated = self
+            self
                 .storage
                 .patch_task(&req.id, patch.clone(), &req.update_mask)
                 .await
                 .map_err(|e| Status::not_found(format!("Failed to update task: {}", e)))?;
+            if let Some(updated) = self.storage.get_task(&req.id).await {
+                self.publish(TaskEventType::Updated, &updated, &actor);
+                if masked("status") {
+                    self.complete_parents(&updated, &actor).await;
+                }
+            }
     
             // Return the updated task (clone patch, since storage returns `()`)
             let response = UpdateTaskResponse {
@@ -128,18 +768,68 @@ impl TaskService for TaskServiceImpl {
         }
     }
 
+    async fn update_task_status(
+        &self,
+        request: Request<UpdateTaskStatusRequest>,
+    ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+
+        let to = TaskStatus::try_from(req.status)
+            .ok()
+            .filter(|status| *status != TaskStatus::Unspecified)
+            .ok_or_else(|| Status::invalid_argument("Unknown task status"))?;
+        let mut task = self.storage
+            .get_task(&req.id)
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+
+        check_transition(task.status, to as i32)?;
+
+        task.status = to as i32;
+        if let Some(completion) = workflow::completion_for(to) {
+            task.metrics.get_or_insert_with(Default::default).completion_percentage = completion;
+        }
+        task.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
+
+        self.storage
+            .update_task(task.clone())
+            .await
+            .map_err(|e| Status::internal(format!("Failed to update task: {}", e)))?;
+
+        let event_type = if to == TaskStatus::Done { TaskEventType::Completed } else { TaskEventType::Updated };
+        self.publish(event_type, &task, &actor);
+        self.complete_parents(&task, &actor).await;
+
+        let response = UpdateTaskResponse {
+            task: Some(task),
+            success: true,
+            message: "Task status updated successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
     async fn delete_task(
         &self,
         request: Request<DeleteTaskRequest>,
     ) -> Result<Response<DeleteTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
         let req = request.into_inner();
     
-        // Await the Result<bool, E> and map errors to a tonic::Status
-        let success = self
-            .storage
-            .delete_task(&req.id)
-            .await
+        let existing = self.storage.get_task(&req.id).await;
+
+        // Soft deletes leave a tombstone behind for compaction to purge later
+        let result = if req.force {
+            self.storage.delete_task(&req.id).await
+        } else {
+            self.storage.soft_delete_task(&req.id).await
+        };
+        let success = result
             .map_err(|e| Status::internal(format!("Failed to delete task: {}", e)))?;
+        if let (true, Some(task)) = (success, &existing) {
+            self.publish(TaskEventType::Deleted, task, &actor);
+        }
     
         let response = DeleteTaskResponse {
             success,
@@ -153,61 +843,643 @@ impl TaskService for TaskServiceImpl {
         Ok(Response::new(response))
     }
 
-    async fn list_tasks(
+    async fn archive_task(
         &self,
-        request: Request<ListTasksRequest>,
-    ) -> Result<Response<ListTasksResponse>, Status> {
+        request: Request<ArchiveTaskRequest>,
+    ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
         let req = request.into_inner();
-        
-        let tasks = self.storage.list_tasks(req.page_size, &req.page_token).await;
-        
-        let response = ListTasksResponse {
-            tasks: tasks.clone(),
-            next_page_token: if tasks.len() >= req.page_size as usize {
-                format!("page_{}", req.page_token.parse::<u32>().unwrap_or(0) + 1)
-            } else {
-                String::new()
-            },
-            total_count: self.storage.count_tasks().await,
+
+        let task = self.storage
+            .archive_task(&req.id)
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+        self.publish(TaskEventType::Archived, &task, &actor);
+
+        let response = UpdateTaskResponse {
+            task: Some(task),
+            success: true,
+            message: "Task archived successfully".to_string(),
         };
-        
+
         Ok(Response::new(response))
     }
 
-    async fn bulk_update_tasks(
+    async fn list_archived_tasks(
         &self,
-        request: Request<BulkUpdateTasksRequest>,
-    ) -> Result<Response<BulkUpdateTasksResponse>, Status> {
+        request: Request<ListArchivedTasksRequest>,
+    ) -> Result<Response<ListTasksResponse>, Status> {
         let req = request.into_inner();
-        
-        let mut updated_count = 0;
-        let mut failed_ids = Vec::new();
-        
-        for task_id in req.task_ids {
-            if let Some(mut task) = self.storage.get_task(&task_id).await {
-                if req.status != TaskStatus::Unspecified as i32 {
-                    task.status = req.status;
+
+        let tasks = self.storage.list_archived_tasks().await;
+        let total_count = tasks.len() as u64;
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
+        let warning = soft_limit_warning(total_count, self.config.result_soft_limit);
+        let tasks = paginate(tasks, req.page_size, &req.page_token);
+
+        let response = ListTasksResponse {
+            next_page_token: next_page_token(&req.page_token, tasks.len(), req.page_size),
+            tasks,
+            total_count,
+            grand_total: total_count,
+            current_page,
+            total_pages,
+            warning,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn restore_archived_task(
+        &self,
+        request: Request<RestoreArchivedTaskRequest>,
+    ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+
+        let task = self.storage
+            .restore_archived_task(&req.id)
+            .await
+            .map_err(|e| match TaskerError::from(e) {
+                TaskerError::Storage(e) => Status::failed_precondition(e.to_string()),
+                other => other.into(),
+            })?
+            .ok_or_else(|| Status::not_found("Archived task not found"))?;
+        self.publish(TaskEventType::Restored, &task, &actor);
+
+        let response = UpdateTaskResponse {
+            task: Some(task),
+            success: true,
+            message: "Task restored successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn merge_tasks(
+        &self,
+        request: Request<MergeTasksRequest>,
+    ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+
+        if req.source_id == req.target_id {
+            return Err(Status::invalid_argument("A task can't be merged into itself"));
+        }
+        let (Some(source), Some(target)) = (
+            self.storage.get_task(&req.source_id).await,
+            self.storage.get_task(&req.target_id).await,
+        ) else {
+            return Err(Status::not_found("Task not found"));
+        };
+        let tag_count = target.tags.len() + source.tags.iter().filter(|tag| !target.tags.contains(tag)).count();
+        if tag_count > self.config.max_tags_per_task {
+            return Err(too_many_tags(&self.config));
+        }
+
+        let merged = self.storage
+            .merge_tasks(&req.source_id, &req.target_id)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to merge tasks: {}", e)))?
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+
+        let events = self.storage.events();
+        let metadata = HashMap::from([("merged_into".to_string(), merged.id.clone())]);
+        events.publish(TaskEventType::Deleted, &source, &actor, metadata);
+        let metadata = HashMap::from([("source_id".to_string(), source.id.clone())]);
+        events.publish(TaskEventType::Merged, &merged, &actor, metadata);
+
+        let response = UpdateTaskResponse {
+            task: Some(merged),
+            success: true,
+            message: format!("Merged task {} into this task", source.id),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn snooze_task(
+        &self,
+        request: Request<SnoozeTaskRequest>,
+    ) -> Result<Response<UpdateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+
+        let max_snoozes = self.config.max_snoozes_per_task;
+        let now = SerdeTimestamp::now();
+        let mut previous = None;
+        #[allow(clippy::result_large_err)]
+        let task = self.storage
+            .modify_task(&req.id, |task| {
+                if max_snoozes > 0 && task.snooze_count >= max_snoozes {
+                    return Err(Status::resource_exhausted(format!(
+                        "Task has already been snoozed {} times, the most allowed",
+                        max_snoozes
+                    )));
                 }
-                if !req.assigned_to.is_empty() {
-                    task.assigned_to = req.assigned_to.clone();
+
+                previous = task.due_date.clone();
+                let current = previous.as_ref().map_or(now.seconds, |due| due.seconds);
+                let due_date = match (req.until, req.duration_secs) {
+                    (Some(_), duration) if duration != 0 => {
+                        return Err(Status::invalid_argument("Set either 'until' or 'duration_secs', not both"));
+                    }
+                    (Some(until), _) => {
+                        if until.seconds <= now.seconds || until.seconds <= current {
+                            return Err(Status::invalid_argument("'until' must be later than now and the current due date"));
+                        }
+                        until
+                    }
+                    (None, duration) if duration > 0 => SerdeTimestamp(Timestamp {
+                        seconds: current.max(now.seconds) + duration,
+                        nanos: 0,
+                    }),
+                    (None, _) => {
+                        return Err(Status::invalid_argument("Either 'until' or a positive 'duration_secs' is required"));
+                    }
+                };
+
+                task.due_date = Some(due_date);
+                task.snooze_count += 1;
+                task.updated_at = Some(now.clone());
+                Ok(())
+            })
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))??;
+
+        let mut metadata = HashMap::from([
+            ("reason".to_string(), "snoozed".to_string()),
+            ("snooze_count".to_string(), task.snooze_count.to_string()),
+        ]);
+        if let Some(previous) = previous {
+            metadata.insert("previous_due_date".to_string(), previous.to_string());
+        }
+        self.storage.events().publish(TaskEventType::Updated, &task, &actor, metadata);
+
+        let response = UpdateTaskResponse {
+            task: Some(task),
+            success: true,
+            message: "Task snoozed successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn set_reminder(
+        &self,
+        request: Request<SetReminderRequest>,
+    ) -> Result<Response<SetReminderResponse>, Status> {
+        let caller = self.require_caller(&request).await?;
+        let req = request.into_inner();
+
+        if req.before_secs < 0 {
+            return Err(Status::invalid_argument("before_secs can't be negative"));
+        }
+        let reminder = TaskReminder {
+            id: Uuid::new_v4().to_string(),
+            user_id: caller.id,
+            before_secs: req.before_secs,
+            fired_at: None,
+        };
+        #[allow(clippy::result_large_err)]
+        self.storage
+            .modify_task(&req.task_id, |task| {
+                if task.due_date.is_none() {
+                    return Err(Status::failed_precondition("Task has no due date to be reminded of"));
                 }
-                task.tags.extend(req.tags_to_add.clone());
-                task.tags.retain(|tag| !req.tags_to_remove.contains(tag));
-                task.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
-                
-                self.storage.update_task(task).await;
-                updated_count += 1;
-            } else {
-                failed_ids.push(task_id);
+                if task.reminders.len() >= MAX_REMINDERS_PER_TASK {
+                    return Err(Status::resource_exhausted(format!(
+                        "A task may have at most {} reminders",
+                        MAX_REMINDERS_PER_TASK
+                    )));
+                }
+                task.reminders.push(reminder.clone());
+                Ok(())
+            })
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))??;
+
+        let response = SetReminderResponse {
+            reminder: Some(reminder),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn clone_task(
+        &self,
+        request: Request<CloneTaskRequest>,
+    ) -> Result<Response<CreateTaskResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+
+        let source = self.storage
+            .get_task(&req.task_id)
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+        let mut overrides = req.overrides.unwrap_or_default();
+        validate_text_lengths(&self.config, overrides.title.as_deref(), overrides.description.as_deref())?;
+        normalize_tags(&self.config, &mut overrides.tags)?;
+        let assigned_to = overrides.assigned_to.unwrap_or(source.assigned_to);
+        check_quota(&self.storage, &self.config, &assigned_to, 0).await?;
+        let priority = overrides.priority.unwrap_or(source.priority);
+        check_due_date_policy(&self.config, priority, overrides.due_date.as_ref())?;
+
+        let now = Self::system_time_to_timestamp(SystemTime::now());
+        let estimated_hours = source.metrics.as_ref().map_or(0, |m| m.estimated_hours);
+        let task = Task {
+            id: self.config.id_format.generate(),
+            title: overrides.title.unwrap_or(source.title),
+            description: overrides.description.unwrap_or(source.description),
+            status: self.config.initial_status as i32,
+            priority,
+            tags: if overrides.tags.is_empty() { source.tags } else { overrides.tags },
+            assigned_to,
+            created_at: Some(now.clone()),
+            updated_at: Some(now),
+            due_date: overrides.due_date,
+            metrics: Some(TaskMetrics {
+                estimated_hours,
+                actual_hours: 0,
+                completion_percentage: 0.0,
+            }),
+            comments: vec![],
+            attachments: vec![],
+            // An overridden priority would be contradicted by the source's score
+            priority_score: source.priority_score.filter(|_| overrides.priority.is_none()),
+            watchers: vec![],
+            project_id: source.project_id,
+            snooze_count: 0,
+            reminders: vec![],
+            number: 0,
+            parent_id: source.parent_id,
+            custom_fields: source.custom_fields,
+        };
+
+        let task = self.storage
+            .create_task(task)
+            .await
+            .map_err(|e| Status::from(TaskerError::from(e.context("Failed to clone task"))))?;
+        self.publish(TaskEventType::Created, &task, &actor);
+
+        let response = CreateTaskResponse {
+            task: Some(task),
+            success: true,
+            message: "Task cloned successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn add_comment(
+        &self,
+        request: Request<AddCommentRequest>,
+    ) -> Result<Response<CommentResponse>, Status> {
+        let author = self.require_caller(&request).await?;
+        let req = request.into_inner();
+
+        let reviewed = self.moderation.review(&req.content).map_err(Status::invalid_argument)?;
+
+        let now = Self::system_time_to_timestamp(SystemTime::now());
+        let comment = TaskComment {
+            id: Uuid::new_v4().to_string(),
+            task_id: req.task_id.clone(),
+            author_id: author.id.clone(),
+            content: reviewed.content,
+            created_at: Some(now.clone()),
+            edited_at: None,
+            flags: reviewed.flags,
+        };
+
+        // Mentioned users start watching the task; unknown names are ignored
+        let mut mentioned = Vec::new();
+        for username in mentions::mentioned_usernames(&comment.content) {
+            let Some(user) = self.storage.get_user_by_username(&username).await else {
+                continue;
+            };
+            if user.id != author.id && !mentioned.contains(&user.id) {
+                mentioned.push(user.id);
             }
         }
-        
-        let response = BulkUpdateTasksResponse {
-            updated_count,
-            failed_ids,
-            message: format!("Updated {} tasks", updated_count),
+
+        #[allow(clippy::result_large_err)]
+        let task = self.storage
+            .modify_task(&req.task_id, |task| {
+                task.comments.push(comment.clone());
+                task.updated_at = Some(now);
+                for user_id in &mentioned {
+                    if !task.watchers.contains(user_id) {
+                        task.watchers.push(user_id.clone());
+                    }
+                }
+                Ok::<_, Status>(())
+            })
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))??;
+        self.publish(TaskEventType::Commented, &task, &author.id);
+        for user_id in mentioned {
+            let metadata = HashMap::from([
+                ("mentioned_user_id".to_string(), user_id),
+                ("comment_id".to_string(), comment.id.clone()),
+            ]);
+            self.storage.events().publish(TaskEventType::Mentioned, &task, &author.id, metadata);
+        }
+
+        let response = CommentResponse {
+            comment: Some(comment),
+            success: true,
+            message: "Comment added successfully".to_string(),
         };
-        
+
+        Ok(Response::new(response))
+    }
+
+    async fn edit_comment(
+        &self,
+        request: Request<EditCommentRequest>,
+    ) -> Result<Response<CommentResponse>, Status> {
+        let caller = self.require_caller(&request).await?;
+        let req = request.into_inner();
+
+        let reviewed = self.moderation.review(&req.content).map_err(Status::invalid_argument)?;
+
+        let now = Self::system_time_to_timestamp(SystemTime::now());
+        let mut edited = None;
+        #[allow(clippy::result_large_err)]
+        let task = self.storage
+            .modify_task(&req.task_id, |task| {
+                let index = own_comment(&caller, task, &req.comment_id)?;
+                let comment = &mut task.comments[index];
+                comment.content = reviewed.content;
+                comment.flags = reviewed.flags;
+                comment.edited_at = Some(now.clone());
+                edited = Some(comment.clone());
+                task.updated_at = Some(now);
+                Ok::<_, Status>(())
+            })
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))??;
+        let comment = edited.expect("set by a successful edit");
+        self.publish(TaskEventType::Updated, &task, &caller.id);
+
+        let response = CommentResponse {
+            comment: Some(comment),
+            success: true,
+            message: "Comment updated successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn delete_comment(
+        &self,
+        request: Request<DeleteCommentRequest>,
+    ) -> Result<Response<DeleteCommentResponse>, Status> {
+        let caller = self.require_caller(&request).await?;
+        let req = request.into_inner();
+
+        let now = Self::system_time_to_timestamp(SystemTime::now());
+        #[allow(clippy::result_large_err)]
+        let task = self.storage
+            .modify_task(&req.task_id, |task| {
+                let index = own_comment(&caller, task, &req.comment_id)?;
+                task.comments.remove(index);
+                task.updated_at = Some(now);
+                Ok::<_, Status>(())
+            })
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))??;
+        self.publish(TaskEventType::Updated, &task, &caller.id);
+
+        let response = DeleteCommentResponse {
+            success: true,
+            message: "Comment deleted successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn list_task_comments(
+        &self,
+        request: Request<ListTaskCommentsRequest>,
+    ) -> Result<Response<ListTaskCommentsResponse>, Status> {
+        let req = request.into_inner();
+
+        let task = self.storage
+            .get_task(&req.task_id)
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+        let total_count = task.comments.len() as u64;
+
+        // Comments are stored in the order they were added
+        let mut comments = task.comments;
+        if !req.oldest_first {
+            comments.reverse();
+        }
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
+        let comments = paginate(comments, req.page_size, &req.page_token);
+
+        let response = ListTaskCommentsResponse {
+            next_page_token: next_page_token(&req.page_token, comments.len(), req.page_size),
+            comments,
+            total_count,
+            current_page,
+            total_pages,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn list_tasks(
+        &self,
+        request: Request<ListTasksRequest>,
+    ) -> Result<Response<ListTasksResponse>, Status> {
+        let req = request.into_inner();
+
+        let response = self
+            .list_filtered(req.filter.as_ref(), req.sort.as_ref(), req.page_size, &req.page_token)
+            .await;
+
+        Ok(Response::new(response))
+    }
+
+    async fn create_saved_view(
+        &self,
+        request: Request<CreateSavedViewRequest>,
+    ) -> Result<Response<CreateSavedViewResponse>, Status> {
+        self.require_self_or_admin(&request, &request.get_ref().user_id).await?;
+        let req = request.into_inner();
+
+        if req.name.trim().is_empty() {
+            return Err(Status::invalid_argument("View name is required"));
+        }
+        if self.storage.get_user(&req.user_id).await.is_none() {
+            return Err(Status::not_found("User not found"));
+        }
+
+        let view = SavedView {
+            id: Uuid::new_v4().to_string(),
+            user_id: req.user_id,
+            name: req.name,
+            filter: req.filter,
+            sort: req.sort,
+            created_at: Some(Self::system_time_to_timestamp(SystemTime::now())),
+        };
+
+        self.storage
+            .create_saved_view(view.clone())
+            .await
+            .map_err(|e| Status::internal(format!("Failed to save view: {}", e)))?;
+
+        let response = CreateSavedViewResponse {
+            view: Some(view),
+            success: true,
+            message: "View saved successfully".to_string(),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn list_saved_views(
+        &self,
+        request: Request<ListSavedViewsRequest>,
+    ) -> Result<Response<ListSavedViewsResponse>, Status> {
+        self.require_self_or_admin(&request, &request.get_ref().user_id).await?;
+        let req = request.into_inner();
+
+        let response = ListSavedViewsResponse {
+            views: self.storage.list_saved_views(&req.user_id).await,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn apply_saved_view(
+        &self,
+        request: Request<ApplySavedViewRequest>,
+    ) -> Result<Response<ListTasksResponse>, Status> {
+        self.require_self_or_admin(&request, &request.get_ref().user_id).await?;
+        let req = request.into_inner();
+
+        let view = self.storage
+            .get_saved_view(&req.user_id, &req.view_id)
+            .await
+            .ok_or_else(|| Status::not_found("Saved view not found"))?;
+
+        let response = self
+            .list_filtered(view.filter.as_ref(), view.sort.as_ref(), req.page_size, &req.page_token)
+            .await;
+
+        Ok(Response::new(response))
+    }
+
+    async fn bulk_update_tasks(
+        &self,
+        request: Request<BulkUpdateTasksRequest>,
+    ) -> Result<Response<BulkUpdateTasksResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let response = self.bulk_update(request.into_inner(), &actor).await?;
+        Ok(Response::new(response))
+    }
+
+    async fn bulk_tag_tasks(
+        &self,
+        request: Request<BulkTagTasksRequest>,
+    ) -> Result<Response<BulkUpdateTasksResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+        if req.add.is_empty() && req.remove.is_empty() {
+            return Err(Status::invalid_argument("Nothing to do: both 'add' and 'remove' are empty"));
+        }
+        for tag in &req.add {
+            let tag = normalize_tag(&self.config, tag)?;
+            if req.remove.iter().any(|removed| normalize_tag(&self.config, removed).is_ok_and(|removed| removed == tag)) {
+                return Err(Status::invalid_argument(format!("Tag '{}' is both added and removed", tag)));
+            }
+        }
+
+        let changes = BulkUpdateTasksRequest {
+            task_ids: req.task_ids,
+            tags_to_add: req.add,
+            tags_to_remove: req.remove,
+            dry_run: req.dry_run,
+            ..Default::default()
+        };
+        let response = self.bulk_update(changes, &actor).await?;
+        Ok(Response::new(response))
+    }
+
+    async fn bulk_update_tasks_by_filter(
+        &self,
+        request: Request<BulkUpdateTasksByFilterRequest>,
+    ) -> Result<Response<BulkUpdateTasksResponse>, Status> {
+        let actor = self.caller_id(&request).await;
+        let req = request.into_inner();
+        // An absent filter would match every task; make callers say so explicitly
+        let filter = req.filter
+            .ok_or_else(|| Status::invalid_argument("A filter is required"))?;
+
+        let task_ids = self.storage
+            .query_tasks(Some(&filter), None)
+            .await
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        let changes = BulkUpdateTasksRequest {
+            task_ids,
+            status: req.status,
+            assigned_to: req.assigned_to,
+            tags_to_add: req.tags_to_add,
+            tags_to_remove: req.tags_to_remove,
+            dry_run: req.dry_run,
+        };
+        let response = self.bulk_update(changes, &actor).await?;
+        Ok(Response::new(response))
+    }
+
+    async fn list_tags(
+        &self,
+        request: Request<ListTagsRequest>,
+    ) -> Result<Response<ListTagsResponse>, Status> {
+        let req = request.into_inner();
+
+        let tags = self.storage
+            .list_tags(&req.prefix)
+            .await
+            .into_iter()
+            .map(|(tag, count)| TagCount { tag, count: count as u64 })
+            .collect();
+
+        Ok(Response::new(ListTagsResponse { tags }))
+    }
+
+    async fn rename_tag(
+        &self,
+        request: Request<RenameTagRequest>,
+    ) -> Result<Response<RenameTagResponse>, Status> {
+        let mut req = request.into_inner();
+
+        if req.from.is_empty() || req.to.is_empty() {
+            return Err(Status::invalid_argument("Both 'from' and 'to' tags are required"));
+        }
+        // `from` is matched as stored, so legacy unnormalized tags can be renamed away
+        req.to = normalize_tag(&self.config, &req.to)?;
+        if req.from == req.to {
+            return Err(Status::invalid_argument("'from' and 'to' tags must differ"));
+        }
+
+        let updated_count = self
+            .storage
+            .rename_tag(&req.from, &req.to)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to rename tag: {}", e)))?;
+
+        let response = RenameTagResponse {
+            updated_count: updated_count as u64,
+            success: true,
+            message: format!("Renamed tag '{}' to '{}' on {} tasks", req.from, req.to, updated_count),
+        };
+
         Ok(Response::new(response))
     }
 
@@ -217,47 +1489,62 @@ impl TaskService for TaskServiceImpl {
         &self,
         request: Request<StreamTaskEventsRequest>,
     ) -> Result<Response<Self::StreamTaskEventsStream>, Status> {
-        let _req = request.into_inner();
+        let caller = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default();
+        let peer_ip = request.remote_addr().map(|addr| addr.ip().to_string());
+        let req = request.into_inner();
+        let bus = self.storage.events().clone();
+        let (missed, mut events) = bus.subscribe_since(req.since.as_ref(), &req.after_event_id);
+        let filters = StreamFilters {
+            task_ids: req.task_ids.clone(),
+            event_types: req.event_types
+                .iter()
+                .filter_map(|&value| TaskEventType::try_from(value).ok())
+                .map(|event_type| event_type.as_str_name().to_string())
+                .collect(),
+            user_id: req.user_id.clone(),
+        };
+        let registration = self.register_stream(&bus, "task_events", &caller, peer_ip.as_deref(), filters)?;
         
-        let (tx, rx) = mpsc::channel(10);
+        let (tx, rx) = mpsc::channel(self.config.stream_buffer_size);
         
-        // Simulate real-time events
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
-            let mut counter = 0;
-            
+            let _registration = registration;
+            for event in missed {
+                if event_matches(&req, &event) && bus.send_to_stream(&tx, Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+
             loop {
-                interval.tick().await;
-                counter += 1;
-                
-                let event = TaskEvent {
-                    event_id: Uuid::new_v4().to_string(),
-                    event_type: TaskEventType::Updated as i32,
-                    task: Some(Task {
-                        id: format!("task_{}", counter),
-                        title: format!("Sample Task {}", counter),
-                        description: "Auto-generated task event".to_string(),
-                        status: TaskStatus::InProgress as i32,
-                        priority: TaskPriority::Medium as i32,
-                        tags: vec!["auto".to_string()],
-                        assigned_to: "system".to_string(),
-                        created_at: Some(TaskServiceImpl::system_time_to_timestamp(SystemTime::now())),
-                        updated_at: Some(TaskServiceImpl::system_time_to_timestamp(SystemTime::now())),
-                        due_date: None,
-                        metrics: Some(TaskMetrics {
-                            estimated_hours: 2,
-                            actual_hours: 1,
-                            completion_percentage: 50.0,
-                        }),
-                        comments: vec![],
-                        attachments: vec![],
-                    }),
-                    user_id: "system".to_string(),
-                    timestamp: Some(TaskServiceImpl::system_time_to_timestamp(SystemTime::now())),
-                    metadata: std::collections::HashMap::new(),
+                // Watch for the client going away too, so an idle stream is
+                // deregistered without waiting for an event to fail to send
+                let received = tokio::select! {
+                    received = events.recv() => received,
+                    _ = tx.closed() => break,
+                };
+                let event = match received {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Event stream fell behind and skipped {} events", skipped);
+                        bus.record_lagged(skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
                 };
-                
-                if tx.send(Ok(event)).await.is_err() {
+
+                if event.event_type == TaskEventType::ServerShutdown as i32 {
+                    let _ = tx.send(Ok(event)).await;
+                    break;
+                }
+
+                if !event_matches(&req, &event) {
+                    continue;
+                }
+
+                if bus.send_to_stream(&tx, Ok(event)).await.is_err() {
                     break;
                 }
             }
@@ -267,6 +1554,42 @@ impl TaskService for TaskServiceImpl {
         Ok(Response::new(Box::pin(stream) as Self::StreamTaskEventsStream))
     }
 
+    type StreamTasksStream = Pin<Box<dyn Stream<Item = Result<Task, Status>> + Send>>;
+
+    /// Stream every matching task as it stood when the call started: the
+    /// tasks are copied under one read lock, so writes made while the stream
+    /// drains don't show up in it. Counts against the caller's stream limit.
+    async fn stream_tasks(
+        &self,
+        request: Request<StreamTasksRequest>,
+    ) -> Result<Response<Self::StreamTasksStream>, Status> {
+        let caller = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default();
+        let peer_ip = request.remote_addr().map(|addr| addr.ip().to_string());
+        let req = request.into_inner();
+        let bus = self.storage.events().clone();
+        let registration = self.register_stream(&bus, "tasks", &caller, peer_ip.as_deref(), StreamFilters::default())?;
+        let tasks = self.storage
+            .query_tasks(req.filter.as_ref(), Some(self.sort_or_default(req.sort.as_ref())))
+            .await;
+
+        let (tx, rx) = mpsc::channel(self.config.stream_buffer_size);
+
+        tokio::spawn(async move {
+            let _registration = registration;
+            for task in tasks {
+                if bus.send_to_stream(&tx, Ok(task)).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        let stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream) as Self::StreamTasksStream))
+    }
+
     type ImportTasksStream = Pin<Box<dyn Stream<Item = Result<CreateTaskResponse, Status>> + Send>>;
 
     async fn import_tasks(
@@ -275,19 +1598,65 @@ impl TaskService for TaskServiceImpl {
     ) -> Result<Response<Self::ImportTasksStream>, Status> {
         let mut stream = request.into_inner();
         let storage = self.storage.clone();
+        let config = self.config.clone();
+        let bus = self.storage.events().clone();
         
-        let (tx, rx) = mpsc::channel(10);
+        let (tx, rx) = mpsc::channel(self.config.stream_buffer_size);
         
         tokio::spawn(async move {
             while let Some(request) = stream.next().await {
                 match request {
-                    Ok(req) => {
+                    Ok(mut req) => {
+                        let valid = validate_text_lengths(&config, Some(&req.title), Some(&req.description));
+                        let valid = match valid {
+                            Ok(()) => check_due_date_policy(&config, priority_or_default(&config, req.priority), req.due_date.as_ref()),
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => check_due_date_not_past(&config, req.due_date.as_ref(), req.allow_past_due, SystemTime::now()),
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => validate_priority_score(req.priority_score),
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => normalize_tags(&config, &mut req.tags),
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => validate_custom_fields(&config, &req.custom_fields),
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => check_project(&storage, &req.project_id).await,
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => check_parent(&storage, &req.parent_id).await,
+                            invalid => invalid,
+                        };
+                        let valid = match valid {
+                            Ok(()) => check_quota(&storage, &config, &req.assigned_to, 0).await,
+                            invalid => invalid,
+                        };
+                        if let Err(status) = valid {
+                            let response = CreateTaskResponse {
+                                task: None,
+                                success: false,
+                                message: format!("Import failed: {}", status.message()),
+                            };
+                            if bus.send_to_stream(&tx, Ok(response)).await.is_err() {
+                                break;
+                            }
+                            continue;
+                        }
                         let task = Task {
-                            id: Uuid::new_v4().to_string(),
+                            id: config.id_format.generate(),
                             title: req.title,
                             description: req.description,
-                            status: TaskStatus::Todo as i32,
-                            priority: req.priority,
+                            status: config.initial_status as i32,
+                            priority: priority_or_default(&config, req.priority),
                             tags: req.tags,
                             assigned_to: req.assigned_to,
                             created_at: Some(TaskServiceImpl::system_time_to_timestamp(SystemTime::now())),
@@ -300,17 +1669,30 @@ impl TaskService for TaskServiceImpl {
                             }),
                             comments: vec![],
                             attachments: vec![],
+                            priority_score: req.priority_score,
+                            watchers: vec![],
+                            project_id: req.project_id,
+                            snooze_count: 0,
+                            reminders: vec![],
+                            number: 0,
+                            parent_id: req.parent_id,
+                            custom_fields: req.custom_fields,
                         };
 
-                        storage.create_task(task.clone()).await;
-
-                        let response = CreateTaskResponse {
-                            task: Some(task),
-                            success: true,
-                            message: "Task imported successfully".to_string(),
+                        let response = match storage.create_task(task).await {
+                            Ok(task) => CreateTaskResponse {
+                                task: Some(task),
+                                success: true,
+                                message: "Task imported successfully".to_string(),
+                            },
+                            Err(e) => CreateTaskResponse {
+                                task: None,
+                                success: false,
+                                message: format!("Import failed: {}", e),
+                            },
                         };
 
-                        if tx.send(Ok(response)).await.is_err() {
+                        if bus.send_to_stream(&tx, Ok(response)).await.is_err() {
                             break;
                         }
                     }
@@ -320,7 +1702,7 @@ impl TaskService for TaskServiceImpl {
                             success: false,
                             message: format!("Import failed: {}", e),
                         };
-                        if tx.send(Ok(response)).await.is_err() {
+                        if bus.send_to_stream(&tx, Ok(response)).await.is_err() {
                             break;
                         }
                     }
@@ -338,16 +1720,35 @@ impl TaskService for TaskServiceImpl {
         &self,
         request: Request<Streaming<TaskEvent>>,
     ) -> Result<Response<Self::CollaborateOnTasksStream>, Status> {
+        let caller = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .map(|user| user.id)
+            .unwrap_or_default();
+        let peer_ip = request.remote_addr().map(|addr| addr.ip().to_string());
         let mut stream = request.into_inner();
+        let bus = self.storage.events().clone();
+        let mut events = bus.subscribe();
+        let registration = self.register_stream(&bus, "collaborate", &caller, peer_ip.as_deref(), StreamFilters::default())?;
         
-        let (tx, rx) = mpsc::channel(10);
+        let (tx, rx) = mpsc::channel(self.config.stream_buffer_size);
         
         tokio::spawn(async move {
-            while let Some(event) = stream.next().await {
+            let _registration = registration;
+            loop {
+                let event = tokio::select! {
+                    event = stream.next() => match event {
+                        Some(event) => event,
+                        None => break,
+                    },
+                    shutdown = Self::next_shutdown(&mut events) => {
+                        let _ = tx.send(Ok(shutdown)).await;
+                        break;
+                    }
+                };
                 match event {
                     Ok(event) => {
                         // Echo the event back (in real implementation, broadcast to other clients)
-                        if tx.send(Ok(event)).await.is_err() {
+                        if bus.send_to_stream(&tx, Ok(event)).await.is_err() {
                             break;
                         }
                     }
@@ -367,6 +1768,13 @@ impl TaskService for TaskServiceImpl {
         &self,
         request: Request<Streaming<UploadTaskAttachmentRequest>>,
     ) -> Result<Response<UploadTaskAttachmentResponse>, Status> {
+        // Claimed before reading the stream, since that's where the memory goes
+        let _slot = self.storage.upload_slot().await.ok_or_else(|| {
+            Status::resource_exhausted(format!(
+                "Too many uploads in progress; at most {} at once",
+                self.config.max_concurrent_uploads
+            ))
+        })?;
         let mut stream = request.into_inner();
         let mut file_data = Vec::new();
         let mut filename = String::new();
@@ -393,27 +1801,33 @@ impl TaskService for TaskServiceImpl {
             }
         }
         
-        // Store attachment (in real implementation, save to file system or cloud storage)
+        let mut task = self.storage
+            .get_task(&task_id)
+            .await
+            .ok_or_else(|| Status::not_found("Task not found"))?;
+
+        let attachments = AttachmentStore::from_config(&self.config);
         let attachment_id = Uuid::new_v4().to_string();
         let file_size = file_data.len() as u64;
-        
-        // Update task with attachment info
-        if let Some(mut task) = self.storage.get_task(&task_id).await {
-            let _attachment = TaskAttachment {
-                id: attachment_id.clone(),
-                filename: filename.clone(),
-                content_type,
-                file_size,
-                uploaded_at: Some(Self::system_time_to_timestamp(SystemTime::now())),
-                uploaded_by: "user".to_string(), // In real implementation, get from auth
-                url: format!("/attachments/{}", attachment_id),
-            };
-            
-            // In real implementation, you'd have an attachments field in Task
-            // For now, we'll just update the task
-            task.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
-            self.storage.update_task(task).await;
-        }
+        attachments
+            .save(&attachment_id, &file_data)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to store attachment: {}", e)))?;
+
+        task.attachments.push(TaskAttachment {
+            id: attachment_id.clone(),
+            filename: filename.clone(),
+            content_type,
+            file_size,
+            uploaded_at: Some(Self::system_time_to_timestamp(SystemTime::now())),
+            uploaded_by: "user".to_string(), // In real implementation, get from auth
+            url: attachments.url(&attachment_id),
+        });
+        task.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
+        self.storage
+            .update_task(task)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to update task: {}", e)))?;
         
         let response = UploadTaskAttachmentResponse {
             attachment_id,
@@ -431,10 +1845,19 @@ impl TaskService for TaskServiceImpl {
         request: Request<SearchTasksRequest>,
     ) -> Result<Response<SearchTasksResponse>, Status> {
         let req = request.into_inner();
-        
-        // Fix: Use the correct method signature with 3 parameters
-        let tasks = self.storage.search_tasks(&req.query, 50, "").await;
-        let total_count = tasks.len() as u32;
+        let page_size = if req.page_size > 0 { req.page_size } else { 50 };
+
+        // Exact search stays the default; fuzzy only kicks in once the query is long enough
+        let tasks = if req.fuzzy && req.query.chars().count() >= MIN_FUZZY_QUERY_LEN {
+            let max_distance = match req.max_distance {
+                0 => self.config.fuzzy_max_distance,
+                n => n as usize,
+            };
+            self.storage.fuzzy_search_tasks(&req.query, max_distance, page_size, &req.page_token).await
+        } else {
+            self.storage.search_tasks(&req.query, page_size, &req.page_token).await
+        };
+        let total_count = tasks.len() as u64;
 
         let response = SearchTasksResponse {
             tasks,
@@ -449,33 +1872,18 @@ impl TaskService for TaskServiceImpl {
         &self,
         request: Request<GetTaskAnalyticsRequest>,
     ) -> Result<Response<GetTaskAnalyticsResponse>, Status> {
-        let _req = request.into_inner();
-        
-        // Simulate analytics calculation
-        let total_tasks = self.storage.count_tasks().await;
-        let completed_tasks = self.storage.count_tasks_by_status(TaskStatus::Done).await;
-        let in_progress_tasks = self.storage.count_tasks_by_status(TaskStatus::InProgress).await;
-        let todo_tasks = self.storage.count_tasks_by_status(TaskStatus::Todo).await;
-        
-        let analytics = TaskAnalytics {
-            total_tasks: total_tasks.try_into().unwrap_or(0),
-            completed_tasks: completed_tasks.try_into().unwrap_or(0),
-            in_progress_tasks: in_progress_tasks.try_into().unwrap_or(0),
-            todo_tasks: todo_tasks.try_into().unwrap_or(0),
-            completion_rate: if total_tasks > 0 {
-                (completed_tasks as f32 / total_tasks as f32) * 100.0
-            } else {
-                0.0
-            },
-            average_completion_time_hours: 24.5, // Simulated
-            overdue_tasks: self.storage.count_overdue_tasks().await.try_into().unwrap_or(0),
-            tasks_by_priority: std::collections::HashMap::from([
-                (TaskPriority::High as i32, 15),
-                (TaskPriority::Medium as i32, 25),
-                (TaskPriority::Low as i32, 10),
-            ]),
-            tasks_created_this_week: 12,
-            tasks_completed_this_week: 8,
+        let req = request.into_inner();
+        let include_archived = req.include_archived.unwrap_or(self.config.analytics_include_archived);
+
+        let max_age = Duration::from_secs(self.config.analytics_cache_ttl_secs);
+        let analytics = match self.storage.cached_analytics(include_archived, max_age).await {
+            Some(analytics) => analytics,
+            None => {
+                let generation = self.storage.generation();
+                let analytics = self.compute_analytics(include_archived).await;
+                self.storage.cache_analytics(generation, include_archived, analytics.clone()).await;
+                analytics
+            }
         };
         
         let response = GetTaskAnalyticsResponse {
@@ -486,6 +1894,61 @@ impl TaskService for TaskServiceImpl {
         Ok(Response::new(response))
     }
 
+    async fn get_workload(
+        &self,
+        request: Request<GetWorkloadRequest>,
+    ) -> Result<Response<GetWorkloadResponse>, Status> {
+        let req = request.into_inner();
+
+        let response = GetWorkloadResponse {
+            workloads: self.storage.workload(&req.user_ids).await,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn get_task_timeseries(
+        &self,
+        request: Request<GetTaskTimeseriesRequest>,
+    ) -> Result<Response<GetTaskTimeseriesResponse>, Status> {
+        let req = request.into_inner();
+
+        let metric = TimeseriesMetric::try_from(req.metric)
+            .ok()
+            .filter(|metric| *metric != TimeseriesMetric::Unspecified)
+            .ok_or_else(|| Status::invalid_argument("metric must be CREATED or COMPLETED"))?;
+        let bucket_days = match TimeseriesBucket::try_from(req.bucket) {
+            Ok(TimeseriesBucket::Unspecified | TimeseriesBucket::Day) => 1,
+            Ok(TimeseriesBucket::Week) => 7,
+            Err(_) => return Err(Status::invalid_argument("Unknown bucket")),
+        };
+        let to = match req.to.as_str() {
+            "" => chrono::Utc::now().date_naive(),
+            to => parse_date("to", to)?,
+        };
+        let from = match req.from.as_str() {
+            "" => to - chrono::Duration::days(DEFAULT_TIMESERIES_DAYS - 1),
+            from => parse_date("from", from)?,
+        };
+        if from > to {
+            return Err(Status::invalid_argument("from must not be after to"));
+        }
+        if (to - from).num_days() / bucket_days + 1 > MAX_TIMESERIES_BUCKETS {
+            return Err(Status::invalid_argument(format!(
+                "A time series may span at most {} buckets",
+                MAX_TIMESERIES_BUCKETS
+            )));
+        }
+
+        let points = self.storage.task_timeseries(metric, from, to, bucket_days).await;
+        let response = GetTaskTimeseriesResponse {
+            total: points.iter().map(|point| point.count).sum(),
+            points,
+        };
+
+        Ok(Response::new(response))
+    }
+
     async fn health(
         &self,
         _request: Request<()>,
@@ -498,4 +1961,830 @@ impl TaskService for TaskServiceImpl {
         
         Ok(Response::new(response))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Session;
+
+    async fn seed_task(storage: &Storage, id: &str, status: TaskStatus) {
+        let task = Task { id: id.to_string(), title: id.to_string(), status: status as i32, ..Default::default() };
+        storage.create_task(task).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_tasks_is_a_snapshot_and_counts_as_a_stream() {
+        let storage = Arc::new(Storage::new());
+        for id in ["t1", "t2", "t3"] {
+            seed_task(&storage, id, TaskStatus::Todo).await;
+        }
+        let config = Config { stream_buffer_size: 1, max_streams_per_client: 1, ..Default::default() };
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(config));
+
+        let mut stream = service.stream_tasks(Request::new(StreamTasksRequest::default())).await.unwrap().into_inner();
+        let second = service.stream_tasks(Request::new(StreamTasksRequest::default())).await;
+        assert_eq!(second.err().map(|status| status.code()), Some(tonic::Code::ResourceExhausted));
+
+        storage.delete_task("t3").await.unwrap();
+        seed_task(&storage, "t4", TaskStatus::Todo).await;
+        let mut streamed = Vec::new();
+        while let Some(task) = stream.next().await {
+            streamed.push(task.unwrap().id);
+        }
+        streamed.sort();
+        assert_eq!(streamed, ["t1", "t2", "t3"]);
+    }
+
+    /// `message` sent with the bearer token of a fresh session for `user_id`
+    async fn as_user<T>(storage: &Storage, user_id: &str, message: T) -> Request<T> {
+        let user = User { id: user_id.to_string(), username: user_id.to_string(), is_active: true, ..Default::default() };
+        if storage.get_user(user_id).await.is_none() {
+            storage.create_user(user).await.unwrap();
+        }
+        let token = format!("{}-token", user_id);
+        let session = Session { user_id: user_id.to_string(), expires_at: i64::MAX / 2, refresh: false };
+        storage.create_session(&token, session).await.unwrap();
+        let mut request = Request::new(message);
+        request.metadata_mut().insert("authorization", format!("Bearer {}", token).parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn editing_a_comment_keeps_its_id_and_deleting_removes_it() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t1", TaskStatus::Todo).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let add = AddCommentRequest { task_id: "t1".to_string(), content: "first draft".to_string() };
+        let added = service.add_comment(as_user(&storage, "ann", add).await).await.unwrap().into_inner().comment.unwrap();
+        assert!(added.edited_at.is_none());
+
+        let edit = |user_id: &'static str| EditCommentRequest {
+            task_id: "t1".to_string(),
+            comment_id: added.id.clone(),
+            content: format!("edited by {}", user_id),
+        };
+        let refused = service.edit_comment(as_user(&storage, "bob", edit("bob")).await).await;
+        assert_eq!(refused.err().map(|status| status.code()), Some(tonic::Code::PermissionDenied));
+        let edited = service.edit_comment(as_user(&storage, "ann", edit("ann")).await).await.unwrap().into_inner().comment.unwrap();
+        assert_eq!(edited.id, added.id);
+        assert_eq!(edited.content, "edited by ann");
+        assert!(edited.edited_at.is_some());
+        let stored = storage.get_task("t1").await.unwrap().comments;
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].content, "edited by ann");
+
+        let delete = DeleteCommentRequest { task_id: "t1".to_string(), comment_id: added.id.clone() };
+        service.delete_comment(as_user(&storage, "ann", delete).await).await.unwrap();
+        assert!(storage.get_task("t1").await.unwrap().comments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn bulk_update_refuses_status_moves_the_workflow_forbids() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "backlog", TaskStatus::Backlog).await;
+        seed_task(&storage, "todo", TaskStatus::Todo).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let req = BulkUpdateTasksRequest {
+            task_ids: vec!["backlog".to_string(), "todo".to_string()],
+            status: TaskStatus::Done as i32,
+            ..Default::default()
+        };
+        let response = service.bulk_update(req, "").await.unwrap();
+
+        assert_eq!(response.updated_count, 1);
+        assert_eq!(response.failed_ids, ["backlog"]);
+        assert_eq!(response.results[0].error, BulkUpdateError::InvalidTransition as i32);
+        assert_eq!(storage.get_task("backlog").await.unwrap().status, TaskStatus::Backlog as i32);
+        assert_eq!(storage.get_task("todo").await.unwrap().status, TaskStatus::Done as i32);
+    }
+
+    #[tokio::test]
+    async fn archived_task_leaves_listings_but_is_retrievable() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "kept", TaskStatus::Todo).await;
+        seed_task(&storage, "shelved", TaskStatus::Done).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let listed = |response: ListTasksResponse| {
+            response.tasks.into_iter().map(|task| task.id).collect::<Vec<_>>()
+        };
+        let list = || ListTasksRequest { page_size: 50, ..Default::default() };
+        let list_archived = || ListArchivedTasksRequest { page_size: 50, ..Default::default() };
+
+        let request = Request::new(ArchiveTaskRequest { id: "shelved".to_string() });
+        service.archive_task(request).await.unwrap();
+
+        let active = service.list_tasks(Request::new(list())).await.unwrap().into_inner();
+        assert_eq!(listed(active), ["kept"]);
+        let found = service.get_task(Request::new(GetTaskRequest { id: "shelved".to_string(), ..Default::default() }))
+            .await.unwrap().into_inner();
+        assert!(!found.found);
+        let archived = service.list_archived_tasks(Request::new(list_archived())).await.unwrap().into_inner();
+        assert_eq!(listed(archived), ["shelved"]);
+
+        let request = Request::new(RestoreArchivedTaskRequest { id: "shelved".to_string() });
+        let restored = service.restore_archived_task(request).await.unwrap().into_inner();
+        assert_eq!(restored.task.unwrap().number, 2);
+        let mut active = listed(service.list_tasks(Request::new(list())).await.unwrap().into_inner());
+        active.sort();
+        assert_eq!(active, ["kept", "shelved"]);
+        let archived = service.list_archived_tasks(Request::new(list_archived())).await.unwrap().into_inner();
+        assert!(archived.tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn saved_view_lists_what_the_same_filter_does_and_only_for_its_owner() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "todo-b", TaskStatus::Todo).await;
+        seed_task(&storage, "todo-a", TaskStatus::Todo).await;
+        seed_task(&storage, "done", TaskStatus::Done).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let filter = TaskFilter { status: vec![TaskStatus::Todo as i32], ..Default::default() };
+        let sort = TaskSort { field: TaskSortField::Title as i32, direction: SortDirection::Asc as i32 };
+
+        let create = CreateSavedViewRequest { user_id: "ann".to_string(), name: "Todo".to_string(), filter: Some(filter.clone()), sort: Some(sort.clone()) };
+        let view = service.create_saved_view(as_user(&storage, "ann", create).await).await.unwrap().into_inner().view.unwrap();
+
+        let apply = |user_id: &str| ApplySavedViewRequest { user_id: user_id.to_string(), view_id: view.id.clone(), page_size: 10, page_token: String::new() };
+        let saved = service.apply_saved_view(as_user(&storage, "ann", apply("ann")).await).await.unwrap().into_inner();
+        let ad_hoc = ListTasksRequest { page_size: 10, page_token: String::new(), filter: Some(filter), sort: Some(sort) };
+        let ad_hoc = service.list_tasks(Request::new(ad_hoc)).await.unwrap().into_inner();
+        let ids = |tasks: &[Task]| tasks.iter().map(|task| task.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&saved.tasks), ["todo-a", "todo-b"]);
+        assert_eq!(ids(&saved.tasks), ids(&ad_hoc.tasks));
+
+        let list = ListSavedViewsRequest { user_id: "ann".to_string() };
+        let anonymous = service.list_saved_views(Request::new(list.clone())).await;
+        assert_eq!(anonymous.err().map(|status| status.code()), Some(tonic::Code::Unauthenticated));
+        let other = service.list_saved_views(as_user(&storage, "bob", list).await).await;
+        assert_eq!(other.err().map(|status| status.code()), Some(tonic::Code::PermissionDenied));
+        let other = service.apply_saved_view(as_user(&storage, "bob", apply("ann")).await).await;
+        assert_eq!(other.err().map(|status| status.code()), Some(tonic::Code::PermissionDenied));
+    }
+
+    #[tokio::test]
+    async fn update_with_a_status_mask_follows_the_workflow() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t1", TaskStatus::Backlog).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let update = |status: TaskStatus| UpdateTaskRequest {
+            id: "t1".to_string(),
+            task: Some(Task { status: status as i32, ..Default::default() }),
+            update_mask: vec!["status".to_string()],
+        };
+
+        let refused = service.update_task(Request::new(update(TaskStatus::Done))).await;
+        assert_eq!(refused.err().map(|status| status.code()), Some(tonic::Code::FailedPrecondition));
+        assert_eq!(storage.get_task("t1").await.unwrap().status, TaskStatus::Backlog as i32);
+
+        service.update_task(Request::new(update(TaskStatus::Todo))).await.unwrap();
+        assert_eq!(storage.get_task("t1").await.unwrap().status, TaskStatus::Todo as i32);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_comments_and_snoozes_are_all_kept() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t1", TaskStatus::Todo).await;
+        let service = Arc::new(TaskServiceImpl::new(storage.clone(), Arc::new(Config::default())));
+        let start = Arc::new(tokio::sync::Barrier::new(100));
+
+        let mut handles = Vec::new();
+        for i in 0..50 {
+            let add = AddCommentRequest { task_id: "t1".to_string(), content: format!("comment {}", i) };
+            let request = as_user(&storage, "ann", add).await;
+            let (commenter, barrier) = (service.clone(), start.clone());
+            handles.push(tokio::spawn(async move {
+                barrier.wait().await;
+                commenter.add_comment(request).await.map(|_| ())
+            }));
+            let snooze = SnoozeTaskRequest { id: "t1".to_string(), until: None, duration_secs: 60 };
+            let (snoozer, barrier) = (service.clone(), start.clone());
+            handles.push(tokio::spawn(async move {
+                barrier.wait().await;
+                snoozer.snooze_task(Request::new(snooze)).await.map(|_| ())
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let task = storage.get_task("t1").await.unwrap();
+        assert_eq!(task.comments.len(), 50);
+        assert_eq!(task.snooze_count, 50);
+    }
+
+    #[tokio::test]
+    async fn cached_analytics_are_dropped_by_the_next_write() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t1", TaskStatus::Todo).await;
+        let config = Config { analytics_cache_ttl_secs: 3600, ..Default::default() };
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(config));
+        let total = || async {
+            let request = Request::new(GetTaskAnalyticsRequest::default());
+            service.get_task_analytics(request).await.unwrap().into_inner().analytics.unwrap().total_tasks
+        };
+
+        assert_eq!(total().await, 1);
+        assert!(storage.cached_analytics(false, Duration::from_secs(3600)).await.is_some());
+        assert!(storage.cached_analytics(true, Duration::from_secs(3600)).await.is_none());
+
+        seed_task(&storage, "t2", TaskStatus::Todo).await;
+        assert!(storage.cached_analytics(false, Duration::from_secs(3600)).await.is_none());
+        assert_eq!(total().await, 2);
+    }
+
+    #[tokio::test]
+    async fn a_clone_starts_in_todo_and_is_independent_of_its_source() {
+        let storage = Arc::new(Storage::new());
+        let source = Task {
+            id: "src".to_string(),
+            title: "Ship it".to_string(),
+            status: TaskStatus::Done as i32,
+            tags: vec!["release".to_string()],
+            comments: vec![TaskComment { id: "c1".to_string(), content: "done!".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        storage.create_task(source).await.unwrap();
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let overrides = TaskOverrides { title: Some("Ship it again".to_string()), ..Default::default() };
+        let request = CloneTaskRequest { task_id: "src".to_string(), overrides: Some(overrides) };
+        let clone = service.clone_task(Request::new(request)).await.unwrap().into_inner().task.unwrap();
+        assert_ne!(clone.id, "src");
+        assert_eq!(clone.status, TaskStatus::Todo as i32);
+        assert_eq!(clone.title, "Ship it again");
+        assert_eq!(clone.tags, ["release"]);
+        assert!(clone.comments.is_empty());
+
+        storage.modify_task(&clone.id, |task| {
+            task.tags.push("hotfix".to_string());
+            Ok::<_, ()>(())
+        }).await.unwrap().unwrap();
+        let source = storage.get_task("src").await.unwrap();
+        assert_eq!(source.tags, ["release"]);
+        assert_eq!(source.title, "Ship it");
+        assert_eq!(source.status, TaskStatus::Done as i32);
+        assert_eq!(source.comments.len(), 1);
+    }
+
+    async fn list_done(service: &TaskServiceImpl, page_size: i32, page_token: &str) -> ListTasksResponse {
+        let request = ListTasksRequest {
+            page_size,
+            page_token: page_token.to_string(),
+            filter: Some(TaskFilter { status: vec![TaskStatus::Done as i32], ..Default::default() }),
+            sort: None,
+        };
+        service.list_tasks(Request::new(request)).await.unwrap().into_inner()
+    }
+
+    #[tokio::test]
+    async fn list_counts_filtered_and_unfiltered_tasks_separately() {
+        let storage = Arc::new(Storage::new());
+        for (id, status) in [("t1", TaskStatus::Done), ("t2", TaskStatus::Todo), ("t3", TaskStatus::Done), ("t4", TaskStatus::Todo)] {
+            seed_task(&storage, id, status).await;
+        }
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let page = list_done(&service, 1, "").await;
+        assert_eq!(page.tasks.len(), 1);
+        assert_eq!(page.total_count, 2);
+        assert_eq!(page.grand_total, 4);
+    }
+
+    #[tokio::test]
+    async fn shutdown_reaches_filtered_streams_and_closes_them() {
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t2", TaskStatus::Todo).await;
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let request = StreamTaskEventsRequest {
+            task_ids: vec!["t1".to_string()],
+            event_types: vec![TaskEventType::Created as i32],
+            ..Default::default()
+        };
+        let mut stream = service.stream_task_events(Request::new(request)).await.unwrap().into_inner();
+
+        let other = storage.get_task("t2").await.unwrap();
+        storage.events().publish(TaskEventType::Updated, &other, "", HashMap::new());
+        storage.events().publish_shutdown();
+
+        let last = tokio::time::timeout(Duration::from_secs(5), stream.next()).await.unwrap().unwrap().unwrap();
+        assert_eq!(last.event_type, TaskEventType::ServerShutdown as i32);
+        assert!(tokio::time::timeout(Duration::from_secs(5), stream.next()).await.unwrap().is_none());
+    }
+
+    async fn create(service: &TaskServiceImpl, request: CreateTaskRequest) -> Result<Task, Status> {
+        Ok(service.create_task(Request::new(request)).await?.into_inner().task.unwrap())
+    }
+
+    #[tokio::test]
+    async fn new_tasks_take_the_configured_priority_and_status() {
+        let storage = Arc::new(Storage::new());
+        let config = Config { default_priority: TaskPriority::High, initial_status: TaskStatus::Backlog, ..Default::default() };
+        let service = TaskServiceImpl::new(storage, Arc::new(config));
+
+        let plain = create(&service, CreateTaskRequest { title: "Plain".to_string(), ..Default::default() }).await.unwrap();
+        assert_eq!(plain.priority, TaskPriority::High as i32);
+        assert_eq!(plain.status, TaskStatus::Backlog as i32);
+
+        let low = CreateTaskRequest { title: "Low".to_string(), priority: TaskPriority::Low as i32, ..Default::default() };
+        assert_eq!(create(&service, low).await.unwrap().priority, TaskPriority::Low as i32);
+    }
+
+    #[tokio::test]
+    async fn comments_page_newest_first_unless_asked_otherwise() {
+        let storage = Arc::new(Storage::new());
+        let comments = (1..=5)
+            .map(|n| TaskComment { id: format!("c{}", n), ..Default::default() })
+            .collect();
+        storage.create_task(Task { id: "t1".to_string(), comments, ..Default::default() }).await.unwrap();
+        let service = TaskServiceImpl::new(storage, Arc::new(Config::default()));
+        let page = |page_token: String, oldest_first: bool| {
+            let service = &service;
+            async move {
+                let request = ListTaskCommentsRequest { task_id: "t1".to_string(), page_size: 2, page_token, oldest_first };
+                service.list_task_comments(Request::new(request)).await.unwrap().into_inner()
+            }
+        };
+        let ids = |response: &ListTaskCommentsResponse| response.comments.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+
+        let mut token = String::new();
+        let mut pages = Vec::new();
+        loop {
+            let response = page(token, false).await;
+            assert_eq!(response.total_count, 5);
+            assert_eq!(response.total_pages, 3);
+            pages.push(ids(&response));
+            if response.next_page_token.is_empty() {
+                break;
+            }
+            token = response.next_page_token;
+        }
+        assert_eq!(pages, [vec!["c5", "c4"], vec!["c3", "c2"], vec!["c1"]]);
+        assert_eq!(ids(&page(String::new(), true).await), ["c1", "c2"]);
+    }
+
+
+    #[tokio::test]
+    async fn tags_past_the_count_or_length_limit_are_rejected() {
+        let storage = Arc::new(Storage::new());
+        let config = Config { max_tags_per_task: 2, max_tag_length: 5, ..Default::default() };
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(config));
+        let tagged = |tags: &[&str]| CreateTaskRequest {
+            title: "Tagged".to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+
+        for tags in [&["a", "b", "c"][..], &["toolong"], &["ok", "  "]] {
+            let status = create(&service, tagged(tags)).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{:?}", tags);
+        }
+        let task = create(&service, tagged(&[" a ", "b"])).await.unwrap();
+        assert_eq!(task.tags, ["a", "b"]);
+
+        // Bulk-adding a third tag would push the task over the limit
+        let request = BulkTagTasksRequest { task_ids: vec![task.id.clone()], add: vec!["c".to_string()], ..Default::default() };
+        let response = service.bulk_tag_tasks(Request::new(request)).await.unwrap().into_inner();
+        assert_eq!(response.failed_ids, vec![task.id.clone()]);
+        assert_eq!(storage.get_task(&task.id).await.unwrap().tags, ["a", "b"]);
+    }
+
+
+    #[tokio::test]
+    async fn tags_differing_in_case_or_spacing_are_one_tag() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let request = CreateTaskRequest {
+            title: "Tagged".to_string(),
+            tags: vec!["Backend".to_string(), "backend".to_string(), " backend ".to_string()],
+            ..Default::default()
+        };
+        let task = create(&service, request).await.unwrap();
+        assert_eq!(task.tags, ["backend"]);
+
+        let patch = Task { tags: vec!["API".to_string(), "api ".to_string()], ..Default::default() };
+        let request = UpdateTaskRequest { id: task.id.clone(), task: Some(patch), update_mask: vec!["tags".to_string()] };
+        service.update_task(Request::new(request)).await.unwrap();
+        assert_eq!(storage.get_task(&task.id).await.unwrap().tags, ["api"]);
+
+        let request = BulkTagTasksRequest { task_ids: vec![task.id.clone()], add: vec!["Api".to_string()], ..Default::default() };
+        service.bulk_tag_tasks(Request::new(request)).await.unwrap();
+        assert_eq!(storage.get_task(&task.id).await.unwrap().tags, ["api"]);
+    }
+
+
+    #[tokio::test]
+    async fn list_reports_the_current_page_and_page_count() {
+        let storage = Arc::new(Storage::new());
+        for n in 0..25 {
+            seed_task(&storage, &format!("t{:02}", n), TaskStatus::Done).await;
+        }
+        let service = TaskServiceImpl::new(storage, Arc::new(Config::default()));
+
+        let first = list_done(&service, 10, "").await;
+        assert_eq!((first.current_page, first.total_pages, first.total_count), (1, 3, 25));
+        let last = list_done(&service, 10, "page_2").await;
+        assert_eq!((last.current_page, last.total_pages, last.tasks.len()), (3, 3, 5));
+        assert!(last.next_page_token.is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn results_past_the_soft_limit_carry_a_warning() {
+        let storage = Arc::new(Storage::new());
+        for n in 0..4 {
+            seed_task(&storage, &format!("t{}", n), TaskStatus::Done).await;
+        }
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { result_soft_limit: 3, ..Default::default() }));
+
+        let response = list_done(&service, 2, "").await;
+        assert!(response.warning.contains("soft limit of 3"), "{}", response.warning);
+        assert_eq!(response.tasks.len(), 2);
+
+        storage.delete_task("t0").await.unwrap();
+        assert!(list_done(&service, 2, "").await.warning.is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn a_burst_larger_than_the_buffer_arrives_in_full() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { stream_buffer_size: 4, ..Default::default() }));
+        let mut stream = service
+            .stream_task_events(Request::new(StreamTaskEventsRequest::default()))
+            .await
+            .unwrap()
+            .into_inner();
+
+        // More than the default buffer, published before the client reads anything
+        for n in 0..200 {
+            let task = Task { id: format!("t{}", n), ..Default::default() };
+            storage.events().publish(TaskEventType::Created, &task, "", HashMap::new());
+        }
+        for n in 0..200 {
+            let event = stream.next().await.unwrap().unwrap();
+            assert_eq!(event.task.unwrap().id, format!("t{}", n));
+        }
+        let stats = storage.events().stream_stats();
+        assert_eq!(stats.lagged_events, 0);
+        assert!(stats.buffer_full_waits > 0);
+    }
+
+
+    #[tokio::test]
+    async fn a_stream_of_deletions_skips_other_events() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let request = StreamTaskEventsRequest { event_types: vec![TaskEventType::Deleted as i32], ..Default::default() };
+        let mut stream = service.stream_task_events(Request::new(request)).await.unwrap().into_inner();
+
+        let events = storage.events();
+        let task = |id: &str| Task { id: id.to_string(), ..Default::default() };
+        events.publish(TaskEventType::Created, &task("created"), "", HashMap::new());
+        events.publish(TaskEventType::Updated, &task("updated"), "", HashMap::new());
+        events.publish(TaskEventType::Deleted, &task("deleted"), "", HashMap::new());
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.event_type, TaskEventType::Deleted as i32);
+        assert_eq!(event.task.unwrap().id, "deleted");
+    }
+
+
+    #[tokio::test]
+    async fn streams_are_listed_while_open_and_dropped_on_disconnect() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let request = StreamTaskEventsRequest { task_ids: vec!["t1".to_string()], ..Default::default() };
+        let stream = service.stream_task_events(Request::new(request)).await.unwrap().into_inner();
+
+        let active = storage.events().active_streams();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].kind, "task_events");
+        assert_eq!(active[0].filters.task_ids, ["t1"]);
+
+        drop(stream);
+        for _ in 0..100 {
+            if storage.events().active_streams().is_empty() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("stream was still registered after the client went away");
+    }
+
+
+    #[tokio::test]
+    async fn a_client_past_its_stream_limit_is_refused_until_one_closes() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { max_streams_per_client: 2, ..Default::default() }));
+        let open = |user_id: &'static str| {
+            let (service, storage) = (&service, &storage);
+            async move {
+                let request = as_user(storage, user_id, StreamTaskEventsRequest::default()).await;
+                service.stream_task_events(request).await.map(Response::into_inner)
+            }
+        };
+
+        let first = open("ann").await.unwrap();
+        let _second = open("ann").await.unwrap();
+        assert_eq!(open("ann").await.err().map(|status| status.code()), Some(tonic::Code::ResourceExhausted));
+        let _other = open("bob").await.unwrap();
+
+        drop(first);
+        for _ in 0..100 {
+            if open("ann").await.is_ok() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("closing a stream didn't free a slot");
+    }
+
+
+    #[tokio::test]
+    async fn patching_one_metric_leaves_the_others_alone() {
+        let storage = Arc::new(Storage::new());
+        let metrics = TaskMetrics { estimated_hours: 8, actual_hours: 1, completion_percentage: 10.0 };
+        storage.create_task(Task { id: "t1".to_string(), metrics: Some(metrics), ..Default::default() }).await.unwrap();
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let patch = |field: &str| {
+            let metrics = TaskMetrics { actual_hours: 5, ..Default::default() };
+            let task = Task { metrics: Some(metrics), ..Default::default() };
+            Request::new(UpdateTaskRequest { id: "t1".to_string(), task: Some(task), update_mask: vec![field.to_string()] })
+        };
+
+        service.update_task(patch("metrics.actual_hours")).await.unwrap();
+        let metrics = storage.get_task("t1").await.unwrap().metrics.unwrap();
+        assert_eq!((metrics.estimated_hours, metrics.actual_hours, metrics.completion_percentage), (8, 5, 10.0));
+
+        let status = service.update_task(patch("metrics.velocity")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+
+    #[tokio::test]
+    async fn a_user_at_quota_gets_no_more_tasks() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { max_tasks_per_user: 2, ..Default::default() }));
+        let for_ann = |title: &str| CreateTaskRequest { title: title.to_string(), assigned_to: "ann".to_string(), ..Default::default() };
+
+        create(&service, for_ann("One")).await.unwrap();
+        create(&service, for_ann("Two")).await.unwrap();
+        let status = create(&service, for_ann("Three")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+        // Unassigned tasks are exempt, but can't then be handed to ann
+        let spare = create(&service, CreateTaskRequest { title: "Spare".to_string(), ..Default::default() }).await.unwrap();
+        let patch = Task { assigned_to: "ann".to_string(), ..Default::default() };
+        let request = UpdateTaskRequest { id: spare.id, task: Some(patch), update_mask: vec!["assignedTo".to_string()] };
+        let status = service.update_task(Request::new(request)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    }
+
+
+    #[tokio::test]
+    async fn unsorted_lists_use_the_configured_default_order() {
+        // t2 and t3 tie on creation time; filling two stores in different
+        // orders shows the tie doesn't fall back to map order
+        let created = [("t4", 400), ("t1", 100), ("t3", 200), ("t2", 200), ("t5", 500)];
+        let mut stores = Vec::new();
+        for order in [created.to_vec(), created.iter().rev().cloned().collect()] {
+            let storage = Arc::new(Storage::new());
+            for (id, seconds) in order {
+                let created_at = Some(SerdeTimestamp(Timestamp { seconds, nanos: 0 }));
+                storage.create_task(Task { id: id.to_string(), created_at, ..Default::default() }).await.unwrap();
+            }
+            stores.push(storage);
+        }
+        let ids = |response: ListTasksResponse| response.tasks.into_iter().map(|task| task.id).collect::<Vec<_>>();
+        let list = || Request::new(ListTasksRequest { page_size: 10, ..Default::default() });
+
+        for storage in &stores {
+            let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+            assert_eq!(ids(service.list_tasks(list()).await.unwrap().into_inner()), ["t5", "t4", "t3", "t2", "t1"]);
+        }
+
+        let oldest_first = TaskSort { field: TaskSortField::CreatedAt as i32, direction: SortDirection::Asc as i32 };
+        let service = TaskServiceImpl::new(stores[0].clone(), Arc::new(Config { default_sort: oldest_first, ..Default::default() }));
+        assert_eq!(ids(service.list_tasks(list()).await.unwrap().into_inner()), ["t1", "t2", "t3", "t4", "t5"]);
+    }
+
+
+    #[tokio::test]
+    async fn titles_are_limited_by_characters_not_bytes() {
+        let storage = Arc::new(Storage::new());
+        let config = Config { max_title_length: 5, max_description_length: 8, ..Default::default() };
+        let service = TaskServiceImpl::new(storage, Arc::new(config));
+        let titled = |title: &str, description: &str| CreateTaskRequest {
+            title: title.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        };
+
+        // Five characters, ten bytes
+        create(&service, titled("héllö", "")).await.unwrap();
+        create(&service, titled("ééééé", "12345678")).await.unwrap();
+        let status = create(&service, titled("éééééé", "")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Task title may be at most 5 characters long");
+        let status = create(&service, titled("ok", "123456789")).await.unwrap_err();
+        assert_eq!(status.message(), "Task description may be at most 8 characters long");
+    }
+
+
+    #[tokio::test]
+    async fn high_priority_tasks_need_a_due_date_when_the_policy_is_on() {
+        let prioritized = |priority: TaskPriority, due_date: Option<SerdeTimestamp>| CreateTaskRequest {
+            title: "Urgent".to_string(),
+            priority: priority as i32,
+            due_date,
+            ..Default::default()
+        };
+        let tomorrow = || Some(SerdeTimestamp::from(SystemTime::now() + Duration::from_secs(86_400)));
+
+        let off = TaskServiceImpl::new(Arc::new(Storage::new()), Arc::new(Config::default()));
+        create(&off, prioritized(TaskPriority::Critical, None)).await.unwrap();
+
+        let storage = Arc::new(Storage::new());
+        let on = TaskServiceImpl::new(storage, Arc::new(Config { due_date_required_from: Some(TaskPriority::High), ..Default::default() }));
+        for priority in [TaskPriority::High, TaskPriority::Critical] {
+            let status = create(&on, prioritized(priority, None)).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        }
+        create(&on, prioritized(TaskPriority::High, tomorrow())).await.unwrap();
+        let medium = create(&on, prioritized(TaskPriority::Medium, None)).await.unwrap();
+
+        // Raising the priority of a task without a due date trips it too
+        let patch = Task { priority: TaskPriority::High as i32, ..Default::default() };
+        let request = UpdateTaskRequest { id: medium.id, task: Some(patch), update_mask: vec!["priority".to_string()] };
+        let status = on.update_task(Request::new(request)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+
+    #[tokio::test]
+    async fn bulk_tagging_adds_and_removes_across_a_selection() {
+        let storage = Arc::new(Storage::new());
+        for (id, tags) in [("t1", vec!["old"]), ("t2", vec!["old", "keep"]), ("t3", vec!["old"])] {
+            let task = Task { id: id.to_string(), tags: tags.into_iter().map(String::from).collect(), ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+
+        let request = BulkTagTasksRequest {
+            task_ids: vec!["t1".to_string(), "t2".to_string(), "missing".to_string()],
+            add: vec![" New ".to_string(), "new".to_string()],
+            remove: vec!["OLD".to_string()],
+            dry_run: false,
+        };
+        let response = service.bulk_tag_tasks(Request::new(request)).await.unwrap().into_inner();
+        assert_eq!(response.updated_count, 2);
+        let outcomes: Vec<_> = response.results.iter().map(|result| (result.task_id.as_str(), result.success)).collect();
+        assert_eq!(outcomes, [("t1", true), ("t2", true), ("missing", false)]);
+        assert_eq!(response.results[2].error, BulkUpdateError::NotFound as i32);
+
+        assert_eq!(storage.get_task("t1").await.unwrap().tags, ["new"]);
+        assert_eq!(storage.get_task("t2").await.unwrap().tags, ["keep", "new"]);
+        assert_eq!(storage.get_task("t3").await.unwrap().tags, ["old"]);
+        let counts = storage.list_tags("").await;
+        assert_eq!(counts, [("new".to_string(), 2), ("keep".to_string(), 1), ("old".to_string(), 1)]);
+
+        let request = BulkTagTasksRequest { task_ids: vec!["t1".to_string()], add: vec!["x".to_string()], remove: vec!["X".to_string()], dry_run: false };
+        let status = service.bulk_tag_tasks(Request::new(request)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+
+    #[tokio::test]
+    async fn comments_are_length_checked_and_passed_through_the_filter_hook() {
+        use crate::moderation::Verdict;
+        use std::sync::Mutex;
+
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+        impl ContentFilter for Recorder {
+            fn review(&self, content: &str) -> Verdict {
+                self.0.lock().unwrap().push(content.to_string());
+                if content.contains("spam") { Verdict::Flag("looks like spam".to_string()) } else { Verdict::Allow }
+            }
+        }
+
+        let storage = Arc::new(Storage::new());
+        seed_task(&storage, "t1", TaskStatus::Todo).await;
+        let recorder = Recorder::default();
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { max_comment_length: 10, ..Default::default() }))
+            .with_comment_filter(recorder.clone());
+        let comment = |content: &str| AddCommentRequest { task_id: "t1".to_string(), content: content.to_string() };
+
+        for content in ["far too long to fit", "   "] {
+            let status = service.add_comment(as_user(&storage, "ann", comment(content)).await).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{:?}", content);
+        }
+        assert!(recorder.0.lock().unwrap().is_empty());
+
+        let added = service.add_comment(as_user(&storage, "ann", comment("buy spam")).await).await.unwrap();
+        assert_eq!(*recorder.0.lock().unwrap(), ["buy spam"]);
+        assert_eq!(added.into_inner().comment.unwrap().flags, ["looks like spam"]);
+    }
+
+
+    #[tokio::test]
+    async fn new_tasks_are_assigned_to_their_creator_when_the_flag_is_on() {
+        let storage = Arc::new(Storage::new());
+        let config = Config { auto_assign_to_creator: true, ..Default::default() };
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(config));
+        let request = as_user(&storage, "alice", CreateTaskRequest { title: "Mine".to_string(), ..Default::default() }).await;
+        let task = service.create_task(request).await.unwrap().into_inner().task.unwrap();
+        assert_eq!(task.assigned_to, "alice");
+        assert_eq!(storage.count_user_tasks("alice").await, 1);
+
+        let request = CreateTaskRequest { title: "Theirs".to_string(), assigned_to: "bob".to_string(), ..Default::default() };
+        let task = service.create_task(as_user(&storage, "alice", request).await).await.unwrap().into_inner().task.unwrap();
+        assert_eq!(task.assigned_to, "bob");
+
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let request = as_user(&storage, "alice", CreateTaskRequest { title: "Loose".to_string(), ..Default::default() }).await;
+        let task = service.create_task(request).await.unwrap().into_inner().task.unwrap();
+        assert!(task.assigned_to.is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn upserts_with_the_same_natural_key_make_one_task() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let upsert = |title: &str, assigned_to: &str| Request::new(CreateTaskRequest {
+            title: title.to_string(),
+            assigned_to: assigned_to.to_string(),
+            ..Default::default()
+        });
+
+        let first = service.upsert_task(upsert("Renew cert", "ann")).await.unwrap().into_inner();
+        assert!(first.created);
+        let again = service.upsert_task(upsert("Renew cert", "ann")).await.unwrap().into_inner();
+        assert!(!again.created);
+        assert_eq!(again.task.unwrap().id, first.task.unwrap().id);
+        assert_eq!(storage.count_tasks().await, 1);
+
+        let other = service.upsert_task(upsert("Renew cert", "bob")).await.unwrap().into_inner();
+        assert!(other.created);
+        assert_eq!(storage.count_tasks().await, 2);
+    }
+
+
+    #[tokio::test]
+    async fn past_due_dates_are_rejected_unless_overridden() {
+        let service = TaskServiceImpl::new(Arc::new(Storage::new()), Arc::new(Config::default()));
+        let due = |seconds: i64| Some(SerdeTimestamp(prost_types::Timestamp { seconds, nanos: 0 }));
+        let yesterday = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs() as i64 - 86_400;
+        let request = |allow_past_due: bool| CreateTaskRequest {
+            title: "Backfill".to_string(),
+            due_date: due(yesterday),
+            allow_past_due,
+            ..Default::default()
+        };
+
+        let status = create(&service, request(false)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        let task = create(&service, request(true)).await.unwrap();
+        assert_eq!(task.due_date.map(|due| due.seconds), Some(yesterday));
+
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let config = Config::default();
+        assert!(check_due_date_not_past(&config, due(1_000).as_ref(), false, now).is_ok());
+        assert!(check_due_date_not_past(&config, due(999).as_ref(), false, now).is_err());
+        let lenient = Config { reject_past_due_dates: false, ..Default::default() };
+        assert!(check_due_date_not_past(&lenient, due(999).as_ref(), false, now).is_ok());
+    }
+
+
+    #[tokio::test]
+    async fn completing_the_last_subtask_completes_the_parent() {
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config { auto_complete_parents: true, ..Default::default() }));
+        seed_task(&storage, "parent", TaskStatus::Todo).await;
+        for id in ["c1", "c2"] {
+            let child = Task { id: id.to_string(), status: TaskStatus::Todo as i32, parent_id: "parent".to_string(), ..Default::default() };
+            storage.create_task(child).await.unwrap();
+        }
+        let complete = |id: &str| Request::new(UpdateTaskStatusRequest { id: id.to_string(), status: TaskStatus::Done as i32 });
+        let parent_status = || async { storage.get_task("parent").await.unwrap().status };
+
+        service.update_task_status(complete("c1")).await.unwrap();
+        assert_eq!(parent_status().await, TaskStatus::Todo as i32);
+        service.update_task_status(complete("c2")).await.unwrap();
+        assert_eq!(parent_status().await, TaskStatus::Done as i32);
+
+        // Off by default
+        let storage = Arc::new(Storage::new());
+        let service = TaskServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        seed_task(&storage, "parent", TaskStatus::Todo).await;
+        let child = Task { id: "c1".to_string(), status: TaskStatus::Todo as i32, parent_id: "parent".to_string(), ..Default::default() };
+        storage.create_task(child).await.unwrap();
+        service.update_task_status(complete("c1")).await.unwrap();
+        assert_eq!(storage.get_task("parent").await.unwrap().status, TaskStatus::Todo as i32);
+    }
+}
@@ -1,24 +1,32 @@
 // src/services/user_service.rs
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{SystemTime, Duration};
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
 use prost_types::Timestamp;
+use tracing::warn;
 
 use crate::protogen::{
     user_service_server::UserService,
     *,
 };
-use crate::storage::Storage;
+use crate::auth;
+use crate::config::Config;
+use crate::error::TaskerError;
+use crate::storage::{BatchOutcome, Session, Storage, UserPurge};
 use crate::types::timestamp::SerdeTimestamp; // Add this import
+use crate::validation;
+use super::pagination::{next_page_token, page_info};
 
 pub struct UserServiceImpl {
     storage: Arc<Storage>,
+    config: Arc<Config>,
 }
 
 impl UserServiceImpl {
-    pub fn new(storage: Arc<Storage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<Storage>, config: Arc<Config>) -> Self {
+        Self { storage, config }
     }
     
     fn system_time_to_timestamp(time: SystemTime) -> SerdeTimestamp {
@@ -29,26 +37,43 @@ impl UserServiceImpl {
         };
         SerdeTimestamp(timestamp)
     }
-}
 
-#[tonic::async_trait]
-impl UserService for UserServiceImpl {
-    async fn create_user(
-        &self,
-        request: Request<CreateUserRequest>,
-    ) -> Result<Response<CreateUserResponse>, Status> {
-        let req = request.into_inner();
-        
-        // Check if user already exists
-        if self.storage.get_user_by_email(&req.email).await.is_some() {
-            return Err(Status::already_exists("User with this email already exists"));
+    /// Check the profile's optional fields and fill in the default avatar.
+    #[allow(clippy::result_large_err)]
+    fn prepare_profile(&self, profile: &mut UserProfile) -> Result<(), Status> {
+        profile.avatar_url = profile.avatar_url.trim().to_string();
+        if profile.avatar_url.is_empty() {
+            profile.avatar_url = self.config.default_avatar_url.clone().unwrap_or_default();
+        } else if !validation::valid_url(&profile.avatar_url) {
+            return Err(Status::invalid_argument("avatar_url must be an http(s) URL"));
         }
-        
+        if !profile.phone.trim().is_empty() && !validation::valid_phone(&profile.phone) {
+            return Err(Status::invalid_argument("phone is not a valid phone number"));
+        }
+        Ok(())
+    }
+
+    /// Validate a sign-up and build the user it describes, without checking
+    /// whether the email is taken.
+    #[allow(clippy::result_large_err)]
+    fn new_user(&self, req: CreateUserRequest) -> Result<User, Status> {
+        self.config.password_policy
+            .check(&req.password)
+            .map_err(Status::invalid_argument)?;
+        let email = validation::normalize_email(&req.email)
+            .ok_or_else(|| Status::invalid_argument("Invalid email address"))?;
+
+        let requested = req.preferences.unwrap_or_default();
+        let or_default = |value: String, default: &str| {
+            let value = value.trim();
+            if value.is_empty() { default.to_string() } else { value.to_string() }
+        };
+
         let now = SystemTime::now();
-        let user = User {
-            id: Uuid::new_v4().to_string(),
+        Ok(User {
+            id: self.config.id_format.generate(),
             username: req.username,
-            email: req.email,
+            email,
             full_name: req.full_name,
             role: req.role,
             is_active: true,
@@ -58,22 +83,97 @@ impl UserService for UserServiceImpl {
             updated_at: Some(Self::system_time_to_timestamp(now)),
             last_login: None,
             preferences: Some(UserPreferences {
-                theme: "light".to_string(),
-                language: "en".to_string(),
-                timezone: "UTC".to_string(),
+                theme: or_default(requested.theme, &self.config.default_theme),
+                language: or_default(requested.language, &self.config.default_language),
+                timezone: or_default(requested.timezone, &self.config.default_timezone),
                 notifications_enabled: true,
                 email_notifications: true,
             }),
             profile: Some(UserProfile {
-                avatar_url: String::new(),
+                avatar_url: self.config.default_avatar_url.clone().unwrap_or_default(),
                 bio: String::new(),
                 department: String::new(),
                 phone: String::new(),
                 location: String::new(),
             }),
+        })
+    }
+
+    /// Record an issued token so later requests can be authenticated with it.
+    async fn issue_session(&self, token: &str, user_id: &str, expires_at: SystemTime, refresh: bool) -> Result<(), Status> {
+        let expires_at = expires_at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs() as i64;
+        let session = Session { user_id: user_id.to_string(), expires_at, refresh };
+
+        self.storage
+            .create_session(token, session)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to create session: {}", e)))
+    }
+
+    /// `delete_user` with `purge` set; admins only, since it rewrites other
+    /// users' tasks
+    async fn purge_user(&self, request: Request<DeleteUserRequest>) -> Result<Response<DeleteUserResponse>, Status> {
+        let admin = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .ok_or_else(|| Status::unauthenticated("Missing or invalid access token"))?;
+        if !auth::is_admin(&admin) {
+            return Err(Status::permission_denied("Admin role required"));
+        }
+        let req = request.into_inner();
+
+        let purge = self.storage
+            .purge_user(&req.id, self.config.user_purge_task_policy, &req.reassign_to)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to purge user: {}", e)))?;
+        let (updated, deleted, anonymized_comments) = match purge {
+            UserPurge::NotFound => return Err(Status::not_found("User not found")),
+            UserPurge::UnknownReassignee => {
+                return Err(Status::invalid_argument("reassign_to must be another existing user"));
+            }
+            UserPurge::Purged { updated, deleted, anonymized_comments } => (updated, deleted, anonymized_comments),
+        };
+
+        for task in &updated {
+            self.storage.events().publish(TaskEventType::Updated, task, &admin.id, HashMap::new());
+        }
+        for task in &deleted {
+            self.storage.events().publish(TaskEventType::Deleted, task, &admin.id, HashMap::new());
+        }
+        // Only once the scrubbed snapshots are out, so nothing retained
+        // afterwards still refers to the user
+        self.storage.events().forget_user(&req.id);
+        if let Err(e) = self.storage.save_event_history().await {
+            warn!("Failed to rewrite event history after purging {}: {}", req.id, e);
+        }
+
+        let response = DeleteUserResponse {
+            success: true,
+            message: "User purged successfully".to_string(),
+            affected_tasks: (updated.len() + deleted.len()) as u64,
+            anonymized_comments: anonymized_comments as u64,
         };
 
-        self.storage.create_user(user.clone()).await;
+        Ok(Response::new(response))
+    }
+}
+
+fn user_limit_message(config: &Config) -> String {
+    format!("The server already holds the maximum of {} users", config.max_total_users)
+}
+
+#[tonic::async_trait]
+impl UserService for UserServiceImpl {
+    async fn create_user(
+        &self,
+        request: Request<CreateUserRequest>,
+    ) -> Result<Response<CreateUserResponse>, Status> {
+        let user = self.new_user(request.into_inner())?;
+        
+        // Storage refuses a taken email or username itself, under its write lock
+        self.storage
+            .create_user(user.clone())
+            .await
+            .map_err(|e| Status::from(TaskerError::from(e.context("Failed to create user"))))?;
 
         let response = CreateUserResponse {
             user: Some(user),
@@ -84,6 +184,87 @@ impl UserService for UserServiceImpl {
         Ok(Response::new(response))
     }
 
+    async fn batch_create_users(
+        &self,
+        request: Request<BatchCreateUsersRequest>,
+    ) -> Result<Response<BatchCreateUsersResponse>, Status> {
+        let admin = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .ok_or_else(|| Status::unauthenticated("Missing or invalid access token"))?;
+        if !auth::is_admin(&admin) {
+            return Err(Status::permission_denied("Admin role required"));
+        }
+        let req = request.into_inner();
+
+        let failure = |error: BatchCreateUserError, message: String| BatchCreateUserResult {
+            user: None,
+            success: false,
+            error: error as i32,
+            error_message: message,
+        };
+
+        // Validate every entry first so duplicates within the batch are
+        // caught before anything is written
+        let mut results: Vec<Option<BatchCreateUserResult>> = Vec::with_capacity(req.users.len());
+        let mut pending = Vec::new();
+        let mut seen_emails = HashSet::new();
+        let mut seen_usernames = HashSet::new();
+        for (index, entry) in req.users.into_iter().enumerate() {
+            let user = match self.new_user(entry) {
+                Ok(user) => user,
+                Err(status) => {
+                    results.push(Some(failure(BatchCreateUserError::Invalid, status.message().to_string())));
+                    continue;
+                }
+            };
+            if !seen_emails.insert(user.email.to_lowercase()) {
+                let message = format!("Email {} appears earlier in the batch", user.email);
+                results.push(Some(failure(BatchCreateUserError::Duplicate, message)));
+                continue;
+            }
+            if !seen_usernames.insert(user.username.clone()) {
+                let message = format!("Username {} appears earlier in the batch", user.username);
+                results.push(Some(failure(BatchCreateUserError::Duplicate, message)));
+                continue;
+            }
+            results.push(None);
+            pending.push((index, user));
+        }
+
+        let (indices, users): (Vec<usize>, Vec<User>) = pending.into_iter().unzip();
+        match self.storage.batch_create_users(users.clone()).await {
+            Ok(outcomes) => {
+                for ((index, user), outcome) in indices.into_iter().zip(users).zip(outcomes) {
+                    results[index] = Some(match outcome {
+                        BatchOutcome::Created => {
+                            BatchCreateUserResult { user: Some(user), success: true, ..Default::default() }
+                        }
+                        BatchOutcome::Duplicate => {
+                            failure(BatchCreateUserError::Duplicate, "Email or username appears earlier in the batch".to_string())
+                        }
+                        BatchOutcome::Taken => {
+                            failure(BatchCreateUserError::AlreadyExists, "Email or username is already taken".to_string())
+                        }
+                        BatchOutcome::LimitReached => failure(BatchCreateUserError::LimitReached, user_limit_message(&self.config)),
+                    });
+                }
+            }
+            Err(e) => {
+                for index in indices {
+                    results[index] = Some(failure(BatchCreateUserError::Storage, format!("Failed to create user: {}", e)));
+                }
+            }
+        }
+
+        let results: Vec<BatchCreateUserResult> = results.into_iter().flatten().collect();
+        let response = BatchCreateUsersResponse {
+            created_count: results.iter().filter(|result| result.success).count() as u64,
+            results,
+        };
+
+        Ok(Response::new(response))
+    }
+
     async fn get_user(
         &self,
         request: Request<GetUserRequest>,
@@ -113,9 +294,15 @@ impl UserService for UserServiceImpl {
         
         if let Some(mut user) = req.user {
             user.id = req.id.clone();
+            user.email = validation::normalize_email(&user.email)
+                .ok_or_else(|| Status::invalid_argument("Invalid email address"))?;
+            self.prepare_profile(user.profile.get_or_insert_with(Default::default))?;
             user.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
             
-            self.storage.update_user(user.clone()).await;
+            self.storage
+                .update_user(user.clone())
+                .await
+                .map_err(|e| Status::from(TaskerError::from(e.context("Failed to update user"))))?;
             
             let response = UpdateUserResponse {
                 user: Some(user),
@@ -132,6 +319,9 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<DeleteUserRequest>,
     ) -> Result<Response<DeleteUserResponse>, Status> {
+        if request.get_ref().purge {
+            return self.purge_user(request).await;
+        }
         let req = request.into_inner();
     
         let success = self
@@ -147,6 +337,7 @@ impl UserService for UserServiceImpl {
             } else {
                 "User not found".to_string()
             },
+            ..Default::default()
         };
     
         Ok(Response::new(response))
@@ -158,16 +349,17 @@ impl UserService for UserServiceImpl {
     ) -> Result<Response<ListUsersResponse>, Status> {
         let req = request.into_inner();
         
-        let users = self.storage.list_users(req.page_size, &req.page_token).await;
+        let newest_first = self.config.default_sort.direction == SortDirection::Desc as i32;
+        let users = self.storage.list_users(req.page_size, &req.page_token, newest_first).await;
+        let total_count = self.storage.count_users().await;
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
         
         let response = ListUsersResponse {
-            users: users.clone(),
-            next_page_token: if users.len() >= req.page_size as usize {
-                format!("page_{}", req.page_token.parse::<u32>().unwrap_or(0) + 1)
-            } else {
-                String::new()
-            },
-            total_count: self.storage.count_users().await,
+            next_page_token: next_page_token(&req.page_token, users.len(), req.page_size),
+            users,
+            total_count,
+            current_page,
+            total_pages,
         };
         
         Ok(Response::new(response))
@@ -185,10 +377,14 @@ impl UserService for UserServiceImpl {
             
             let now = SystemTime::now();
             user.last_login = Some(Self::system_time_to_timestamp(now));
-            self.storage.update_user(user.clone()).await;
+            self.storage
+                .update_user(user.clone())
+                .await
+                .map_err(|e| Status::internal(format!("Failed to record login: {}", e)))?;
             
             let token = format!("jwt_token_{}", Uuid::new_v4());
             let tomorrow = now + Duration::from_secs(3600 * 24);
+            self.issue_session(&token, &user.id, tomorrow, false).await?;
 
             let response = AuthenticateUserResponse {
                 user: Some(user),
@@ -219,15 +415,15 @@ impl UserService for UserServiceImpl {
         let req = request.into_inner();
         
         let tasks = self.storage.get_tasks_by_user(&req.user_id, req.page_size, &req.page_token).await;
+        let total_count = self.storage.count_user_tasks(&req.user_id).await;
+        let (current_page, total_pages) = page_info(&req.page_token, req.page_size, total_count);
         
         let response = GetUserTasksResponse {
-            tasks: tasks.clone(),
-            next_page_token: if tasks.len() >= req.page_size as usize {
-                format!("page_{}", req.page_token.parse::<u32>().unwrap_or(0) + 1)
-            } else {
-                String::new()
-            },
-            total_count: self.storage.count_user_tasks(&req.user_id).await,
+            next_page_token: next_page_token(&req.page_token, tasks.len(), req.page_size),
+            tasks,
+            total_count,
+            current_page,
+            total_pages,
         };
         
         Ok(Response::new(response))
@@ -243,7 +439,10 @@ impl UserService for UserServiceImpl {
             user.preferences = req.preferences;
             user.updated_at = Some(Self::system_time_to_timestamp(SystemTime::now()));
             
-            self.storage.update_user(user.clone()).await;
+            self.storage
+                .update_user(user.clone())
+                .await
+                .map_err(|e| Status::internal(format!("Failed to update preferences: {}", e)))?;
             
             let response = UpdateUserPreferencesResponse {
                 preferences: user.preferences,
@@ -257,6 +456,46 @@ impl UserService for UserServiceImpl {
         }
     }
 
+    async fn reassign_user_tasks(
+        &self,
+        request: Request<ReassignUserTasksRequest>,
+    ) -> Result<Response<ReassignUserTasksResponse>, Status> {
+        let admin = auth::user_from_metadata(&self.storage, request.metadata())
+            .await
+            .ok_or_else(|| Status::unauthenticated("Missing or invalid access token"))?;
+        if !auth::is_admin(&admin) {
+            return Err(Status::permission_denied("Admin role required"));
+        }
+        let req = request.into_inner();
+
+        if req.from_user_id == req.to_user_id {
+            return Err(Status::invalid_argument("Source and target user must differ"));
+        }
+
+        let tasks = self.storage
+            .reassign_user_tasks(&req.from_user_id, &req.to_user_id)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to reassign tasks: {}", e)))?
+            .ok_or_else(|| Status::not_found("Target user not found"))?;
+
+        let metadata = std::collections::HashMap::from([
+            ("from_user_id".to_string(), req.from_user_id.clone()),
+            ("to_user_id".to_string(), req.to_user_id.clone()),
+        ]);
+        for task in &tasks {
+            self.storage.events().publish(TaskEventType::Assigned, task, &admin.id, metadata.clone());
+        }
+
+        let response = ReassignUserTasksResponse {
+            reassigned_count: tasks.len() as u64,
+            task_ids: tasks.into_iter().map(|task| task.id).collect(),
+            success: true,
+            message: format!("Reassigned tasks from {} to {}", req.from_user_id, req.to_user_id),
+        };
+
+        Ok(Response::new(response))
+    }
+
     // Legacy authentication methods (required by the trait)
     async fn login(
         &self,
@@ -269,11 +508,16 @@ impl UserService for UserServiceImpl {
         if let Some(mut user) = self.storage.get_user_by_username(&req.username).await {
             let now = SystemTime::now();
             user.last_login = Some(Self::system_time_to_timestamp(now));
-            self.storage.update_user(user.clone()).await;
+            self.storage
+                .update_user(user.clone())
+                .await
+                .map_err(|e| Status::internal(format!("Failed to record login: {}", e)))?;
             
             let access_token = format!("access_token_{}", Uuid::new_v4());
             let refresh_token = format!("refresh_token_{}", Uuid::new_v4());
             let expires_at = now + Duration::from_secs(3600); // 1 hour
+            self.issue_session(&access_token, &user.id, expires_at, false).await?;
+            self.issue_session(&refresh_token, &user.id, now + Duration::from_secs(3600 * 24 * 7), true).await?;
 
             let response = LoginResponse {
                 access_token,
@@ -292,12 +536,15 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<RefreshTokenRequest>,
     ) -> Result<Response<RefreshTokenResponse>, Status> {
-        let _req = request.into_inner();
+        let req = request.into_inner();
         
-        // In a real implementation, you'd validate the refresh token
-        // For demo purposes, we'll just generate a new access token
+        let user = crate::auth::authenticate_refresh(&self.storage, &req.refresh_token)
+            .await
+            .ok_or_else(|| Status::unauthenticated("Invalid or expired refresh token"))?;
+
         let access_token = format!("access_token_{}", Uuid::new_v4());
         let expires_at = SystemTime::now() + Duration::from_secs(3600); // 1 hour
+        self.issue_session(&access_token, &user.id, expires_at, false).await?;
 
         let response = RefreshTokenResponse {
             access_token,
@@ -309,10 +556,214 @@ impl UserService for UserServiceImpl {
 
     async fn logout(
         &self,
-        _request: Request<LogoutRequest>,
+        request: Request<LogoutRequest>,
     ) -> Result<Response<()>, Status> {
-        // In a real implementation, you'd invalidate the token
-        // For demo purposes, we'll just return success
+        let req = request.into_inner();
+
+        self.storage
+            .revoke_session(&req.access_token)
+            .await
+            .map_err(|e| Status::internal(format!("Failed to revoke session: {}", e)))?;
+
         Ok(Response::new(()))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::PasswordPolicy;
+    use crate::config::UserPurgeTaskPolicy;
+
+    async fn seed_user(storage: &Storage, id: &str, role: UserRole) {
+        let user = User {
+            id: id.to_string(),
+            username: id.to_string(),
+            email: format!("{}@example.com", id),
+            role: role as i32,
+            is_active: true,
+            ..Default::default()
+        };
+        storage.create_user(user).await.unwrap();
+    }
+
+    fn comment(author_id: &str) -> TaskComment {
+        TaskComment {
+            id: Uuid::new_v4().to_string(),
+            author_id: author_id.to_string(),
+            content: format!("written by {}", author_id),
+            ..Default::default()
+        }
+    }
+
+    fn admin_request<T>(req: T) -> Request<T> {
+        let mut request = Request::new(req);
+        request.metadata_mut().insert("authorization", "Bearer admin-token".parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn purge_leaves_no_trace_in_tasks_or_event_history() {
+        let storage = Arc::new(Storage::new());
+        seed_user(&storage, "admin", UserRole::Admin).await;
+        seed_user(&storage, "gone", UserRole::Member).await;
+        seed_user(&storage, "other", UserRole::Member).await;
+        let session = Session { user_id: "admin".to_string(), expires_at: i64::MAX / 2, refresh: false };
+        storage.create_session("admin-token", session).await.unwrap();
+
+        let own = storage.create_task(Task {
+            id: "own".to_string(),
+            assigned_to: "gone".to_string(),
+            comments: vec![comment("gone"), comment("other")],
+            ..Default::default()
+        }).await.unwrap();
+        let shared = storage.create_task(Task {
+            id: "shared".to_string(),
+            assigned_to: "other".to_string(),
+            comments: vec![comment("gone")],
+            watchers: vec!["gone".to_string()],
+            ..Default::default()
+        }).await.unwrap();
+        storage.events().publish(TaskEventType::Created, &own, "gone", HashMap::new());
+        storage.events().publish(TaskEventType::Updated, &shared, "other", HashMap::new());
+
+        let config = Config { user_purge_task_policy: UserPurgeTaskPolicy::Delete, ..Default::default() };
+        let service = UserServiceImpl::new(storage.clone(), Arc::new(config));
+        let request = DeleteUserRequest { id: "gone".to_string(), purge: true, reassign_to: String::new() };
+        let response = service.purge_user(admin_request(request)).await.unwrap().into_inner();
+        assert_eq!(response.affected_tasks, 2);
+        assert_eq!(response.anonymized_comments, 2);
+
+        let mentions = |task: &Task| {
+            task.assigned_to == "gone"
+                || task.watchers.iter().any(|watcher| watcher == "gone")
+                || task.comments.iter().any(|comment| comment.author_id == "gone")
+        };
+        assert!(storage.get_task("own").await.is_none());
+        let shared = storage.get_task("shared").await.unwrap();
+        assert!(!mentions(&shared));
+        assert_eq!(shared.comments.len(), 1);
+
+        let epoch = SerdeTimestamp(Timestamp { seconds: 0, nanos: 0 });
+        let (history, _) = storage.events().subscribe_since(Some(&epoch), "");
+        assert!(!history.is_empty());
+        for event in &history {
+            assert_ne!(event.user_id, "gone");
+            assert!(!event.task.as_ref().is_some_and(mentions), "event {} still refers to the user", event.event_id);
+        }
+        let deleted = history.iter()
+            .find(|event| event.event_type == TaskEventType::Deleted as i32)
+            .and_then(|event| event.task.as_ref())
+            .unwrap();
+        assert_eq!(deleted.id, "own");
+        assert_eq!(deleted.comments.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn an_admin_can_hand_all_of_a_users_tasks_to_another() {
+        let storage = Arc::new(Storage::new());
+        seed_user(&storage, "admin", UserRole::Admin).await;
+        seed_user(&storage, "ann", UserRole::Member).await;
+        seed_user(&storage, "bob", UserRole::Member).await;
+        let session = Session { user_id: "admin".to_string(), expires_at: i64::MAX / 2, refresh: false };
+        storage.create_session("admin-token", session).await.unwrap();
+        for (id, assignee) in [("a1", "ann"), ("a2", "ann"), ("b1", "bob")] {
+            storage.create_task(Task { id: id.to_string(), assigned_to: assignee.to_string(), ..Default::default() }).await.unwrap();
+        }
+        let service = UserServiceImpl::new(storage.clone(), Arc::new(Config::default()));
+        let reassign = |to: &str| ReassignUserTasksRequest { from_user_id: "ann".to_string(), to_user_id: to.to_string() };
+
+        let anonymous = service.reassign_user_tasks(Request::new(reassign("bob"))).await;
+        assert_eq!(anonymous.err().map(|status| status.code()), Some(tonic::Code::Unauthenticated));
+        let missing = service.reassign_user_tasks(admin_request(reassign("nobody"))).await;
+        assert_eq!(missing.err().map(|status| status.code()), Some(tonic::Code::NotFound));
+
+        let response = service.reassign_user_tasks(admin_request(reassign("bob"))).await.unwrap().into_inner();
+        assert_eq!(response.reassigned_count, 2);
+        assert_eq!(storage.count_user_tasks("ann").await, 0);
+        assert_eq!(storage.count_user_tasks("bob").await, 3);
+        assert_eq!(storage.get_task("a1").await.unwrap().assigned_to, "bob");
+    }
+
+
+    #[tokio::test]
+    async fn passwords_must_meet_the_configured_policy() {
+        let storage = Arc::new(Storage::new());
+        let policy = PasswordPolicy { require_symbol: true, ..Default::default() };
+        let service = UserServiceImpl::new(storage, Arc::new(Config { password_policy: policy, ..Default::default() }));
+        let sign_up = |username: &str, password: &str| CreateUserRequest {
+            username: username.to_string(),
+            email: format!("{}@example.com", username),
+            password: password.to_string(),
+            ..Default::default()
+        };
+
+        for (password, rule) in [
+            ("Sh0rt!", "at least 10 characters"),
+            ("all lower 1!", "upper- and lowercase"),
+            ("No Digits Here!", "a digit"),
+            ("NoSymbols123", "a symbol"),
+        ] {
+            let status = service.create_user(Request::new(sign_up("weak", password))).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+            assert!(status.message().contains(rule), "{}: {}", password, status.message());
+        }
+        let created = service.create_user(Request::new(sign_up("strong", "Str0ng enough!"))).await.unwrap();
+        assert!(created.into_inner().success);
+    }
+
+
+    #[tokio::test]
+    async fn profile_urls_and_phones_are_checked_and_the_default_avatar_fills_in() {
+        let storage = Arc::new(Storage::new());
+        seed_user(&storage, "ann", UserRole::Member).await;
+        let config = Config { default_avatar_url: Some("https://cdn.example.com/default.png".to_string()), ..Default::default() };
+        let service = UserServiceImpl::new(storage.clone(), Arc::new(config));
+        let update = |profile: UserProfile| {
+            let user = User { username: "ann".to_string(), email: "ann@example.com".to_string(), profile: Some(profile), ..Default::default() };
+            Request::new(UpdateUserRequest { id: "ann".to_string(), user: Some(user) })
+        };
+
+        for profile in [
+            UserProfile { avatar_url: "not a url".to_string(), ..Default::default() },
+            UserProfile { avatar_url: "javascript:alert(1)".to_string(), ..Default::default() },
+            UserProfile { phone: "call me maybe".to_string(), ..Default::default() },
+        ] {
+            let status = service.update_user(update(profile.clone())).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{:?}", profile);
+        }
+
+        let profile = UserProfile { phone: "+1 (555) 010-0000".to_string(), ..Default::default() };
+        service.update_user(update(profile)).await.unwrap();
+        let saved = storage.get_user("ann").await.unwrap().profile.unwrap();
+        assert_eq!(saved.avatar_url, "https://cdn.example.com/default.png");
+    }
+
+
+    #[tokio::test]
+    async fn new_users_take_the_configured_preferences_unless_they_ask_otherwise() {
+        let storage = Arc::new(Storage::new());
+        let config = Config {
+            default_theme: "dark".to_string(),
+            default_language: "de".to_string(),
+            default_timezone: "Europe/Berlin".to_string(),
+            ..Default::default()
+        };
+        let service = UserServiceImpl::new(storage, Arc::new(config));
+        let sign_up = |username: &str, preferences: Option<UserPreferences>| CreateUserRequest {
+            username: username.to_string(),
+            email: format!("{}@example.com", username),
+            password: "Passw0rd!long".to_string(),
+            preferences,
+            ..Default::default()
+        };
+        let preferences_of = |response: Response<CreateUserResponse>| response.into_inner().user.unwrap().preferences.unwrap();
+
+        let plain = preferences_of(service.create_user(Request::new(sign_up("ann", None))).await.unwrap());
+        assert_eq!((plain.theme.as_str(), plain.language.as_str(), plain.timezone.as_str()), ("dark", "de", "Europe/Berlin"));
+
+        let chosen = UserPreferences { language: "fr".to_string(), ..Default::default() };
+        let mixed = preferences_of(service.create_user(Request::new(sign_up("bob", Some(chosen)))).await.unwrap());
+        assert_eq!((mixed.theme.as_str(), mixed.language.as_str(), mixed.timezone.as_str()), ("dark", "fr", "Europe/Berlin"));
+    }
+}
@@ -1,31 +1,508 @@
 // src/storage/mod.rs
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::path::Path;
-use tokio::sync::RwLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tokio::sync::{watch, Mutex, OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::fs;
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, Context};
+use tracing::{info, warn};
 
-use crate::protogen::{User, Task, TaskStatus, TaskPriority};
+use crate::attachments::AttachmentStore;
+use crate::calendar::DueSoon;
+use crate::config::{Config, NaturalKeyField, ProjectDeletePolicy, UserPurgeTaskPolicy};
+use crate::encryption::{EncryptedField, FieldCipher};
+use crate::error::TaskerError;
+use crate::events::{EventBus, StreamStats};
+use crate::search;
+use crate::types::SerdeTimestamp;
+use crate::workflow;
+use crate::protogen::{
+    User, Task, TaskStatus, TaskPriority, TaskFilter, TaskSort, TaskSortField, SortDirection,
+    SavedView, TaskAnalytics, TaskAttachment, TaskComment, TaskEvent, TaskReminder, Project, AssigneeWorkload,
+    TimeseriesMetric, TimeseriesPoint,
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Valid values for `Task::priority_score`
+pub const PRIORITY_SCORE_RANGE: std::ops::RangeInclusive<f64> = 0.0..=100.0;
+
+/// Where a task sorts by priority: by its enum priority, then by its score
+/// among tasks of the same priority, unscored ones first.
+fn priority_rank(task: &Task) -> (i32, f64) {
+    (task.priority, task.priority_score.unwrap_or(f64::NEG_INFINITY))
+}
+
+/// Only the primary maps are persisted; the `#[serde(skip)]` indices are
+/// derived from them by `rebuild_indices` whenever data is loaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct StorageData {
     users: HashMap<String, User>,
+    #[serde(skip)]
     users_by_email: HashMap<String, String>,
+    #[serde(skip)]
     users_by_username: HashMap<String, String>,
     tasks: HashMap<String, Task>,
+    #[serde(skip)]
     user_tasks: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    saved_views: HashMap<String, Vec<SavedView>>,
+    #[serde(skip)]
+    tasks_by_tag: HashMap<String, HashSet<String>>,
+    #[serde(default)]
+    sessions: HashMap<String, Session>,
+    #[serde(default)]
+    deleted_tasks: HashMap<String, DeletedTask>,
+    /// Tasks moved out of the active set; unlike tombstones they are kept
+    /// until restored
+    #[serde(default)]
+    archived_tasks: HashMap<String, ArchivedTask>,
+    #[serde(default)]
+    projects: HashMap<String, Project>,
+    #[serde(skip)]
+    project_tasks: HashMap<String, Vec<String>>,
+    /// Last task number handed out; it only grows, so numbers of deleted
+    /// tasks are never reused
+    #[serde(default)]
+    last_task_number: u64,
+    #[serde(skip)]
+    tasks_by_number: HashMap<u64, String>,
+    /// Fields holding ciphertext in the file, by user id. Only ever filled on
+    /// the copy being written; decoding drains it. Always written, even
+    /// when empty, as bincode files are positional.
+    #[serde(default)]
+    encrypted_fields: HashMap<String, Vec<EncryptedField>>,
 }
 
-impl Default for StorageData {
-    fn default() -> Self {
-        Self {
-            users: HashMap::new(),
-            users_by_email: HashMap::new(),
-            users_by_username: HashMap::new(),
-            tasks: HashMap::new(),
-            user_tasks: HashMap::new(),
+/// A soft-deleted task, kept until compaction purges it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedTask {
+    pub task: Task,
+    /// Deletion time as seconds since the Unix epoch
+    pub deleted_at: i64,
+}
+
+/// An archived task, out of active listings and scans until restored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedTask {
+    pub task: Task,
+    /// Archive time as seconds since the Unix epoch
+    pub archived_at: i64,
+}
+
+/// What a compaction run reclaimed
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CompactionReport {
+    pub purged_tombstones: usize,
+    /// Attachment files no task, tombstone or archived task refers to
+    pub orphaned_attachments: usize,
+    pub attachment_bytes_reclaimed: u64,
+    pub dangling_user_task_entries: usize,
+    pub expired_sessions: usize,
+    pub bytes_before: Option<u64>,
+    pub bytes_after: Option<u64>,
+}
+
+/// What a reindex run rebuilt
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReindexReport {
+    pub tasks_indexed: usize,
+    pub users_indexed: usize,
+    /// Index entries that differed from the rebuilt indices
+    pub repaired_entries: usize,
+    pub duration_ms: u64,
+}
+
+/// Everything stored about one user, for data-subject requests
+#[derive(Debug, Clone, Serialize)]
+pub struct UserExport {
+    pub exported_at: String,
+    pub user: User,
+    /// Tasks assigned to the user
+    pub tasks: Vec<Task>,
+    /// Archived tasks assigned to the user
+    pub archived_tasks: Vec<Task>,
+    /// Soft-deleted tasks assigned to the user, until compaction purges them
+    pub deleted_tasks: Vec<Task>,
+    /// Comments the user wrote, on any task, archived and soft-deleted ones included
+    pub comments: Vec<TaskComment>,
+    pub saved_views: Vec<SavedView>,
+    /// Task events the user caused, as far back as the in-memory event
+    /// history goes; older activity isn't kept anywhere
+    pub activity: Vec<TaskEvent>,
+}
+
+/// Outcome of `Storage::purge_user`
+#[derive(Debug, Clone)]
+pub enum UserPurge {
+    NotFound,
+    /// The user to hand tasks to doesn't exist or is the one being purged
+    UnknownReassignee,
+    Purged {
+        /// Tasks that were reassigned, unassigned or scrubbed, as they are now
+        updated: Vec<Task>,
+        /// Tasks deleted under `UserPurgeTaskPolicy::Delete`
+        deleted: Vec<Task>,
+        anonymized_comments: usize,
+    },
+}
+
+/// Outcome of `Storage::delete_project`
+#[derive(Debug, Clone)]
+pub enum ProjectDeletion {
+    NotFound,
+    /// The policy is `Block` and the project still has tasks
+    Blocked { task_count: usize },
+    /// The tasks that were unassigned or deleted along with the project
+    Deleted { tasks: Vec<Task> },
+}
+
+/// Outcome for one record of `Storage::batch_create_tasks` or
+/// `Storage::batch_create_users`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOutcome {
+    Created,
+    /// Repeats the id (or a user's email or username) of an earlier record in the batch
+    Duplicate,
+    /// Collides with something already stored
+    Taken,
+    /// The store already holds the maximum number of records
+    LimitReached,
+}
+
+/// An issued token and who it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub user_id: String,
+    /// Expiry as seconds since the Unix epoch
+    pub expires_at: i64,
+    /// Refresh tokens can only mint new access tokens, not authorize requests
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Dataset size figures for capacity planning
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageStats {
+    pub task_count: usize,
+    pub user_count: usize,
+    pub distinct_tags: usize,
+    pub attachment_count: usize,
+    pub attachment_bytes: u64,
+    pub storage_file_bytes: Option<u64>,
+    pub last_saved_at: Option<String>,
+    pub streams: StreamStats,
+}
+
+impl StorageData {
+    fn index_tags(&mut self, task_id: &str, tags: &[String]) {
+        for tag in tags {
+            self.tasks_by_tag
+                .entry(tag.clone())
+                .or_default()
+                .insert(task_id.to_string());
+        }
+    }
+
+    fn unindex_tags(&mut self, task_id: &str, tags: &[String]) {
+        for tag in tags {
+            if let Some(ids) = self.tasks_by_tag.get_mut(tag) {
+                ids.remove(task_id);
+                if ids.is_empty() {
+                    self.tasks_by_tag.remove(tag);
+                }
+            }
+        }
+    }
+
+    /// Drop a user's email and username keys, but only where they still
+    /// point at that user; a key may belong to another user it collided with.
+    fn unindex_user(&mut self, user_id: &str, email: &str, username: &str) {
+        if self.users_by_email.get(email).is_some_and(|owner| owner == user_id) {
+            self.users_by_email.remove(email);
+        }
+        if self.users_by_username.get(username).is_some_and(|owner| owner == user_id) {
+            self.users_by_username.remove(username);
+        }
+    }
+
+    /// Drop a task from the primary map and every index that references it.
+    fn remove_task(&mut self, task_id: &str) -> Option<Task> {
+        let task = self.tasks.remove(task_id)?;
+        self.unindex_tags(task_id, &task.tags);
+        self.tasks_by_number.remove(&task.number);
+
+        if !task.assigned_to.is_empty() {
+            if let Some(user_tasks) = self.user_tasks.get_mut(&task.assigned_to) {
+                user_tasks.retain(|id| id != task_id);
+            }
+        }
+        self.reindex_project(task_id, &task.project_id, "");
+        Some(task)
+    }
+
+    /// Move a task between projects in `project_tasks`.
+    fn reindex_project(&mut self, task_id: &str, old: &str, new: &str) {
+        if old == new {
+            return;
+        }
+        if let Some(project_tasks) = self.project_tasks.get_mut(old) {
+            project_tasks.retain(|id| id != task_id);
+        }
+        if !new.is_empty() {
+            self.project_tasks.entry(new.to_string()).or_default().push(task_id.to_string());
+        }
+    }
+
+    /// Move a task between assignees in `user_tasks`.
+    fn reindex_assignee(&mut self, task_id: &str, old: &str, new: &str) {
+        if old == new {
+            return;
+        }
+        if let Some(user_tasks) = self.user_tasks.get_mut(old) {
+            user_tasks.retain(|id| id != task_id);
+        }
+        if !new.is_empty() {
+            self.user_tasks.entry(new.to_string()).or_default().push(task_id.to_string());
+        }
+    }
+
+    /// Store `task` over any task with the same id, moving it between the
+    /// tag, assignee and project indices as needed.
+    fn replace_task(&mut self, task: Task) {
+        let task_id = task.id.clone();
+        let new_tags = task.tags.clone();
+        let new_assignee = task.assigned_to.clone();
+        let new_project = task.project_id.clone();
+        let (old_assignee, old_project) = match self.tasks.insert(task_id.clone(), task) {
+            Some(old) => {
+                self.unindex_tags(&task_id, &old.tags);
+                (old.assigned_to, old.project_id)
+            }
+            None => (String::new(), String::new()),
+        };
+        self.index_tags(&task_id, &new_tags);
+        self.reindex_assignee(&task_id, &old_assignee, &new_assignee);
+        self.reindex_project(&task_id, &old_project, &new_project);
+    }
+
+    /// Give a task whose id is known to be free the next task number, then
+    /// store and index it.
+    fn insert_new_task(&mut self, mut task: Task) -> Task {
+        self.last_task_number += 1;
+        task.number = self.last_task_number;
+        self.insert_task(task)
+    }
+
+    /// Store a task whose id is known to be free and index it.
+    fn insert_task(&mut self, task: Task) -> Task {
+        let task_id = task.id.clone();
+        if task.number > 0 {
+            self.tasks_by_number.insert(task.number, task_id.clone());
+        }
+        self.index_tags(&task_id, &task.tags);
+        self.reindex_project(&task_id, "", &task.project_id);
+        if !task.assigned_to.is_empty() {
+            self.user_tasks
+                .entry(task.assigned_to.clone())
+                .or_default()
+                .push(task_id.clone());
+        }
+        self.tasks.insert(task_id, task.clone());
+        task
+    }
+
+    /// Move a task from the active set into the archive.
+    fn archive_task(&mut self, task_id: &str, archived_at: i64) -> Option<Task> {
+        let task = self.remove_task(task_id)?;
+        self.archived_tasks.insert(task_id.to_string(), ArchivedTask { task: task.clone(), archived_at });
+        Some(task)
+    }
+
+    fn find_by_natural_key(&self, task: &Task, key: &[NaturalKeyField]) -> Option<&Task> {
+        self.tasks.values().find(|existing| same_natural_key(key, existing, task))
+    }
+
+    /// Number tasks stored before numbering existed, oldest first, and keep
+    /// the counter past every number in use, soft-deleted tasks included.
+    fn assign_missing_numbers(&mut self) {
+        let highest = self.tasks.values()
+            .chain(self.deleted_tasks.values().map(|deleted| &deleted.task))
+            .chain(self.archived_tasks.values().map(|archived| &archived.task))
+            .map(|task| task.number)
+            .max()
+            .unwrap_or(0);
+        self.last_task_number = self.last_task_number.max(highest);
+
+        let mut unnumbered: Vec<(Option<(i64, i32)>, String)> = self.tasks.values()
+            .filter(|task| task.number == 0)
+            .map(|task| (task.created_at.as_ref().map(|ts| (ts.seconds, ts.nanos)), task.id.clone()))
+            .collect();
+        unnumbered.sort();
+        for (_, task_id) in unnumbered {
+            self.last_task_number += 1;
+            if let Some(task) = self.tasks.get_mut(&task_id) {
+                task.number = self.last_task_number;
+            }
+        }
+    }
+
+    /// Recompute every secondary index from the primary `users`/`tasks` maps,
+    /// returning how many index entries had to change.
+    fn rebuild_indices(&mut self, fold_username_case: bool) -> usize {
+        let indices = self.build_indices(fold_username_case);
+        self.swap_indices(indices)
+    }
+
+    /// Compute every secondary index afresh without touching the current ones
+    fn build_indices(&self, fold_username_case: bool) -> Indices {
+        let mut users_by_email = HashMap::new();
+        let mut users_by_username = HashMap::new();
+        let mut user_tasks: HashMap<String, Vec<String>> = HashMap::new();
+        for user in self.users.values() {
+            users_by_email.insert(email_key(&user.email), user.id.clone());
+            users_by_username.insert(username_key(&user.username, fold_username_case), user.id.clone());
+            user_tasks.insert(user.id.clone(), Vec::new());
+        }
+
+        let mut tasks: Vec<&Task> = self.tasks.values().collect();
+        tasks.sort_by_key(|task| task.created_at.as_ref().map(|ts| (ts.seconds, ts.nanos)));
+
+        let mut project_tasks: HashMap<String, Vec<String>> = self.projects.keys()
+            .map(|id| (id.clone(), Vec::new()))
+            .collect();
+        let mut tasks_by_tag: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut tasks_by_number = HashMap::new();
+        for task in tasks {
+            if task.number > 0 {
+                tasks_by_number.insert(task.number, task.id.clone());
+            }
+            if !task.assigned_to.is_empty() {
+                user_tasks.entry(task.assigned_to.clone()).or_default().push(task.id.clone());
+            }
+            if !task.project_id.is_empty() {
+                project_tasks.entry(task.project_id.clone()).or_default().push(task.id.clone());
+            }
+            for tag in &task.tags {
+                tasks_by_tag.entry(tag.clone()).or_default().insert(task.id.clone());
+            }
+        }
+
+        Indices { users_by_email, users_by_username, user_tasks, tasks_by_tag, project_tasks, tasks_by_number }
+    }
+
+    /// Replace the secondary indices with `indices`, returning how many
+    /// entries differed.
+    fn swap_indices(&mut self, indices: Indices) -> usize {
+        let as_sets = |index: &HashMap<String, Vec<String>>| -> HashMap<String, HashSet<String>> {
+            index.iter()
+                .map(|(key, ids)| (key.clone(), ids.iter().cloned().collect()))
+                .collect()
+        };
+        let repaired = count_differences(&self.users_by_email, &indices.users_by_email)
+            + count_differences(&self.users_by_username, &indices.users_by_username)
+            + count_differences(&as_sets(&self.user_tasks), &as_sets(&indices.user_tasks))
+            + count_differences(&self.tasks_by_tag, &indices.tasks_by_tag)
+            + count_differences(&as_sets(&self.project_tasks), &as_sets(&indices.project_tasks))
+            + count_differences(&self.tasks_by_number, &indices.tasks_by_number);
+
+        self.users_by_email = indices.users_by_email;
+        self.users_by_username = indices.users_by_username;
+        self.user_tasks = indices.user_tasks;
+        self.tasks_by_tag = indices.tasks_by_tag;
+        self.project_tasks = indices.project_tasks;
+        self.tasks_by_number = indices.tasks_by_number;
+        repaired
+    }
+}
+
+/// A full set of secondary indices, built by `StorageData::build_indices`
+struct Indices {
+    users_by_email: HashMap<String, String>,
+    users_by_username: HashMap<String, String>,
+    user_tasks: HashMap<String, Vec<String>>,
+    tasks_by_tag: HashMap<String, HashSet<String>>,
+    project_tasks: HashMap<String, Vec<String>>,
+    tasks_by_number: HashMap<u64, String>,
+}
+
+/// Whether two tasks agree on every field of a natural key.
+pub fn same_natural_key(key: &[NaturalKeyField], a: &Task, b: &Task) -> bool {
+    key.iter().all(|field| match field {
+        NaturalKeyField::Title => a.title == b.title,
+        NaturalKeyField::AssignedTo => a.assigned_to == b.assigned_to,
+        NaturalKeyField::ProjectId => a.project_id == b.project_id,
+        NaturalKeyField::CustomField(name) => a.custom_fields.get(name) == b.custom_fields.get(name),
+    })
+}
+
+/// Emails are looked up case-insensitively.
+fn email_key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Usernames keep their casing on the `User`; only the index key is folded.
+fn username_key(username: &str, fold_case: bool) -> String {
+    if fold_case {
+        username.to_lowercase()
+    } else {
+        username.to_string()
+    }
+}
+
+/// Number of keys whose value differs between two versions of an index.
+fn count_differences<K: Eq + std::hash::Hash, V: PartialEq>(old: &HashMap<K, V>, new: &HashMap<K, V>) -> usize {
+    let changed_or_missing = new.iter().filter(|(key, value)| old.get(*key) != Some(value)).count();
+    let stale = old.keys().filter(|key| !new.contains_key(*key)).count();
+    changed_or_missing + stale
+}
+
+/// On-disk encoding of the storage file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageFormat {
+    Json,
+    MessagePack,
+    /// Compact but positional: files written before a schema change won't load after it
+    Bincode,
+}
+
+impl StorageFormat {
+    /// Pick a format from the file extension, defaulting to JSON.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("msgpack") | Some("mp") => Self::MessagePack,
+            Some("bin") | Some("bincode") => Self::Bincode,
+            _ => Self::Json,
+        }
+    }
+
+    fn serialize<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+        Ok(match self {
+            Self::Json => serde_json::to_vec_pretty(value)?,
+            Self::MessagePack => rmp_serde::to_vec_named(value)?,
+            Self::Bincode => bincode::serialize(value)?,
+        })
+    }
+
+    fn deserialize<T: serde::de::DeserializeOwned>(self, bytes: &[u8]) -> Result<T> {
+        Ok(match self {
+            Self::Json => serde_json::from_slice(bytes)?,
+            Self::MessagePack => rmp_serde::from_slice(bytes)?,
+            Self::Bincode => bincode::deserialize(bytes)?,
+        })
+    }
+}
+
+impl std::str::FromStr for StorageFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "msgpack" | "messagepack" => Ok(Self::MessagePack),
+            "bincode" | "bin" => Ok(Self::Bincode),
+            other => Err(anyhow::anyhow!("unknown storage format: {}", other)),
         }
     }
 }
@@ -34,7 +511,115 @@ impl Default for StorageData {
 pub struct Storage {
     data: Arc<RwLock<StorageData>>,
     persistence_path: Option<String>,
+    format: StorageFormat,
     auto_save: bool,
+    last_saved: Arc<RwLock<Option<SystemTime>>>,
+    /// Serializes saves with each other and with change detection
+    save_lock: Arc<Mutex<()>>,
+    /// Modification time of the storage file as of our last load or save
+    known_mtime: Arc<RwLock<Option<SystemTime>>>,
+    /// Bumped on every mutation so derived caches can tell they're stale
+    generation: Arc<AtomicU64>,
+    analytics_cache: Arc<RwLock<Option<CachedAnalytics>>>,
+    /// Held while this instance may write the storage file
+    lock: Option<Arc<StorageLock>>,
+    events: EventBus,
+    /// Maintenance switch checked by the request layers, not by storage itself
+    read_only: Arc<AtomicBool>,
+    /// Set once the first `load_from_disk` has finished; the request layers
+    /// turn traffic away until then
+    loaded: Arc<watch::Sender<bool>>,
+    /// Encrypts selected user fields in the file; `None` writes plaintext
+    cipher: Option<Arc<FieldCipher>>,
+    due_soon: Arc<DueSoon>,
+    /// Caps concurrent attachment uploads; `None` = unlimited
+    uploads: Option<Arc<Semaphore>>,
+    upload_wait: Duration,
+    /// Index usernames lowercased, so lookups ignore case
+    fold_username_case: bool,
+    /// Seconds a session stays usable past its `expires_at`
+    token_leeway_secs: i64,
+    /// Most live tasks / users the store accepts; 0 = no limit. Checked under
+    /// the write lock so concurrent creates can't overshoot.
+    max_total_tasks: u64,
+    max_total_users: u64,
+}
+
+/// Holds one of the concurrent upload slots until dropped
+#[derive(Debug)]
+pub struct UploadSlot {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+/// OS advisory lock on `<storage>.lock`, held while this process may write
+/// the storage file, so a second writer refuses to start instead of the two
+/// overwriting each other. The OS drops the lock when its holder exits, so a
+/// crash can't leave a stale one behind; the pid written into the file is
+/// only there to name the holder in the error.
+#[derive(Debug)]
+struct StorageLock {
+    file: std::fs::File,
+}
+
+impl StorageLock {
+    fn acquire(storage_path: &Path) -> Result<Self> {
+        use std::io::{Seek, Write};
+
+        let mut path = storage_path.as_os_str().to_owned();
+        path.push(".lock");
+        let path = PathBuf::from(path);
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).context("Failed to create storage directory")?;
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .context("Failed to open lock file")?;
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(std::fs::TryLockError::WouldBlock) => {
+                let holder = std::fs::read_to_string(&path).unwrap_or_default();
+                anyhow::bail!(
+                    "{} is locked by running process {}; another instance is already writing this storage file",
+                    path.display(),
+                    holder.trim()
+                );
+            }
+            Err(std::fs::TryLockError::Error(e)) => return Err(e).context("Failed to lock lock file"),
+        }
+        file.set_len(0).context("Failed to write lock file")?;
+        file.rewind().context("Failed to write lock file")?;
+        write!(file, "{}", std::process::id()).context("Failed to write lock file")?;
+        Ok(Self { file })
+    }
+
+    fn release(&self) {
+        let _ = self.file.unlock();
+    }
+}
+
+impl Drop for StorageLock {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedAnalytics {
+    generation: u64,
+    include_archived: bool,
+    computed_at: SystemTime,
+    analytics: TaskAnalytics,
+}
+
+impl Default for Storage {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Storage {
@@ -42,36 +627,216 @@ impl Storage {
         Self {
             data: Arc::new(RwLock::new(StorageData::default())),
             persistence_path: None,
+            format: StorageFormat::Json,
             auto_save: false,
+            last_saved: Arc::new(RwLock::new(None)),
+            save_lock: Arc::new(Mutex::new(())),
+            known_mtime: Arc::new(RwLock::new(None)),
+            generation: Arc::new(AtomicU64::new(0)),
+            analytics_cache: Arc::new(RwLock::new(None)),
+            lock: None,
+            events: EventBus::new(),
+            read_only: Arc::new(AtomicBool::new(false)),
+            loaded: Arc::new(watch::channel(false).0),
+            cipher: None,
+            due_soon: Arc::default(),
+            uploads: None,
+            upload_wait: Duration::ZERO,
+            fold_username_case: true,
+            token_leeway_secs: 0,
+            max_total_tasks: 0,
+            max_total_users: 0,
         }
     }
 
-    pub fn with_persistence<P: AsRef<Path>>(path: P, auto_save: bool) -> Self {
-        Self {
-            data: Arc::new(RwLock::new(StorageData::default())),
+    /// Storage backed by a file, encoded according to its extension (see
+    /// `StorageFormat::from_path`). Auto-saving instances take the file's lock
+    /// and fail if another live process holds it; read-only instances (such
+    /// as replicas watching the file) don't write and so don't lock.
+    pub fn with_persistence<P: AsRef<Path>>(path: P, auto_save: bool) -> Result<Self> {
+        let lock = if auto_save {
+            Some(Arc::new(StorageLock::acquire(path.as_ref())?))
+        } else {
+            None
+        };
+        Ok(Self {
             persistence_path: Some(path.as_ref().to_string_lossy().to_string()),
+            format: StorageFormat::from_path(&path),
             auto_save,
+            lock,
+            ..Self::new()
+        })
+    }
+
+    /// Release the storage file lock ahead of shutdown; clones may outlive `main`.
+    pub fn release_lock(&self) {
+        if let Some(lock) = &self.lock {
+            lock.release();
+        }
+    }
+
+    /// Build storage from config. Auto-save with nowhere to save to is rejected
+    /// rather than silently dropping every write on restart.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let mut storage = match &config.storage_path {
+            Some(path) => Self::with_persistence(path, config.storage_auto_save)?,
+            None if config.storage_auto_save => {
+                anyhow::bail!(
+                    "auto-save is enabled but no storage path is configured; \
+                     set TASKER_STORAGE_PATH or TASKER_STORAGE_AUTO_SAVE=false"
+                )
+            }
+            None => Self::new(),
+        };
+
+        if let Some(format) = config.storage_format {
+            storage = storage.with_format(format);
+        }
+        if let Some(key) = &config.field_encryption_key {
+            storage.cipher = Some(Arc::new(FieldCipher::new(key, &config.encrypted_fields)?));
+        }
+        storage.events = EventBus::with_history(config.event_history_size);
+        storage.due_soon = Arc::new(DueSoon::from_config(config));
+        storage.uploads = (config.max_concurrent_uploads > 0)
+            .then(|| Arc::new(Semaphore::new(config.max_concurrent_uploads)));
+        storage.upload_wait = Duration::from_secs(config.upload_wait_secs);
+        storage.fold_username_case = config.case_insensitive_usernames;
+        storage.token_leeway_secs = i64::try_from(config.token_leeway_secs).unwrap_or(i64::MAX);
+        storage.max_total_tasks = config.max_total_tasks;
+        storage.max_total_users = config.max_total_users;
+        storage.set_read_only(config.read_only);
+        Ok(storage)
+    }
+
+    fn username_key(&self, username: &str) -> String {
+        username_key(username, self.fold_username_case)
+    }
+
+    /// Fail when `data` already holds `max_total_tasks` live tasks
+    fn check_task_room(&self, data: &StorageData) -> Result<()> {
+        if self.max_total_tasks > 0 && data.tasks.len() as u64 >= self.max_total_tasks {
+            let message = format!("The server already holds the maximum of {} tasks", self.max_total_tasks);
+            return Err(TaskerError::ResourceExhausted(message).into());
+        }
+        Ok(())
+    }
+
+    /// Fail when `data` already holds `max_total_users` users
+    fn check_user_room(&self, data: &StorageData) -> Result<()> {
+        if self.max_total_users > 0 && data.users.len() as u64 >= self.max_total_users {
+            let message = format!("The server already holds the maximum of {} users", self.max_total_users);
+            return Err(TaskerError::ResourceExhausted(message).into());
+        }
+        Ok(())
+    }
+
+    /// Fail when another user already has `email` or `username` (both as
+    /// index keys), checked under the same write lock as the insert so two
+    /// sign-ups can't both claim them
+    fn check_user_keys_free(data: &StorageData, user_id: &str, email: &str, username: &str) -> Result<()> {
+        let taken = |index: &HashMap<String, String>, key: &str| {
+            !key.is_empty() && index.get(key).is_some_and(|owner| owner != user_id)
+        };
+        if taken(&data.users_by_email, email) {
+            return Err(TaskerError::AlreadyExists("User with this email already exists".to_string()).into());
+        }
+        if taken(&data.users_by_username, username) {
+            return Err(TaskerError::AlreadyExists("User with this username already exists".to_string()).into());
+        }
+        Ok(())
+    }
+
+    /// Override the format detected from the persistence path.
+    pub fn with_format(mut self, format: StorageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Serialize `data`, encrypting user fields on a copy when a cipher is set
+    fn encode(&self, data: &StorageData, format: StorageFormat) -> Result<Vec<u8>> {
+        let Some(cipher) = &self.cipher else {
+            return format.serialize(data);
+        };
+        let mut data = data.clone();
+        for (user_id, user) in data.users.iter_mut() {
+            let fields = cipher.encrypt_user(user)?;
+            if !fields.is_empty() {
+                data.encrypted_fields.insert(user_id.clone(), fields);
+            }
+        }
+        format.serialize(&data)
+    }
+
+    /// Deserialize storage data, decrypting user fields and rebuilding indices
+    fn decode(&self, bytes: &[u8], format: StorageFormat) -> Result<StorageData> {
+        let mut data: StorageData = format.deserialize(bytes)?;
+        let encrypted = std::mem::take(&mut data.encrypted_fields);
+        match &self.cipher {
+            Some(cipher) => {
+                for (user_id, fields) in &encrypted {
+                    if let Some(user) = data.users.get_mut(user_id) {
+                        cipher.decrypt_user(user, fields)?;
+                    }
+                }
+            }
+            None if !encrypted.is_empty() => {
+                anyhow::bail!("storage file has encrypted fields but no encryption key is configured");
+            }
+            None => {}
         }
+        data.assign_missing_numbers();
+        // Indices aren't persisted; derive them from the primary maps
+        data.rebuild_indices(self.fold_username_case);
+        if data.users_by_username.len() < data.users.len() {
+            warn!(
+                "{} users share a username with another user; lookups by username find only one of each",
+                data.users.len() - data.users_by_username.len()
+            );
+        }
+        Ok(data)
     }
 
+
     pub async fn load_from_disk(&self) -> Result<()> {
         if let Some(path) = &self.persistence_path {
             if Path::new(path).exists() {
-                let content = fs::read_to_string(path).await
+                let content = fs::read(path).await
                     .context("Failed to read storage file")?;
-                let storage_data: StorageData = serde_json::from_str(&content)
+                let storage_data = self.decode(&content, self.format)
                     .context("Failed to deserialize storage data")?;
-                *self.data.write().await = storage_data;
+                *self.write_data().await = storage_data;
+                *self.known_mtime.write().await = Self::file_mtime(path).await;
+                self.generation.fetch_add(1, Ordering::SeqCst);
                 println!("Loaded data from {}", path);
             }
+            let history_path = event_history_path(path);
+            if Path::new(&history_path).exists() {
+                let content = fs::read(&history_path).await
+                    .context("Failed to read event history")?;
+                let events = serde_json::from_slice(&content)
+                    .context("Failed to deserialize event history")?;
+                self.events.restore_history(events);
+            }
         }
+        self.loaded.send_replace(true);
         Ok(())
     }
 
+    /// Whether the initial load, indices included, has completed
+    pub fn is_loaded(&self) -> bool {
+        *watch::Sender::borrow(&self.loaded)
+    }
+
+    pub async fn wait_until_loaded(&self) {
+        // The sender lives as long as `self`, so this only returns once loaded
+        let _ = self.loaded.subscribe().wait_for(|loaded| *loaded).await;
+    }
+
     pub async fn save_to_disk(&self) -> Result<()> {
         if let Some(path) = &self.persistence_path {
+            let _guard = self.save_lock.lock().await;
             let data = self.data.read().await;
-            let json = serde_json::to_string_pretty(&*data)
+            let bytes = self.encode(&data, self.format)
                 .context("Failed to serialize storage data")?;
             
             // Create parent directories if they don't exist
@@ -82,16 +847,159 @@ impl Storage {
             
             // Write to temporary file first, then rename (atomic operation)
             let temp_path = format!("{}.tmp", path);
-            fs::write(&temp_path, json).await
+            fs::write(&temp_path, bytes).await
                 .context("Failed to write temporary storage file")?;
             fs::rename(&temp_path, path).await
                 .context("Failed to rename temporary storage file")?;
+            *self.last_saved.write().await = Some(SystemTime::now());
+            *self.known_mtime.write().await = Self::file_mtime(path).await;
             
             println!("Saved data to {}", path);
+            Self::write_event_history(path, &self.events).await?;
         }
         Ok(())
     }
 
+    /// Write the retained event history beside the storage file. Saves
+    /// include it, but events are published after the write that caused
+    /// them is saved, so call this on shutdown to keep the latest ones too.
+    pub async fn save_event_history(&self) -> Result<()> {
+        let Some(path) = &self.persistence_path else {
+            return Ok(());
+        };
+        // Saves share one temp file name, so they mustn't overlap
+        let _guard = self.save_lock.lock().await;
+        Self::write_event_history(path, &self.events).await
+    }
+
+    async fn write_event_history(path: &str, events: &EventBus) -> Result<()> {
+        let bytes = serde_json::to_vec(&events.history())
+            .context("Failed to serialize event history")?;
+        let history_path = event_history_path(path);
+        let temp_path = format!("{}.tmp", history_path);
+        fs::write(&temp_path, bytes).await
+            .context("Failed to write temporary event history file")?;
+        fs::rename(&temp_path, &history_path).await
+            .context("Failed to rename temporary event history file")
+    }
+
+    /// Reload whenever another process rewrites the storage file, so a
+    /// read replica sharing the file stays current.
+    pub fn watch_for_changes(&self) -> Result<()> {
+        use notify::{RecursiveMode, Watcher};
+
+        let path = PathBuf::from(self.persistence_path.as_ref()
+            .context("Watching for changes requires a storage path")?);
+        let file_name = path.file_name()
+            .context("Storage path has no file name")?
+            .to_os_string();
+        // Watch the directory: atomic saves replace the file, which would orphan a watch on the file itself
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        std::fs::create_dir_all(&dir).context("Failed to create storage directory")?;
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event {
+                if event.paths.iter().any(|p| p.file_name() == Some(file_name.as_os_str())) {
+                    let _ = tx.send(());
+                }
+            }
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)
+            .context("Failed to watch storage directory")?;
+
+        let storage = self.clone();
+        tokio::spawn(async move {
+            // The watcher stops when dropped, so it lives as long as this task
+            let _watcher = watcher;
+            while rx.recv().await.is_some() {
+                if let Err(e) = storage.reload_if_changed().await {
+                    warn!("Failed to reload changed storage file: {}", e);
+                }
+            }
+        });
+
+        info!("Watching {} for external changes", path.display());
+        Ok(())
+    }
+
+    async fn reload_if_changed(&self) -> Result<()> {
+        let Some(path) = &self.persistence_path else {
+            return Ok(());
+        };
+
+        // Holding the save lock means our own in-flight save has already recorded its mtime
+        let _guard = self.save_lock.lock().await;
+        let Some(mtime) = Self::file_mtime(path).await else {
+            return Ok(());
+        };
+        if *self.known_mtime.read().await == Some(mtime) {
+            return Ok(());
+        }
+
+        if self.auto_save {
+            warn!(
+                "{} was modified by another process while this instance also writes to it; \
+                 concurrent writers will overwrite each other's changes",
+                path
+            );
+        }
+        drop(_guard);
+        self.load_from_disk().await
+    }
+
+    async fn file_mtime(path: &str) -> Option<SystemTime> {
+        fs::metadata(path).await.ok()?.modified().ok()
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.store(read_only, Ordering::SeqCst);
+    }
+
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Claim an upload slot, waiting up to the configured time for one to
+    /// free up; `None` means every slot stayed busy
+    pub async fn upload_slot(&self) -> Option<UploadSlot> {
+        let Some(uploads) = &self.uploads else {
+            return Some(UploadSlot { _permit: None });
+        };
+        if let Ok(permit) = uploads.clone().try_acquire_owned() {
+            return Some(UploadSlot { _permit: Some(permit) });
+        }
+        if self.upload_wait.is_zero() {
+            return None;
+        }
+        match tokio::time::timeout(self.upload_wait, uploads.clone().acquire_owned()).await {
+            Ok(Ok(permit)) => Some(UploadSlot { _permit: Some(permit) }),
+            _ => None,
+        }
+    }
+
+    /// Take the write lock, moving the generation on while holding it, so
+    /// anything computed under a read lock can tell the data has since changed.
+    async fn write_data(&self) -> tokio::sync::RwLockWriteGuard<'_, StorageData> {
+        let data = self.data.write().await;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        data
+    }
+
+    /// Every mutation funnels through here: invalidate derived caches, then persist.
+    async fn after_write(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.auto_save_if_enabled().await;
+    }
+
     async fn auto_save_if_enabled(&self) {
         if self.auto_save {
             if let Err(e) = self.save_to_disk().await {
@@ -103,18 +1011,20 @@ impl Storage {
     // User methods
     pub async fn create_user(&self, user: User) -> Result<()> {
         let user_id = user.id.clone();
-        let email = user.email.clone();
-        let username = user.username.clone();
+        let email = email_key(&user.email);
+        let username = self.username_key(&user.username);
         
         {
-            let mut data = self.data.write().await;
+            let mut data = self.write_data().await;
+            Self::check_user_keys_free(&data, &user_id, &email, &username)?;
+            self.check_user_room(&data)?;
             data.users.insert(user_id.clone(), user);
             data.users_by_email.insert(email, user_id.clone());
             data.users_by_username.insert(username, user_id.clone());
             data.user_tasks.insert(user_id, Vec::new());
         }
         
-        self.auto_save_if_enabled().await;
+        self.after_write().await;
         Ok(())
     }
 
@@ -124,7 +1034,7 @@ impl Storage {
 
     pub async fn get_user_by_email(&self, email: &str) -> Option<User> {
         let data = self.data.read().await;
-        if let Some(user_id) = data.users_by_email.get(email) {
+        if let Some(user_id) = data.users_by_email.get(&email_key(email)) {
             data.users.get(user_id).cloned()
         } else {
             None
@@ -133,7 +1043,7 @@ impl Storage {
 
     pub async fn get_user_by_username(&self, username: &str) -> Option<User> {
         let data = self.data.read().await;
-        if let Some(user_id) = data.users_by_username.get(username) {
+        if let Some(user_id) = data.users_by_username.get(&self.username_key(username)) {
             data.users.get(user_id).cloned()
         } else {
             None
@@ -143,20 +1053,26 @@ impl Storage {
     pub async fn update_user(&self, user: User) -> Result<()> {
         let user_id = user.id.clone();
         {
-            let mut data = self.data.write().await;
-            data.users.insert(user_id, user);
+            let mut data = self.write_data().await;
+            let email = email_key(&user.email);
+            let username = self.username_key(&user.username);
+            Self::check_user_keys_free(&data, &user_id, &email, &username)?;
+            if let Some(old) = data.users.insert(user_id.clone(), user) {
+                data.unindex_user(&user_id, &email_key(&old.email), &self.username_key(&old.username));
+            }
+            data.users_by_email.insert(email, user_id.clone());
+            data.users_by_username.insert(username, user_id);
         }
-        self.auto_save_if_enabled().await;
+        self.after_write().await;
         Ok(())
     }
 
     pub async fn delete_user(&self, user_id: &str) -> Result<bool> {
         let result = {
-            let mut data = self.data.write().await;
+            let mut data = self.write_data().await;
             if let Some(user) = data.users.remove(user_id) {
                 // Clean up related data
-                data.users_by_email.remove(&user.email);
-                data.users_by_username.remove(&user.username);
+                data.unindex_user(user_id, &email_key(&user.email), &self.username_key(&user.username));
                 data.user_tasks.remove(user_id);
                 true
             } else {
@@ -165,127 +1081,629 @@ impl Storage {
         };
         
         if result {
-            self.auto_save_if_enabled().await;
+            self.after_write().await;
         }
         Ok(result)
     }
 
-    pub async fn list_users(&self, page_size: i32, page_token: &str) -> Vec<User> {
-        let data = self.data.read().await;
-        let page_num: usize = page_token.strip_prefix("page_")
-            .and_then(|s| s.parse().ok())
+    /// Delete a user and scrub every trace of them: their comments lose
+    /// their author, they leave watcher lists and project ownership, and
+    /// their saved views and sessions go. Their tasks move to `reassign_to`
+    /// when that's set, otherwise `policy` decides. Soft-deleted, archived
+    /// and the returned deleted tasks are scrubbed the same way. Retained
+    /// events are left to the caller, which publishes the returned tasks
+    /// first and then calls `EventBus::forget_user`.
+    pub async fn purge_user(&self, user_id: &str, policy: UserPurgeTaskPolicy, reassign_to: &str) -> Result<UserPurge> {
+        let purge = {
+            let mut data = self.write_data().await;
+            if !data.users.contains_key(user_id) {
+                return Ok(UserPurge::NotFound);
+            }
+            if !reassign_to.is_empty() && (reassign_to == user_id || !data.users.contains_key(reassign_to)) {
+                return Ok(UserPurge::UnknownReassignee);
+            }
+
+            let scrub = |task: &mut Task| {
+                let mut anonymized = 0;
+                for comment in task.comments.iter_mut().filter(|comment| comment.author_id == user_id) {
+                    comment.author_id.clear();
+                    anonymized += 1;
+                }
+                let watchers = task.watchers.len();
+                task.watchers.retain(|watcher| watcher != user_id);
+                (anonymized, anonymized > 0 || task.watchers.len() != watchers)
+            };
+            let mut anonymized_comments = 0;
+
+            let mut touched = HashSet::new();
+            let mut deleted = Vec::new();
+            for task_id in data.user_tasks.get(user_id).cloned().unwrap_or_default() {
+                if reassign_to.is_empty() && policy == UserPurgeTaskPolicy::Delete {
+                    // Scrubbed too: the caller publishes these snapshots
+                    if let Some(mut task) = data.remove_task(&task_id) {
+                        anonymized_comments += scrub(&mut task).0;
+                        task.assigned_to.clear();
+                        deleted.push(task);
+                    }
+                    continue;
+                }
+                if let Some(task) = data.tasks.get_mut(&task_id) {
+                    task.assigned_to = reassign_to.to_string();
+                    touched.insert(task_id.clone());
+                    data.reindex_assignee(&task_id, user_id, reassign_to);
+                }
+            }
+
+            for task in data.tasks.values_mut() {
+                let (anonymized, changed) = scrub(task);
+                anonymized_comments += anonymized;
+                if changed {
+                    touched.insert(task.id.clone());
+                }
+            }
+            let StorageData { deleted_tasks, archived_tasks, .. } = &mut *data;
+            let kept = deleted_tasks.values_mut().map(|deleted| &mut deleted.task)
+                .chain(archived_tasks.values_mut().map(|archived| &mut archived.task));
+            for task in kept {
+                anonymized_comments += scrub(task).0;
+                if task.assigned_to == user_id {
+                    task.assigned_to.clear();
+                }
+            }
+            for project in data.projects.values_mut().filter(|project| project.owner_id == user_id) {
+                project.owner_id.clear();
+            }
+            data.saved_views.remove(user_id);
+            data.sessions.retain(|_, session| session.user_id != user_id);
+
+            if let Some(user) = data.users.remove(user_id) {
+                data.unindex_user(user_id, &email_key(&user.email), &self.username_key(&user.username));
+            }
+            data.user_tasks.remove(user_id);
+
+            let now = crate::types::SerdeTimestamp::now();
+            let mut updated = Vec::with_capacity(touched.len());
+            for task_id in &touched {
+                if let Some(task) = data.tasks.get_mut(task_id) {
+                    task.updated_at = Some(now.clone());
+                    updated.push(task.clone());
+                }
+            }
+            UserPurge::Purged { updated, deleted, anonymized_comments }
+        };
+
+        self.after_write().await;
+        Ok(purge)
+    }
+
+    /// A page of users ordered by creation time, with the id breaking ties.
+    pub async fn list_users(&self, page_size: i32, page_token: &str, newest_first: bool) -> Vec<User> {
+        let data = self.data.read().await;
+        let page_num: usize = page_token.strip_prefix("page_")
+            .and_then(|s| s.parse().ok())
             .unwrap_or(0);
         
         let start = page_num * page_size as usize;
         
-        data.users.values()
+        let mut users: Vec<&User> = data.users.values().collect();
+        users.sort_by_key(|user| (user.created_at.as_ref().map(|ts| (ts.seconds, ts.nanos)), &user.id));
+        if newest_first {
+            users.reverse();
+        }
+        users.into_iter()
             .skip(start)
             .take(page_size as usize)
             .cloned()
             .collect()
     }
 
-    pub async fn count_users(&self) -> i32 {
-        self.data.read().await.users.len() as i32
+    pub async fn count_users(&self) -> u64 {
+        self.data.read().await.users.len() as u64
     }
 
-    pub async fn count_user_tasks(&self, user_id: &str) -> i32 {
+    pub async fn count_user_tasks(&self, user_id: &str) -> u64 {
         self.data.read().await.user_tasks
             .get(user_id)
-            .map(|tasks| tasks.len() as i32)
+            .map(|tasks| tasks.len() as u64)
             .unwrap_or(0)
     }
 
-    // Task methods
-    pub async fn create_task(&self, task: Task) -> Result<()> {
-        let task_id = task.id.clone();
-        let assignee_id = task.assigned_to.clone();
-        
-        {
-            let mut data = self.data.write().await;
-            data.tasks.insert(task_id.clone(), task);
-            
-            // Add to user's tasks
-            if !assignee_id.is_empty() {
-                data.user_tasks
-                    .entry(assignee_id)
-                    .or_default()
-                    .push(task_id);
+    /// Per-assignee task counts and hours, read off the assignee index. With
+    /// `user_ids`, exactly those users are reported, including any with no
+    /// tasks; otherwise everyone with at least one task is. Busiest first.
+    pub async fn workload(&self, user_ids: &[String]) -> Vec<AssigneeWorkload> {
+        let data = self.data.read().await;
+        let user_ids: Vec<&String> = if user_ids.is_empty() {
+            data.user_tasks.iter()
+                .filter(|(_, task_ids)| !task_ids.is_empty())
+                .map(|(user_id, _)| user_id)
+                .collect()
+        } else {
+            user_ids.iter().collect()
+        };
+
+        let mut workloads: Vec<AssigneeWorkload> = user_ids.into_iter()
+            .map(|user_id| {
+                let mut workload = AssigneeWorkload {
+                    user_id: user_id.clone(),
+                    username: data.users.get(user_id).map(|user| user.username.clone()).unwrap_or_default(),
+                    ..Default::default()
+                };
+                let tasks = data.user_tasks.get(user_id).into_iter().flatten()
+                    .filter_map(|task_id| data.tasks.get(task_id));
+                for task in tasks {
+                    workload.total_tasks += 1;
+                    *workload.tasks_by_status.entry(task.status).or_default() += 1;
+                    if let Some(metrics) = &task.metrics {
+                        workload.estimated_hours += i64::from(metrics.estimated_hours);
+                        workload.actual_hours += i64::from(metrics.actual_hours);
+                    }
+                }
+                workload
+            })
+            .collect();
+        workloads.sort_by(|a, b| b.total_tasks.cmp(&a.total_tasks).then_with(|| a.user_id.cmp(&b.user_id)));
+        workloads.dedup_by(|a, b| a.user_id == b.user_id);
+        workloads
+    }
+
+    /// Tasks created or completed per bucket of `bucket_days` days, from
+    /// `from` through `to` (UTC dates). Every bucket is listed, empty or not.
+    /// Completion is read from `updated_at`, so it moves if a done task is
+    /// edited later.
+    pub async fn task_timeseries(
+        &self,
+        metric: TimeseriesMetric,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        bucket_days: i64,
+    ) -> Vec<TimeseriesPoint> {
+        let buckets = ((to - from).num_days() / bucket_days + 1).max(0) as usize;
+        let mut points: Vec<TimeseriesPoint> = (0..buckets)
+            .map(|index| TimeseriesPoint {
+                date: (from + chrono::Duration::days(index as i64 * bucket_days)).format("%Y-%m-%d").to_string(),
+                count: 0,
+            })
+            .collect();
+
+        let data = self.data.read().await;
+        for task in data.tasks.values() {
+            let at = match metric {
+                TimeseriesMetric::Completed if task.status != TaskStatus::Done as i32 => continue,
+                TimeseriesMetric::Completed => task.updated_at.as_ref(),
+                _ => task.created_at.as_ref(),
+            };
+            let Some(date) = at.and_then(|at| chrono::DateTime::from_timestamp(at.seconds, 0)).map(|at| at.date_naive()) else {
+                continue;
+            };
+            if date < from || date > to {
+                continue;
+            }
+            if let Some(point) = points.get_mut(((date - from).num_days() / bucket_days) as usize) {
+                point.count += 1;
             }
         }
+        points
+    }
+
+    // Task methods
+    pub async fn create_task(&self, task: Task) -> Result<Task> {
+        let task = {
+            let mut data = self.write_data().await;
+            if data.tasks.contains_key(&task.id) {
+                anyhow::bail!("Task {} already exists", task.id);
+            }
+            self.check_task_room(&data)?;
+            data.insert_new_task(task)
+        };
         
-        self.auto_save_if_enabled().await;
-        Ok(())
+        self.after_write().await;
+        Ok(task)
+    }
+
+    /// A live task agreeing with `task` on every `key` field, if there is one
+    pub async fn find_by_natural_key(&self, task: &Task, key: &[NaturalKeyField]) -> Option<Task> {
+        self.data.read().await.find_by_natural_key(task, key).cloned()
+    }
+
+    /// Create `task` unless a live task already shares its natural key, checking
+    /// and inserting under one lock so concurrent upserts can't both create.
+    /// Returns the stored task and whether it was created.
+    pub async fn upsert_task(&self, task: Task, key: &[NaturalKeyField]) -> Result<(Task, bool)> {
+        let task = {
+            let mut data = self.write_data().await;
+            if let Some(existing) = data.find_by_natural_key(&task, key) {
+                return Ok((existing.clone(), false));
+            }
+            if data.tasks.contains_key(&task.id) {
+                anyhow::bail!("Task {} already exists", task.id);
+            }
+            self.check_task_room(&data)?;
+            data.insert_new_task(task)
+        };
+        self.after_write().await;
+        Ok((task, true))
+    }
+
+    pub async fn get_task_by_number(&self, number: u64) -> Option<Task> {
+        let data = self.data.read().await;
+        data.tasks_by_number.get(&number).and_then(|id| data.tasks.get(id)).cloned()
     }
 
     pub async fn get_task(&self, task_id: &str) -> Option<Task> {
         self.data.read().await.tasks.get(task_id).cloned()
     }
 
+    pub async fn find_attachment(&self, attachment_id: &str) -> Option<TaskAttachment> {
+        self.data.read().await.tasks
+            .values()
+            .flat_map(|task| &task.attachments)
+            .find(|attachment| attachment.id == attachment_id)
+            .cloned()
+    }
+
     /// Partially update a task based on the given field mask
     pub async fn patch_task(&self, task_id: &str, patch: Task, mask: &[String]) -> anyhow::Result<()> {
         {
-            let mut data = self.data.write().await;
+            let mut data = self.write_data().await;
 
+            let (old_tags, old_assignee, old_project) = data.tasks.get(task_id)
+                .map(|task| (task.tags.clone(), task.assigned_to.clone(), task.project_id.clone()))
+                .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
             let existing = data.tasks.get_mut(task_id)
                 .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
 
+            let patch_metrics = patch.metrics.clone().unwrap_or_default();
             for field in mask {
                 match field.as_str() {
                     "title"        => existing.title = patch.title.clone(),
                     "description"  => existing.description = patch.description.clone(),
                     "status"       => existing.status = patch.status,
                     "priority"     => existing.priority = patch.priority,
+                    "priority_score" => existing.priority_score = patch.priority_score,
                     "tags"         => existing.tags = patch.tags.clone(),
                     "assignedTo"  => existing.assigned_to = patch.assigned_to.clone(),
                     "due_date"     => existing.due_date = patch.due_date.clone(),
                     "metrics"      => existing.metrics = patch.metrics.clone(),
+                    "metrics.estimated_hours" => {
+                        existing.metrics.get_or_insert_with(Default::default).estimated_hours = patch_metrics.estimated_hours
+                    }
+                    "metrics.actual_hours" => {
+                        existing.metrics.get_or_insert_with(Default::default).actual_hours = patch_metrics.actual_hours
+                    }
+                    "metrics.completion_percentage" => {
+                        existing.metrics.get_or_insert_with(Default::default).completion_percentage =
+                            patch_metrics.completion_percentage
+                    }
                     "comments"     => existing.comments = patch.comments.clone(),
                     "attachments"  => existing.attachments = patch.attachments.clone(),
+                    "project_id"   => existing.project_id = patch.project_id.clone(),
+                    "custom_fields" => existing.custom_fields = patch.custom_fields.clone(),
+                    _ if field.starts_with("custom_fields.") => {
+                        apply_custom_field_mask(&mut existing.custom_fields, &patch.custom_fields, std::slice::from_ref(field))
+                    }
                     _ => {
                         // ignore or return Err(anyhow!("unknown field: {field}"))
                     }
                 }
             }
+
+            let new_tags = existing.tags.clone();
+            let new_assignee = existing.assigned_to.clone();
+            let new_project = existing.project_id.clone();
+            if new_tags != old_tags {
+                data.unindex_tags(task_id, &old_tags);
+                data.index_tags(task_id, &new_tags);
+            }
+            data.reindex_assignee(task_id, &old_assignee, &new_assignee);
+            data.reindex_project(task_id, &old_project, &new_project);
         } 
 
-        self.auto_save_if_enabled().await;
+        self.after_write().await;
         Ok(())
     }
 
 
     pub async fn update_task(&self, task: Task) -> Result<()> {
-        let task_id = task.id.clone();
-        {
-            let mut data = self.data.write().await;
-            data.tasks.insert(task_id, task);
-        }
-        self.auto_save_if_enabled().await;
+        self.write_data().await.replace_task(task);
+        self.after_write().await;
         Ok(())
     }
 
+    /// Read, change and store one task under a single write lock, so
+    /// concurrent changes to the same task can't overwrite each other.
+    /// `change` may refuse by returning an error, and then nothing is
+    /// written. Returns `None` if the task doesn't exist.
+    pub async fn modify_task<E>(
+        &self,
+        task_id: &str,
+        change: impl FnOnce(&mut Task) -> std::result::Result<(), E>,
+    ) -> Option<std::result::Result<Task, E>> {
+        let modified = {
+            let mut data = self.write_data().await;
+            let mut task = data.tasks.get(task_id)?.clone();
+            if let Err(e) = change(&mut task) {
+                return Some(Err(e));
+            }
+            data.replace_task(task.clone());
+            task
+        };
+        self.after_write().await;
+        Some(Ok(modified))
+    }
+
+    /// Hand every task assigned to `from_user` over to `to_user` in one
+    /// write. Returns the reassigned tasks, or `None` if `to_user` doesn't exist.
+    pub async fn reassign_user_tasks(&self, from_user: &str, to_user: &str) -> Result<Option<Vec<Task>>> {
+        let reassigned = {
+            let mut data = self.write_data().await;
+            if !data.users.contains_key(to_user) {
+                return Ok(None);
+            }
+
+            let task_ids = data.user_tasks.get(from_user).cloned().unwrap_or_default();
+            let now = crate::types::SerdeTimestamp::now();
+            let mut reassigned = Vec::with_capacity(task_ids.len());
+            for task_id in task_ids {
+                let Some(task) = data.tasks.get_mut(&task_id) else {
+                    continue;
+                };
+                task.assigned_to = to_user.to_string();
+                task.updated_at = Some(now.clone());
+                reassigned.push(task.clone());
+                data.reindex_assignee(&task_id, from_user, to_user);
+            }
+            reassigned
+        };
+
+        if !reassigned.is_empty() {
+            self.after_write().await;
+        }
+        Ok(Some(reassigned))
+    }
+
     pub async fn delete_task(&self, task_id: &str) -> Result<bool> {
+        let result = self.write_data().await.remove_task(task_id).is_some();
+        
+        if result {
+            self.after_write().await;
+        }
+        Ok(result)
+    }
+
+    /// Move a task out of the active set into the tombstone map.
+    pub async fn soft_delete_task(&self, task_id: &str) -> Result<bool> {
         let result = {
-            let mut data = self.data.write().await;
-            if let Some(task) = data.tasks.remove(task_id) {
-                // Remove from user's tasks
-                if !task.assigned_to.is_empty() {
-                    if let Some(user_tasks) = data.user_tasks.get_mut(&task.assigned_to) {
-                        user_tasks.retain(|id| id != task_id);
-                    }
-                }
+            let mut data = self.write_data().await;
+            if let Some(task) = data.remove_task(task_id) {
+                let deleted_at = unix_now();
+                data.deleted_tasks.insert(task_id.to_string(), DeletedTask { task, deleted_at });
                 true
             } else {
                 false
             }
         };
-        
+
         if result {
-            self.auto_save_if_enabled().await;
+            self.after_write().await;
         }
         Ok(result)
     }
 
+    /// Fold `source_id` into `target_id` in one step: the target gains the
+    /// source's comments, attachments, tags and watchers, keeping its own value
+    /// for every single-valued field, and the source becomes a tombstone.
+    /// Returns the updated target, or `None` if either task doesn't exist.
+    /// Merging a task into itself is an error.
+    pub async fn merge_tasks(&self, source_id: &str, target_id: &str) -> Result<Option<Task>> {
+        if source_id == target_id {
+            anyhow::bail!("Task {} can't be merged into itself", source_id);
+        }
+        let merged = {
+            let mut data = self.write_data().await;
+            if !data.tasks.contains_key(target_id) {
+                return Ok(None);
+            }
+            let Some(source) = data.remove_task(source_id) else {
+                return Ok(None);
+            };
+
+            let target = data.tasks.get_mut(target_id).expect("checked above");
+            let new_tags: Vec<String> = source.tags.iter()
+                .filter(|tag| !target.tags.contains(tag))
+                .cloned()
+                .collect();
+            target.tags.extend(new_tags.iter().cloned());
+            target.comments.extend(source.comments.iter().cloned().map(|mut comment| {
+                comment.task_id = target_id.to_string();
+                comment
+            }));
+            target.comments.sort_by_key(|comment| comment.created_at.as_ref().map(|ts| (ts.seconds, ts.nanos)));
+            target.attachments.extend(source.attachments.iter().cloned());
+            for watcher in &source.watchers {
+                if !target.watchers.contains(watcher) {
+                    target.watchers.push(watcher.clone());
+                }
+            }
+            target.updated_at = Some(crate::types::SerdeTimestamp::now());
+            let merged = target.clone();
+
+            data.index_tags(target_id, &new_tags);
+            data.deleted_tasks.insert(source_id.to_string(), DeletedTask { task: source, deleted_at: unix_now() });
+            merged
+        };
+
+        self.after_write().await;
+        Ok(Some(merged))
+    }
+
+    /// Archive tasks that have been `Done` for at least `max_age_secs`.
+    /// Tasks don't record when they were completed, so their last update
+    /// stands in for it: an edit after completion restarts the clock, which
+    /// errs on the side of keeping a task active. Returns the archived tasks.
+    pub async fn archive_done_tasks(&self, max_age_secs: i64) -> Result<Vec<Task>> {
+        let cutoff = unix_now() - max_age_secs;
+        let archived = {
+            let mut data = self.write_data().await;
+            let expired: Vec<String> = data.tasks.values()
+                .filter(|task| task.status == TaskStatus::Done as i32)
+                .filter(|task| {
+                    task.updated_at.as_ref().or(task.created_at.as_ref())
+                        .is_some_and(|at| at.seconds <= cutoff)
+                })
+                .map(|task| task.id.clone())
+                .collect();
+
+            let archived_at = unix_now();
+            expired.iter()
+                .filter_map(|task_id| data.archive_task(task_id, archived_at))
+                .collect::<Vec<_>>()
+        };
+
+        if !archived.is_empty() {
+            self.after_write().await;
+        }
+        Ok(archived)
+    }
+
+    /// Move one task into the archive; `None` if it isn't an active task.
+    pub async fn archive_task(&self, task_id: &str) -> Option<Task> {
+        let archived = self.write_data().await.archive_task(task_id, unix_now());
+        if archived.is_some() {
+            self.after_write().await;
+        }
+        archived
+    }
+
+    /// Archived tasks, most recently archived first
+    pub async fn list_archived_tasks(&self) -> Vec<Task> {
+        let data = self.data.read().await;
+        let mut archived: Vec<&ArchivedTask> = data.archived_tasks.values().collect();
+        archived.sort_by(|a, b| b.archived_at.cmp(&a.archived_at).then_with(|| a.task.id.cmp(&b.task.id)));
+        archived.into_iter().map(|archived| archived.task.clone()).collect()
+    }
+
+    pub async fn count_archived_tasks(&self) -> u64 {
+        self.data.read().await.archived_tasks.len() as u64
+    }
+
+    /// Archived tasks per status, for analytics that include the archive
+    pub async fn archived_status_counts(&self) -> HashMap<i32, u64> {
+        let mut counts = HashMap::new();
+        for archived in self.data.read().await.archived_tasks.values() {
+            *counts.entry(archived.task.status).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Move a task from the archive back into the active set, keeping its
+    /// number. A project deleted in the meantime is dropped from it.
+    /// `None` if no archived task has that id.
+    pub async fn restore_archived_task(&self, task_id: &str) -> Result<Option<Task>> {
+        let restored = {
+            let mut data = self.write_data().await;
+            if data.tasks.contains_key(task_id) {
+                anyhow::bail!("Task {} is already active", task_id);
+            }
+            if data.archived_tasks.contains_key(task_id) {
+                self.check_task_room(&data)?;
+            }
+            let Some(ArchivedTask { mut task, .. }) = data.archived_tasks.remove(task_id) else {
+                return Ok(None);
+            };
+            if !task.project_id.is_empty() && !data.projects.contains_key(&task.project_id) {
+                task.project_id.clear();
+            }
+            data.insert_task(task)
+        };
+        self.after_write().await;
+        Ok(Some(restored))
+    }
+
+    /// Raise the priority of every open task past its due date by `levels`,
+    /// stopping at `max_priority`. Tasks already at or above the cap are left
+    /// alone, so repeated runs settle instead of escalating forever. Returns
+    /// each escalated task with the priority it had before.
+    pub async fn escalate_overdue_tasks(&self, levels: i32, max_priority: TaskPriority) -> Result<Vec<(Task, TaskPriority)>> {
+        let now = unix_now();
+        let cap = max_priority as i32;
+        let escalated = {
+            let mut data = self.write_data().await;
+            let updated_at = crate::types::SerdeTimestamp::now();
+            let mut escalated = Vec::new();
+            for task in data.tasks.values_mut() {
+                let overdue = task.due_date.as_ref().is_some_and(|due| due.seconds < now);
+                let open = task.status != TaskStatus::Done as i32 && task.status != TaskStatus::Cancelled as i32;
+                if !overdue || !open || task.priority >= cap {
+                    continue;
+                }
+                let previous = task.priority();
+                task.priority = (task.priority + levels).min(cap);
+                task.updated_at = Some(updated_at.clone());
+                escalated.push((task.clone(), previous));
+            }
+            escalated
+        };
+
+        if !escalated.is_empty() {
+            self.after_write().await;
+        }
+        Ok(escalated)
+    }
+
+    /// Mark every reminder that's come due as of `now` (seconds since the
+    /// epoch) as fired, returning each with its task. Reminders on done or
+    /// cancelled tasks, or on tasks without a due date, stay unfired.
+    pub async fn fire_due_reminders(&self, now: i64) -> Result<Vec<(Task, TaskReminder)>> {
+        let fired = {
+            let mut data = self.write_data().await;
+            let fired_at = crate::types::SerdeTimestamp(prost_types::Timestamp { seconds: now, nanos: 0 });
+            let mut fired = Vec::new();
+            for task in data.tasks.values_mut() {
+                let open = task.status != TaskStatus::Done as i32 && task.status != TaskStatus::Cancelled as i32;
+                let Some(due) = task.due_date.as_ref().map(|due| due.seconds).filter(|_| open) else {
+                    continue;
+                };
+                let mut reminders = Vec::new();
+                for reminder in &mut task.reminders {
+                    if reminder.fired_at.is_none() && now >= due - reminder.before_secs {
+                        reminder.fired_at = Some(fired_at.clone());
+                        reminders.push(reminder.clone());
+                    }
+                }
+                fired.extend(reminders.into_iter().map(|reminder| (task.clone(), reminder)));
+            }
+            fired
+        };
+
+        if !fired.is_empty() {
+            self.after_write().await;
+        }
+        Ok(fired)
+    }
+
+    /// Move `parent_id` to Done when it has subtasks, all of them are Done,
+    /// and its workflow allows the move. Returns the parent if it changed.
+    pub async fn complete_parent_if_children_done(&self, parent_id: &str, now: SerdeTimestamp) -> Option<Task> {
+        let completed = {
+            let mut data = self.write_data().await;
+            let mut children = data.tasks.values().filter(|task| task.parent_id == parent_id).peekable();
+            let all_done = children.peek().is_some()
+                && children.all(|task| task.status == TaskStatus::Done as i32);
+            let parent = data.tasks.get_mut(parent_id).filter(|_| all_done)?;
+            let from = TaskStatus::try_from(parent.status).unwrap_or(TaskStatus::Unspecified);
+            if from == TaskStatus::Done || !workflow::can_transition(from, TaskStatus::Done) {
+                return None;
+            }
+            parent.status = TaskStatus::Done as i32;
+            parent.metrics.get_or_insert_with(Default::default).completion_percentage = 100.0;
+            parent.updated_at = Some(now);
+            parent.clone()
+        };
+        self.after_write().await;
+        Some(completed)
+    }
+
     pub async fn list_tasks(&self, page_size: i32, page_token: &str) -> Vec<Task> {
         let data = self.data.read().await;
         let page_num: usize = page_token.strip_prefix("page_")
@@ -301,6 +1719,94 @@ impl Storage {
             .collect()
     }
 
+    /// Return every task matching `filter`, ordered by `sort`. Pagination is
+    /// left to the caller so that the total match count stays available.
+    pub async fn query_tasks(&self, filter: Option<&TaskFilter>, sort: Option<&TaskSort>) -> Vec<Task> {
+        let data = self.data.read().await;
+
+        let mut tasks: Vec<Task> = data.tasks.values()
+            .filter(|task| filter.is_none_or(|f| self.matches_filter(task, f)))
+            .cloned()
+            .collect();
+
+        // Map order differs between runs; start from a fixed one so ties (and
+        // unsorted queries) come back the same way every time
+        tasks.sort_by(|a, b| a.id.cmp(&b.id));
+        if let Some(sort) = sort {
+            Self::sort_tasks(&mut tasks, sort);
+        }
+
+        tasks
+    }
+
+    fn matches_filter(&self, task: &Task, filter: &TaskFilter) -> bool {
+        if !filter.status.is_empty() && !filter.status.contains(&task.status) {
+            return false;
+        }
+        if !filter.priority.is_empty() && !filter.priority.contains(&task.priority) {
+            return false;
+        }
+        // A task must carry every requested tag
+        if !filter.tags.iter().all(|tag| task.tags.contains(tag)) {
+            return false;
+        }
+        if !filter.assigned_to.is_empty() && task.assigned_to != filter.assigned_to {
+            return false;
+        }
+        if filter.unassigned && !task.assigned_to.is_empty() {
+            return false;
+        }
+        if !filter.custom_fields.iter().all(|(key, value)| task.custom_fields.get(key) == Some(value)) {
+            return false;
+        }
+        if let Some(before) = &filter.due_before {
+            match &task.due_date {
+                Some(due) if due.seconds < before.seconds => {}
+                _ => return false,
+            }
+        }
+        if let Some(after) = &filter.due_after {
+            match &task.due_date {
+                Some(due) if due.seconds > after.seconds => {}
+                _ => return false,
+            }
+        }
+        if filter.due_soon && !self.is_due_soon(task, unix_now()) {
+            return false;
+        }
+        if !filter.search_query.is_empty() {
+            let query = search::fold(&filter.search_query);
+            if !search::contains_folded(&task.title, &query)
+                && !search::contains_folded(&task.description, &query)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn sort_tasks(tasks: &mut [Task], sort: &TaskSort) {
+        let seconds = |ts: &Option<crate::types::SerdeTimestamp>| ts.as_ref().map(|t| (t.seconds, t.nanos));
+
+        match TaskSortField::try_from(sort.field).unwrap_or(TaskSortField::Unspecified) {
+            TaskSortField::Unspecified => return,
+            TaskSortField::CreatedAt => tasks.sort_by_key(|t| seconds(&t.created_at)),
+            TaskSortField::UpdatedAt => tasks.sort_by_key(|t| seconds(&t.updated_at)),
+            TaskSortField::DueDate => tasks.sort_by_key(|t| seconds(&t.due_date)),
+            TaskSortField::Priority => tasks.sort_by(|a, b| {
+                let ((a_priority, a_score), (b_priority, b_score)) = (priority_rank(a), priority_rank(b));
+                a_priority.cmp(&b_priority).then(a_score.total_cmp(&b_score))
+            }),
+            TaskSortField::Title => tasks.sort_by_key(|t| t.title.to_lowercase()),
+            TaskSortField::CommentCount => tasks.sort_by_key(|t| t.comments.len()),
+            TaskSortField::AttachmentCount => tasks.sort_by_key(|t| t.attachments.len()),
+        }
+
+        if sort.direction == SortDirection::Desc as i32 {
+            tasks.reverse();
+        }
+    }
+
     pub async fn get_tasks_by_user(&self, user_id: &str, page_size: i32, page_token: &str) -> Vec<Task> {
         let data = self.data.read().await;
         let page_num: usize = page_token.strip_prefix("page_")
@@ -354,27 +1860,27 @@ impl Storage {
             .collect()
     }
 
-    pub async fn count_tasks(&self) -> i32 {
-        self.data.read().await.tasks.len() as i32
+    pub async fn count_tasks(&self) -> u64 {
+        self.data.read().await.tasks.len() as u64
     }
 
-    pub async fn count_tasks_by_status(&self, status: TaskStatus) -> i32 {
+    pub async fn count_tasks_by_status(&self, status: TaskStatus) -> u64 {
         let data = self.data.read().await;
         let status_value = status as i32;
         data.tasks.values()
             .filter(|task| task.status == status_value)
-            .count() as i32
+            .count() as u64
     }
 
-    pub async fn count_tasks_by_priority(&self, priority: TaskPriority) -> i32 {
+    pub async fn count_tasks_by_priority(&self, priority: TaskPriority) -> u64 {
         let data = self.data.read().await;
         let priority_value = priority as i32;
         data.tasks.values()
             .filter(|task| task.priority == priority_value)
-            .count() as i32
+            .count() as u64
     }
 
-    pub async fn count_overdue_tasks(&self) -> i32 {
+    pub async fn count_overdue_tasks(&self) -> u64 {
         let data = self.data.read().await;
         let now = std::time::SystemTime::now();
         let now_timestamp = now.duration_since(std::time::SystemTime::UNIX_EPOCH)
@@ -389,7 +1895,20 @@ impl Storage {
                     false
                 }
             })
-            .count() as i32
+            .count() as u64
+    }
+
+    pub async fn count_due_soon_tasks(&self) -> u64 {
+        let data = self.data.read().await;
+        let now = unix_now();
+        data.tasks.values()
+            .filter(|task| self.is_due_soon(task, now))
+            .count() as u64
+    }
+
+    fn is_due_soon(&self, task: &Task, now: i64) -> bool {
+        let open = task.status != TaskStatus::Done as i32 && task.status != TaskStatus::Cancelled as i32;
+        open && task.due_date.as_ref().is_some_and(|due| self.due_soon.is_due_soon(due.seconds, now))
     }
 
     // Search methods
@@ -400,12 +1919,12 @@ impl Storage {
             .unwrap_or(0);
         
         let start = page_num * page_size as usize;
-        let query_lower = query.to_lowercase();
+        let query = search::fold(query);
         
         data.tasks.values()
             .filter(|task| {
-                task.title.to_lowercase().contains(&query_lower) ||
-                task.description.to_lowercase().contains(&query_lower)
+                search::contains_folded(&task.title, &query) ||
+                search::contains_folded(&task.description, &query)
             })
             .skip(start)
             .take(page_size as usize)
@@ -413,6 +1932,31 @@ impl Storage {
             .collect()
     }
 
+    /// Typo-tolerant variant of `search_tasks`, closest matches first.
+    pub async fn fuzzy_search_tasks(&self, query: &str, max_distance: usize, page_size: i32, page_token: &str) -> Vec<Task> {
+        let data = self.data.read().await;
+        let page_num: usize = page_token.strip_prefix("page_")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        
+        let start = page_num * page_size as usize;
+
+        let mut scored: Vec<(usize, &Task)> = data.tasks.values()
+            .filter_map(|task| {
+                let title = search::fuzzy_distance(query, &task.title, max_distance);
+                let description = search::fuzzy_distance(query, &task.description, max_distance);
+                title.into_iter().chain(description).min().map(|distance| (distance, task))
+            })
+            .collect();
+        scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.title.cmp(&b.1.title)));
+
+        scored.into_iter()
+            .skip(start)
+            .take(page_size as usize)
+            .map(|(_, task)| task.clone())
+            .collect()
+    }
+
     pub async fn search_users(&self, query: &str, page_size: i32, page_token: &str) -> Vec<User> {
         let data = self.data.read().await;
         let page_num: usize = page_token.strip_prefix("page_")
@@ -420,13 +1964,13 @@ impl Storage {
             .unwrap_or(0);
         
         let start = page_num * page_size as usize;
-        let query_lower = query.to_lowercase();
+        let query = search::fold(query);
         
         data.users.values()
             .filter(|user| {
-                user.username.to_lowercase().contains(&query_lower) ||
-                user.email.to_lowercase().contains(&query_lower) ||
-                user.full_name.to_lowercase().contains(&query_lower)
+                search::contains_folded(&user.username, &query) ||
+                search::contains_folded(&user.email, &query) ||
+                search::contains_folded(&user.full_name, &query)
             })
             .skip(start)
             .take(page_size as usize)
@@ -434,44 +1978,466 @@ impl Storage {
             .collect()
     }
 
-    // Batch operations for better performance
-    pub async fn batch_create_tasks(&self, tasks: Vec<Task>) -> Result<()> {
+    // Session methods
+
+    /// Record a session under its token's hash, dropping any that have expired.
+    pub async fn create_session(&self, token: &str, session: Session) -> Result<()> {
         {
+            let mut data = self.write_data().await;
+            let now = unix_now();
+            data.sessions.retain(|_, session| self.session_live(session, now));
+            data.sessions.insert(session_key(token), session);
+        }
+        self.after_write().await;
+        Ok(())
+    }
+
+    /// The live session for `token`; an expired one is removed on the spot.
+    pub async fn get_session(&self, token: &str) -> Option<Session> {
+        let key = session_key(token);
+        let session = self.data.read().await.sessions.get(&key).cloned()?;
+        if self.session_live(&session, unix_now()) {
+            return Some(session);
+        }
+        let removed = self.write_data().await.sessions
+            .remove_entry(&key)
+            .is_some_and(|(_, current)| !self.session_live(&current, unix_now()));
+        if removed {
+            self.after_write().await;
+        }
+        None
+    }
+
+    /// Whether `session` is still usable at `now` (Unix seconds), allowing
+    /// for the configured leeway past expiry
+    pub fn session_live(&self, session: &Session, now: i64) -> bool {
+        session.expires_at.saturating_add(self.token_leeway_secs) > now
+    }
+
+    pub async fn revoke_session(&self, token: &str) -> Result<bool> {
+        let removed = self.write_data().await.sessions.remove(&session_key(token)).is_some();
+        if removed {
+            self.after_write().await;
+        }
+        Ok(removed)
+    }
+
+    // Derived caches
+
+    /// Current data generation; read it *before* computing anything you intend to cache.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// The cached analytics snapshot, if it covers the same tasks, no mutation
+    /// happened since it was computed and it is younger than `max_age`.
+    pub async fn cached_analytics(&self, include_archived: bool, max_age: Duration) -> Option<TaskAnalytics> {
+        let cache = self.analytics_cache.read().await;
+        cache.as_ref()
+            .filter(|cached| cached.include_archived == include_archived)
+            .filter(|cached| cached.generation == self.generation())
+            .filter(|cached| cached.computed_at.elapsed().unwrap_or(Duration::MAX) < max_age)
+            .map(|cached| cached.analytics.clone())
+    }
+
+    pub async fn cache_analytics(&self, generation: u64, include_archived: bool, analytics: TaskAnalytics) {
+        *self.analytics_cache.write().await = Some(CachedAnalytics {
+            generation,
+            include_archived,
+            computed_at: SystemTime::now(),
+            analytics,
+        });
+    }
+
+    // Maintenance
+
+    /// Rebuild all secondary indices, returning how many entries were wrong.
+    pub async fn verify_and_repair(&self) -> Result<usize> {
+        let repaired = self.write_data().await.rebuild_indices(self.fold_username_case);
+        if repaired > 0 {
+            self.after_write().await;
+        }
+        Ok(repaired)
+    }
+
+    /// Rebuild the email, username, assignee, tag and project indices from the
+    /// task and user maps. The new indices are built under the read lock, so
+    /// other reads carry on, and only swapped in under the write lock. If a
+    /// write landed in between they are rebuilt; after a few such misses the
+    /// rebuild happens under the write lock instead.
+    pub async fn reindex(&self) -> Result<ReindexReport> {
+        const ATTEMPTS: usize = 3;
+        let started = std::time::Instant::now();
+        let mut attempt = 0;
+        let report = loop {
+            attempt += 1;
+            let built = if attempt <= ATTEMPTS {
+                let data = self.data.read().await;
+                Some((self.generation(), data.build_indices(self.fold_username_case)))
+            } else {
+                None
+            };
+            // Not `write_data`: swapping in equivalent indices changes nothing
             let mut data = self.data.write().await;
+            let indices = match built {
+                Some((generation, indices)) if generation == self.generation() => indices,
+                Some(_) => continue,
+                None => data.build_indices(self.fold_username_case),
+            };
+            break ReindexReport {
+                repaired_entries: data.swap_indices(indices),
+                tasks_indexed: data.tasks.len(),
+                users_indexed: data.users.len(),
+                duration_ms: 0,
+            };
+        };
+        if report.repaired_entries > 0 {
+            self.after_write().await;
+        }
+        Ok(ReindexReport { duration_ms: started.elapsed().as_millis() as u64, ..report })
+    }
+
+    /// Purge tombstones older than `tombstone_max_age_secs`, drop index entries
+    /// and sessions that no longer point anywhere, then rewrite the file once
+    /// and delete the attachment files nothing refers to any more.
+    pub async fn compact(&self, tombstone_max_age_secs: i64, attachments: &AttachmentStore) -> Result<CompactionReport> {
+        let bytes_before = self.storage_file_size().await;
+        let now = unix_now();
+
+        let (mut report, referenced) = {
+            let mut data = self.write_data().await;
+            let data = &mut *data;
+            let mut report = CompactionReport::default();
+
+            let tombstones_before = data.deleted_tasks.len();
+            data.deleted_tasks.retain(|_, deleted| now - deleted.deleted_at < tombstone_max_age_secs);
+            report.purged_tombstones = tombstones_before - data.deleted_tasks.len();
+
+            for (user_id, task_ids) in data.user_tasks.iter_mut() {
+                let before = task_ids.len();
+                task_ids.retain(|id| data.tasks.get(id).is_some_and(|task| &task.assigned_to == user_id));
+                report.dangling_user_task_entries += before - task_ids.len();
+            }
+            let users = &data.users;
+            data.user_tasks.retain(|user_id, task_ids| !task_ids.is_empty() || users.contains_key(user_id));
+
+            let sessions_before = data.sessions.len();
+            data.sessions.retain(|_, session| self.session_live(session, now));
+            report.expired_sessions = sessions_before - data.sessions.len();
+
+            let referenced: HashSet<String> = data.tasks.values()
+                .chain(data.deleted_tasks.values().map(|deleted| &deleted.task))
+                .chain(data.archived_tasks.values().map(|archived| &archived.task))
+                .flat_map(|task| &task.attachments)
+                .map(|attachment| attachment.id.clone())
+                .collect();
+            (report, referenced)
+        };
+
+        self.save_to_disk().await?;
+        // Only once the file no longer refers to purged tasks' attachments
+        (report.orphaned_attachments, report.attachment_bytes_reclaimed) = attachments.remove_orphans(&referenced).await?;
+
+        report.bytes_before = bytes_before;
+        report.bytes_after = self.storage_file_size().await;
+        Ok(report)
+    }
+
+    async fn storage_file_size(&self) -> Option<u64> {
+        match &self.persistence_path {
+            Some(path) => fs::metadata(path).await.ok().map(|meta| meta.len()),
+            None => None,
+        }
+    }
+
+    // Project methods
+    pub async fn create_project(&self, project: Project) -> Result<()> {
+        {
+            let mut data = self.write_data().await;
+            data.project_tasks.insert(project.id.clone(), Vec::new());
+            data.projects.insert(project.id.clone(), project);
+        }
+        self.after_write().await;
+        Ok(())
+    }
+
+    pub async fn get_project(&self, project_id: &str) -> Option<Project> {
+        self.data.read().await.projects.get(project_id).cloned()
+    }
+
+    pub async fn update_project(&self, project: Project) -> Result<()> {
+        self.write_data().await.projects.insert(project.id.clone(), project);
+        self.after_write().await;
+        Ok(())
+    }
+
+    /// Every project, ordered by name so pages are stable.
+    pub async fn list_projects(&self) -> Vec<Project> {
+        let mut projects: Vec<Project> = self.data.read().await.projects.values().cloned().collect();
+        projects.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        projects
+    }
+
+    /// The project's tasks in the order they joined it.
+    pub async fn get_project_tasks(&self, project_id: &str) -> Vec<Task> {
+        let data = self.data.read().await;
+        data.project_tasks.get(project_id)
+            .map(|ids| ids.iter().filter_map(|id| data.tasks.get(id).cloned()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Delete a project, dealing with its remaining tasks according to `policy`
+    /// in the same write.
+    pub async fn delete_project(&self, project_id: &str, policy: ProjectDeletePolicy) -> Result<ProjectDeletion> {
+        let tasks = {
+            let mut data = self.write_data().await;
+            if !data.projects.contains_key(project_id) {
+                return Ok(ProjectDeletion::NotFound);
+            }
+
+            let task_ids = data.project_tasks.get(project_id).cloned().unwrap_or_default();
+            if policy == ProjectDeletePolicy::Block && !task_ids.is_empty() {
+                return Ok(ProjectDeletion::Blocked { task_count: task_ids.len() });
+            }
+
+            let now = crate::types::SerdeTimestamp::now();
+            let deleted_at = unix_now();
+            let mut tasks = Vec::with_capacity(task_ids.len());
+            for task_id in task_ids {
+                match policy {
+                    ProjectDeletePolicy::Cascade => {
+                        if let Some(task) = data.remove_task(&task_id) {
+                            tasks.push(task.clone());
+                            data.deleted_tasks.insert(task_id, DeletedTask { task, deleted_at });
+                        }
+                    }
+                    _ => {
+                        if let Some(task) = data.tasks.get_mut(&task_id) {
+                            task.project_id.clear();
+                            task.updated_at = Some(now.clone());
+                            tasks.push(task.clone());
+                        }
+                    }
+                }
+            }
+
+            data.projects.remove(project_id);
+            data.project_tasks.remove(project_id);
+            tasks
+        };
+
+        self.after_write().await;
+        Ok(ProjectDeletion::Deleted { tasks })
+    }
+
+    // Statistics
+    pub async fn stats(&self) -> StorageStats {
+        let (task_count, user_count, distinct_tags, attachment_count, attachment_bytes) = {
+            let data = self.data.read().await;
+            let attachments = data.tasks.values().flat_map(|task| &task.attachments);
+            let (count, bytes) = attachments.fold((0, 0), |(count, bytes), a| (count + 1, bytes + a.file_size));
+            (data.tasks.len(), data.users.len(), data.tasks_by_tag.len(), count, bytes)
+        };
+
+        let storage_file_bytes = self.storage_file_size().await;
+        let last_saved_at = self.last_saved.read().await
+            .map(|time| chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339());
+
+        StorageStats {
+            task_count,
+            user_count,
+            distinct_tags,
+            attachment_count,
+            attachment_bytes,
+            storage_file_bytes,
+            last_saved_at,
+            streams: self.events.stream_stats(),
+        }
+    }
+
+    // Tag methods
+
+    /// Distinct tags starting with `prefix` and the number of tasks using each,
+    /// most used first. Reads straight from the tag index.
+    pub async fn list_tags(&self, prefix: &str) -> Vec<(String, usize)> {
+        let data = self.data.read().await;
+        let mut tags: Vec<(String, usize)> = data.tasks_by_tag.iter()
+            .filter(|(tag, _)| tag.starts_with(prefix))
+            .map(|(tag, ids)| (tag.clone(), ids.len()))
+            .collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tags
+    }
+
+    /// Rename `from` to `to` on every task carrying it, merging when a task
+    /// already has `to`. Returns the number of tasks touched.
+    pub async fn rename_tag(&self, from: &str, to: &str) -> Result<usize> {
+        let renamed = {
+            let mut data = self.write_data().await;
+            let Some(task_ids) = data.tasks_by_tag.remove(from) else {
+                return Ok(0);
+            };
+
+            let now = crate::types::SerdeTimestamp::now();
+            for task_id in &task_ids {
+                if let Some(task) = data.tasks.get_mut(task_id) {
+                    if task.tags.iter().any(|tag| tag == to) {
+                        task.tags.retain(|tag| tag != from);
+                    } else {
+                        for tag in task.tags.iter_mut().filter(|tag| *tag == from) {
+                            *tag = to.to_string();
+                        }
+                    }
+                    task.updated_at = Some(now.clone());
+                }
+            }
+
+            let renamed = task_ids.len();
+            data.tasks_by_tag
+                .entry(to.to_string())
+                .or_default()
+                .extend(task_ids);
+            renamed
+        };
+
+        if renamed > 0 {
+            self.after_write().await;
+        }
+        Ok(renamed)
+    }
+
+    // Saved view methods
+    pub async fn create_saved_view(&self, view: SavedView) -> Result<()> {
+        {
+            let mut data = self.write_data().await;
+            data.saved_views
+                .entry(view.user_id.clone())
+                .or_default()
+                .push(view);
+        }
+        self.after_write().await;
+        Ok(())
+    }
+
+    pub async fn list_saved_views(&self, user_id: &str) -> Vec<SavedView> {
+        self.data.read().await.saved_views
+            .get(user_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Gather a user's record and everything tied to them; `None` if there's no such user
+    pub async fn export_user(&self, user_id: &str) -> Option<UserExport> {
+        let data = self.data.read().await;
+        let user = data.users.get(user_id)?.clone();
+        let tasks = data.user_tasks.get(user_id).into_iter().flatten()
+            .filter_map(|task_id| data.tasks.get(task_id).cloned())
+            .collect();
+        let archived = data.archived_tasks.values().map(|archived| &archived.task);
+        let deleted = data.deleted_tasks.values().map(|deleted| &deleted.task);
+        let assigned = |task: &&Task| task.assigned_to == user_id;
+        let archived_tasks = archived.clone().filter(assigned).cloned().collect();
+        let deleted_tasks = deleted.clone().filter(assigned).cloned().collect();
+        let comments = data.tasks.values()
+            .chain(archived)
+            .chain(deleted)
+            .flat_map(|task| &task.comments)
+            .filter(|comment| comment.author_id == user_id)
+            .cloned()
+            .collect();
+        Some(UserExport {
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            user,
+            tasks,
+            archived_tasks,
+            deleted_tasks,
+            comments,
+            saved_views: data.saved_views.get(user_id).cloned().unwrap_or_default(),
+            activity: self.events.history_by_actor(user_id),
+        })
+    }
+
+    pub async fn get_saved_view(&self, user_id: &str, view_id: &str) -> Option<SavedView> {
+        self.data.read().await.saved_views
+            .get(user_id)
+            .and_then(|views| views.iter().find(|view| view.id == view_id).cloned())
+    }
+
+    // Batch operations for better performance
+    /// Create several tasks in one write. A task whose id repeats an earlier
+    /// one in the batch or belongs to a stored task is skipped rather than
+    /// overwriting it. Returns what happened to each task, in order.
+    pub async fn batch_create_tasks(&self, tasks: Vec<Task>) -> Result<Vec<BatchOutcome>> {
+        let mut outcomes = Vec::with_capacity(tasks.len());
+        {
+            let mut data = self.write_data().await;
+            let mut seen = HashSet::new();
             for task in tasks {
                 let task_id = task.id.clone();
-                let assignee_id = task.assigned_to.clone();
-                
-                data.tasks.insert(task_id.clone(), task);
-                
-                if !assignee_id.is_empty() {
-                    data.user_tasks
-                        .entry(assignee_id)
-                        .or_default()
-                        .push(task_id);
+                if !seen.insert(task_id.clone()) {
+                    outcomes.push(BatchOutcome::Duplicate);
+                    continue;
+                }
+                if data.tasks.contains_key(&task_id) {
+                    outcomes.push(BatchOutcome::Taken);
+                    continue;
+                }
+                if self.check_task_room(&data).is_err() {
+                    outcomes.push(BatchOutcome::LimitReached);
+                    continue;
                 }
+                
+                data.insert_new_task(task);
+                outcomes.push(BatchOutcome::Created);
             }
         }
-        self.auto_save_if_enabled().await;
-        Ok(())
+        if outcomes.contains(&BatchOutcome::Created) {
+            self.after_write().await;
+        }
+        Ok(outcomes)
     }
 
-    pub async fn batch_create_users(&self, users: Vec<User>) -> Result<()> {
+    /// Create several users in one write, skipping any whose id, email or
+    /// username repeats an earlier user in the batch or a stored one, and any
+    /// past `max_total_users`. Returns what happened to each user.
+    pub async fn batch_create_users(&self, users: Vec<User>) -> Result<Vec<BatchOutcome>> {
+        let mut outcomes = Vec::with_capacity(users.len());
         {
-            let mut data = self.data.write().await;
+            let mut data = self.write_data().await;
+            let mut seen = HashSet::new();
             for user in users {
                 let user_id = user.id.clone();
-                let email = user.email.clone();
-                let username = user.username.clone();
+                let email = email_key(&user.email);
+                let username = self.username_key(&user.username);
+                let keys = [format!("id:{}", user_id), format!("email:{}", email), format!("username:{}", username)];
+                if keys.iter().any(|key| seen.contains(key)) {
+                    outcomes.push(BatchOutcome::Duplicate);
+                    continue;
+                }
+                seen.extend(keys);
+                if data.users.contains_key(&user_id)
+                    || data.users_by_email.contains_key(&email)
+                    || data.users_by_username.contains_key(&username)
+                {
+                    outcomes.push(BatchOutcome::Taken);
+                    continue;
+                }
+                if self.check_user_room(&data).is_err() {
+                    outcomes.push(BatchOutcome::LimitReached);
+                    continue;
+                }
                 
                 data.users.insert(user_id.clone(), user);
                 data.users_by_email.insert(email, user_id.clone());
                 data.users_by_username.insert(username, user_id.clone());
                 data.user_tasks.insert(user_id, Vec::new());
+                outcomes.push(BatchOutcome::Created);
             }
         }
-        self.auto_save_if_enabled().await;
-        Ok(())
+        if outcomes.contains(&BatchOutcome::Created) {
+            self.after_write().await;
+        }
+        Ok(outcomes)
     }
 
     // Manual save/load operations
@@ -483,10 +2449,10 @@ impl Storage {
         self.load_from_disk().await
     }
 
-    // Backup functionality
+    // Backup functionality; the backup's format follows its own extension
     pub async fn backup_to<P: AsRef<Path>>(&self, backup_path: P) -> Result<()> {
         let data = self.data.read().await;
-        let json = serde_json::to_string_pretty(&*data)
+        let bytes = self.encode(&data, StorageFormat::from_path(&backup_path))
             .context("Failed to serialize storage data for backup")?;
         
         let path = backup_path.as_ref();
@@ -495,7 +2461,7 @@ impl Storage {
                 .context("Failed to create backup directory")?;
         }
         
-        fs::write(path, json).await
+        fs::write(path, bytes).await
             .context("Failed to write backup file")?;
         
         println!("Backup saved to {}", path.display());
@@ -503,25 +2469,69 @@ impl Storage {
     }
 
     pub async fn restore_from<P: AsRef<Path>>(&self, backup_path: P) -> Result<()> {
-        let content = fs::read_to_string(backup_path).await
+        let format = StorageFormat::from_path(&backup_path);
+        let content = fs::read(backup_path).await
             .context("Failed to read backup file")?;
-        let storage_data: StorageData = serde_json::from_str(&content)
+        let storage_data = self.decode(&content, format)
             .context("Failed to deserialize backup data")?;
         
-        *self.data.write().await = storage_data;
-        self.auto_save_if_enabled().await;
+        *self.write_data().await = storage_data;
+        self.after_write().await;
         
         println!("Data restored from backup");
         Ok(())
     }
 }
 
+/// Apply the custom field paths of an update mask to `fields`:
+/// `custom_fields` replaces the whole map, `custom_fields.<key>` sets one
+/// key from `patch`, and a key that's missing or empty in `patch` is removed.
+pub fn apply_custom_field_mask(fields: &mut HashMap<String, String>, patch: &HashMap<String, String>, mask: &[String]) {
+    for path in mask {
+        if path == "custom_fields" {
+            *fields = patch.clone();
+        } else if let Some(key) = path.strip_prefix("custom_fields.") {
+            match patch.get(key).filter(|value| !value.is_empty()) {
+                Some(value) => {
+                    fields.insert(key.to_string(), value.clone());
+                }
+                None => {
+                    fields.remove(key);
+                }
+            }
+        }
+    }
+}
+
+/// The event history lives in its own JSON file next to the storage file,
+/// whatever format that uses
+fn event_history_path(path: &str) -> String {
+    format!("{}.events.json", path)
+}
+
+/// Sessions are keyed by the SHA-256 of their token, so the storage file
+/// never holds a bearer token anyone could replay.
+fn session_key(token: &str) -> String {
+    ring::digest::digest(&ring::digest::SHA256, token.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
 // Example usage in main.rs or lib.rs:
 /*
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create storage with persistence
-    let storage = Storage::with_persistence("data/storage.json", true);
+    let storage = Storage::with_persistence("data/storage.json", true)?;
     
     // Load existing data on startup
     storage.load_from_disk().await?;
@@ -533,4 +2543,710 @@ async fn main() -> Result<()> {
     
     Ok(())
 }
-*/
\ No newline at end of file
+*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protogen::{TaskEventType, TaskMetrics};
+
+    const TEST_KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    fn user(id: &str, email: &str, full_name: &str) -> User {
+        User {
+            id: id.to_string(),
+            username: id.to_string(),
+            email: email.to_string(),
+            full_name: full_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn with_cipher(fields: &[EncryptedField]) -> Storage {
+        let mut storage = Storage::new();
+        storage.cipher = Some(Arc::new(FieldCipher::new(TEST_KEY, fields).unwrap()));
+        storage
+    }
+
+    #[tokio::test]
+    async fn reminder_fires_once_at_the_right_time() {
+        let storage = Storage::new();
+        let due = 1_000_000;
+        let reminder = TaskReminder { id: "r1".to_string(), user_id: "u1".to_string(), before_secs: 600, fired_at: None };
+        storage.create_task(Task {
+            id: "t1".to_string(),
+            status: TaskStatus::Todo as i32,
+            due_date: Some(SerdeTimestamp(prost_types::Timestamp { seconds: due, nanos: 0 })),
+            reminders: vec![reminder],
+            ..Default::default()
+        }).await.unwrap();
+
+        assert!(storage.fire_due_reminders(due - 601).await.unwrap().is_empty());
+        let fired = storage.fire_due_reminders(due - 600).await.unwrap();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].1.id, "r1");
+        assert_eq!(fired[0].1.fired_at.as_ref().map(|at| at.seconds), Some(due - 600));
+        assert!(storage.fire_due_reminders(due - 599).await.unwrap().is_empty());
+        assert!(storage.fire_due_reminders(due + 3600).await.unwrap().is_empty());
+    }
+
+    fn temp_storage_path() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tasker-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("storage.json")
+    }
+
+    #[test]
+    fn lock_refuses_a_second_writer_until_released() {
+        let path = temp_storage_path();
+        let first = StorageLock::acquire(&path).unwrap();
+        let err = StorageLock::acquire(&path).unwrap_err().to_string();
+        assert!(err.contains(&std::process::id().to_string()), "{}", err);
+
+        drop(first);
+        StorageLock::acquire(&path).unwrap();
+    }
+
+    #[test]
+    fn leftover_lock_file_is_not_a_lock() {
+        // A crashed holder leaves its pid behind; whatever the pid now belongs
+        // to (pid 1 in a container, or this very process), nobody holds the lock
+        let path = temp_storage_path();
+        let lock_path = path.with_extension("json.lock");
+        for pid in ["1".to_string(), std::process::id().to_string()] {
+            std::fs::write(&lock_path, &pid).unwrap();
+            let lock = StorageLock::acquire(&path).unwrap();
+            assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), std::process::id().to_string());
+            drop(lock);
+        }
+    }
+
+    #[test]
+    fn encrypted_round_trip_keeps_values_that_look_like_ciphertext() {
+        let storage = with_cipher(&EncryptedField::ALL);
+        let mut data = StorageData::default();
+        data.users.insert("u1".to_string(), user("u1", "enc:v1:x@example.com", "enc:v1:AAAA"));
+        data.users.insert("u2".to_string(), user("u2", "plain@example.com", ""));
+
+        let bytes = storage.encode(&data, StorageFormat::Json).unwrap();
+        let text = String::from_utf8(bytes.clone()).unwrap();
+        assert!(!text.contains("enc:v1:AAAA"), "full_name was written in plaintext");
+        assert!(!text.contains("plain@example.com"), "email was written in plaintext");
+
+        let decoded = storage.decode(&bytes, StorageFormat::Json).unwrap();
+        assert_eq!(decoded.users["u1"].email, "enc:v1:x@example.com");
+        assert_eq!(decoded.users["u1"].full_name, "enc:v1:AAAA");
+        assert_eq!(decoded.users["u2"].email, "plain@example.com");
+        assert!(decoded.encrypted_fields.is_empty());
+    }
+
+    #[test]
+    fn plaintext_file_with_prefixed_values_loads_without_a_key() {
+        let storage = Storage::new();
+        let mut data = StorageData::default();
+        data.users.insert("u1".to_string(), user("u1", "a@example.com", "enc:v1:not-really"));
+
+        let bytes = storage.encode(&data, StorageFormat::Json).unwrap();
+        let decoded = storage.decode(&bytes, StorageFormat::Json).unwrap();
+        assert_eq!(decoded.users["u1"].full_name, "enc:v1:not-really");
+
+        // A keyed server reading the same file leaves the value alone too
+        let decoded = with_cipher(&EncryptedField::ALL).decode(&bytes, StorageFormat::Json).unwrap();
+        assert_eq!(decoded.users["u1"].full_name, "enc:v1:not-really");
+    }
+
+    #[test]
+    fn encrypted_file_needs_a_key() {
+        let mut data = StorageData::default();
+        data.users.insert("u1".to_string(), user("u1", "a@example.com", "Ann"));
+        let bytes = with_cipher(&[EncryptedField::Email]).encode(&data, StorageFormat::Json).unwrap();
+
+        assert!(Storage::new().decode(&bytes, StorageFormat::Json).is_err());
+    }
+
+    #[tokio::test]
+    async fn export_includes_archived_and_deleted_tasks() {
+        let storage = Storage::new();
+        let user = User { id: "u1".to_string(), username: "ann".to_string(), email: "ann@example.com".to_string(), ..Default::default() };
+        storage.create_user(user).await.unwrap();
+        for id in ["active", "archived", "deleted"] {
+            let comment = TaskComment { id: format!("c-{}", id), author_id: "u1".to_string(), ..Default::default() };
+            let task = Task { id: id.to_string(), assigned_to: "u1".to_string(), comments: vec![comment], ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+        storage.archive_task("archived").await.unwrap();
+        storage.soft_delete_task("deleted").await.unwrap();
+
+        let export = storage.export_user("u1").await.unwrap();
+        assert_eq!(export.tasks.iter().map(|task| task.id.as_str()).collect::<Vec<_>>(), ["active"]);
+        assert_eq!(export.archived_tasks[0].id, "archived");
+        assert_eq!(export.deleted_tasks[0].id, "deleted");
+        assert_eq!(export.comments.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn event_history_survives_a_restart() {
+        let path = temp_storage_path();
+        let storage = Storage::with_persistence(&path, false).unwrap();
+        let task = storage.create_task(Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+        storage.events().publish(TaskEventType::Created, &task, "u1", HashMap::new());
+        storage.events().publish(TaskEventType::Updated, &task, "u1", HashMap::new());
+        storage.force_save().await.unwrap();
+
+        let restarted = Storage::with_persistence(&path, false).unwrap();
+        restarted.load_from_disk().await.unwrap();
+        let epoch = SerdeTimestamp(prost_types::Timestamp::default());
+        let (missed, _) = restarted.events().subscribe_since(Some(&epoch), "");
+        let types: Vec<i32> = missed.iter().map(|event| event.event_type).collect();
+        assert_eq!(types, [TaskEventType::Created as i32, TaskEventType::Updated as i32]);
+        assert_eq!(missed[0].task.as_ref().unwrap().id, "t1");
+    }
+
+    #[tokio::test]
+    async fn overlapping_saves_keep_the_event_history_whole() {
+        let path = temp_storage_path();
+        let storage = Arc::new(Storage::with_persistence(&path, false).unwrap());
+        let task = storage.create_task(Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+        storage.events().publish(TaskEventType::Created, &task, "u1", HashMap::new());
+
+        let saves: Vec<_> = (0..16).map(|i| {
+            let storage = storage.clone();
+            tokio::spawn(async move {
+                if i % 2 == 0 { storage.save_to_disk().await } else { storage.save_event_history().await }
+            })
+        }).collect();
+        for save in saves {
+            save.await.unwrap().unwrap();
+        }
+        let history: Vec<TaskEvent> = serde_json::from_slice(&std::fs::read(event_history_path(path.to_str().unwrap())).unwrap()).unwrap();
+        assert_eq!(history.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn priority_sort_ranks_by_priority_then_score() {
+        let storage = Storage::new();
+        let tasks = [
+            ("high", TaskPriority::High, None),
+            ("low-99", TaskPriority::Low, Some(99.0)),
+            ("medium-60", TaskPriority::Medium, Some(60.0)),
+            ("medium-10", TaskPriority::Medium, Some(10.0)),
+            ("medium", TaskPriority::Medium, None),
+        ];
+        for (id, priority, priority_score) in tasks {
+            storage.create_task(Task { id: id.to_string(), priority: priority as i32, priority_score, ..Default::default() }).await.unwrap();
+        }
+
+        let sort = TaskSort { field: TaskSortField::Priority as i32, direction: SortDirection::Asc as i32 };
+        let sorted = storage.query_tasks(None, Some(&sort)).await;
+        let ids: Vec<&str> = sorted.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["low-99", "medium", "medium-10", "medium-60", "high"]);
+    }
+
+    #[tokio::test]
+    async fn caps_reject_creates_until_something_is_deleted() {
+        let mut storage = Storage::new();
+        storage.max_total_tasks = 2;
+        storage.max_total_users = 1;
+        let task = |id: &str| Task { id: id.to_string(), ..Default::default() };
+        storage.create_task(task("t1")).await.unwrap();
+        storage.create_task(task("t2")).await.unwrap();
+        storage.create_user(user("u1", "u1@example.com", "")).await.unwrap();
+
+        let err = storage.create_task(task("t3")).await.unwrap_err();
+        assert!(matches!(TaskerError::from(err), TaskerError::ResourceExhausted(_)));
+        let err = storage.create_user(user("u2", "u2@example.com", "")).await.unwrap_err();
+        assert!(matches!(TaskerError::from(err), TaskerError::ResourceExhausted(_)));
+        assert_eq!(storage.batch_create_tasks(vec![task("t3")]).await.unwrap(), [BatchOutcome::LimitReached]);
+
+        storage.delete_task("t1").await.unwrap();
+        storage.create_task(task("t3")).await.unwrap();
+        storage.delete_user("u1").await.unwrap();
+        storage.create_user(user("u2", "u2@example.com", "")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reindex_repairs_corrupted_indices() {
+        let storage = Storage::new();
+        storage.create_user(user("u1", "ann@example.com", "Ann")).await.unwrap();
+        let task = Task { id: "t1".to_string(), assigned_to: "u1".to_string(), tags: vec!["ops".to_string()], ..Default::default() };
+        storage.create_task(task).await.unwrap();
+        {
+            let mut data = storage.data.write().await;
+            data.users_by_email.clear();
+            data.user_tasks.insert("u1".to_string(), vec!["ghost".to_string()]);
+            data.tasks_by_tag.insert("stale".to_string(), HashSet::from(["t1".to_string()]));
+        }
+        assert!(storage.get_user_by_email("ann@example.com").await.is_none());
+
+        let report = storage.reindex().await.unwrap();
+        assert_eq!(report.repaired_entries, 3);
+        assert_eq!(report.tasks_indexed, 1);
+        assert_eq!(storage.get_user_by_email("ann@example.com").await.unwrap().id, "u1");
+        let assigned = storage.get_tasks_by_user("u1", 10, "").await;
+        assert_eq!(assigned.iter().map(|task| task.id.as_str()).collect::<Vec<_>>(), ["t1"]);
+        assert!(!storage.data.read().await.tasks_by_tag.contains_key("stale"));
+        assert_eq!(storage.reindex().await.unwrap().repaired_entries, 0);
+    }
+
+    #[tokio::test]
+    async fn sessions_are_stored_hashed_and_dropped_once_expired() {
+        let storage = Storage::new();
+        let live = Session { user_id: "u1".to_string(), expires_at: unix_now() + 3600, refresh: false };
+        storage.create_session("live-token", live).await.unwrap();
+        let expired = Session { user_id: "u1".to_string(), expires_at: unix_now() - 1, refresh: false };
+        storage.create_session("expired-token", expired).await.unwrap();
+
+        let encoded = String::from_utf8(storage.encode(&*storage.data.read().await, StorageFormat::Json).unwrap()).unwrap();
+        assert!(!encoded.contains("live-token"));
+        assert_eq!(storage.get_session("live-token").await.unwrap().user_id, "u1");
+
+        assert!(storage.get_session("expired-token").await.is_none());
+        assert_eq!(storage.data.read().await.sessions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn compaction_removes_attachments_of_purged_tasks() {
+        let dir = temp_storage_path().with_file_name("attachments");
+        let attachments = AttachmentStore::new(&dir, "/files").with_orphan_grace(Duration::ZERO);
+        let storage = Storage::new();
+        for id in ["kept", "purged"] {
+            let attachment = TaskAttachment { id: format!("a-{}", id), ..Default::default() };
+            storage.create_task(Task { id: id.to_string(), attachments: vec![attachment], ..Default::default() }).await.unwrap();
+            attachments.save(&format!("a-{}", id), b"bytes").await.unwrap();
+        }
+        attachments.save("a-never-attached", b"bytes").await.unwrap();
+        storage.soft_delete_task("purged").await.unwrap();
+
+        let report = storage.compact(0, &attachments).await.unwrap();
+        assert_eq!(report.purged_tombstones, 1);
+        assert_eq!(report.orphaned_attachments, 2);
+        assert_eq!(report.attachment_bytes_reclaimed, 10);
+        assert!(attachments.path("a-kept").exists());
+        assert!(!attachments.path("a-purged").exists());
+        assert!(!attachments.path("a-never-attached").exists());
+
+        // Young files may belong to an upload that hasn't reached its task yet
+        let attachments = attachments.with_orphan_grace(Duration::from_secs(3600));
+        attachments.save("a-uploading", b"bytes").await.unwrap();
+        assert_eq!(storage.compact(0, &attachments).await.unwrap().orphaned_attachments, 0);
+        assert!(attachments.path("a-uploading").exists());
+    }
+
+    #[tokio::test]
+    async fn merging_a_task_into_itself_is_refused() {
+        let storage = Storage::new();
+        storage.create_task(Task { id: "t1".to_string(), tags: vec!["bug".to_string()], ..Default::default() }).await.unwrap();
+
+        assert!(storage.merge_tasks("t1", "t1").await.is_err());
+        assert_eq!(storage.get_task("t1").await.unwrap().tags, ["bug"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn only_one_concurrent_sign_up_gets_an_email() {
+        let storage = Arc::new(Storage::new());
+        let sign_ups: Vec<_> = (0..8).map(|i| {
+            let storage = storage.clone();
+            let user = user(&format!("u{}", i), "ann@example.com", "Ann");
+            tokio::spawn(async move { storage.create_user(user).await })
+        }).collect();
+        let mut created = 0;
+        for sign_up in sign_ups {
+            match sign_up.await.unwrap() {
+                Ok(()) => created += 1,
+                Err(e) => assert!(matches!(TaskerError::from(e), TaskerError::AlreadyExists(_))),
+            }
+        }
+        assert_eq!(created, 1);
+
+        let owner = storage.get_user_by_email("ann@example.com").await.unwrap();
+        storage.create_user(user("bob", "bob@example.com", "Bob")).await.unwrap();
+        let taken = storage.update_user(user("bob", "ann@example.com", "Bob")).await;
+        assert!(taken.is_err());
+        assert_eq!(storage.get_user_by_email("ann@example.com").await.unwrap().id, owner.id);
+        assert_eq!(storage.get_user_by_email("bob@example.com").await.unwrap().id, "bob");
+    }
+
+    #[tokio::test]
+    async fn usernames_fold_case_by_default_and_match_exactly_when_configured() {
+        let storage = Storage::new();
+        storage.create_user(User { id: "u1".to_string(), username: "Alice".to_string(), ..Default::default() }).await.unwrap();
+        assert_eq!(storage.get_user_by_username("alice").await.unwrap().username, "Alice");
+        let taken = storage.create_user(User { id: "u2".to_string(), username: "ALICE".to_string(), ..Default::default() }).await;
+        assert!(matches!(taken.map_err(TaskerError::from), Err(TaskerError::AlreadyExists(_))));
+
+        let mut exact = Storage::new();
+        exact.fold_username_case = false;
+        exact.create_user(User { id: "u1".to_string(), username: "Alice".to_string(), ..Default::default() }).await.unwrap();
+        exact.create_user(User { id: "u2".to_string(), username: "alice".to_string(), ..Default::default() }).await.unwrap();
+        assert_eq!(exact.get_user_by_username("Alice").await.unwrap().id, "u1");
+        assert_eq!(exact.get_user_by_username("alice").await.unwrap().id, "u2");
+        assert!(exact.get_user_by_username("ALICE").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn renaming_a_colliding_user_keeps_the_other_users_key() {
+        let mut storage = Storage::new();
+        storage.fold_username_case = false;
+        storage.create_user(User { id: "u1".to_string(), username: "Alice".to_string(), ..Default::default() }).await.unwrap();
+        storage.create_user(User { id: "u2".to_string(), username: "alice".to_string(), ..Default::default() }).await.unwrap();
+        // Turning folding on for a store that already holds both
+        storage.fold_username_case = true;
+        storage.reindex().await.unwrap();
+        let owner = storage.get_user_by_username("alice").await.unwrap();
+        let other = if owner.id == "u1" { "u2" } else { "u1" };
+
+        let unchanged = storage.get_user(other).await.unwrap();
+        assert!(storage.update_user(unchanged.clone()).await.is_err());
+        storage.update_user(User { username: "alice2".to_string(), ..unchanged }).await.unwrap();
+        assert_eq!(storage.get_user_by_username("ALICE").await.unwrap().id, owner.id);
+        assert_eq!(storage.get_user_by_username("Alice2").await.unwrap().id, other);
+    }
+
+    fn tagged(id: &str, tags: &[&str]) -> Task {
+        Task { id: id.to_string(), tags: tags.iter().map(|tag| tag.to_string()).collect(), ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn renaming_a_tag_merges_duplicates_and_keeps_the_index_consistent() {
+        let storage = Storage::new();
+        storage.create_task(tagged("t1", &["backend", "ui"])).await.unwrap();
+        storage.create_task(tagged("t2", &["backend", "api"])).await.unwrap();
+        storage.create_task(tagged("t3", &["ui"])).await.unwrap();
+
+        assert_eq!(storage.rename_tag("backend", "api").await.unwrap(), 2);
+        assert_eq!(storage.get_task("t1").await.unwrap().tags, ["api", "ui"]);
+        assert_eq!(storage.get_task("t2").await.unwrap().tags, ["api"]);
+        assert_eq!(storage.list_tags("").await, [("api".to_string(), 2), ("ui".to_string(), 2)]);
+        assert_eq!(storage.verify_and_repair().await.unwrap(), 0);
+        assert_eq!(storage.rename_tag("backend", "api").await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn tag_counts_match_a_scan_of_the_tasks() {
+        let storage = Storage::new();
+        let tag_sets: [&[&str]; 5] = [&["api", "bug"], &["bug"], &[], &["api", "backend"], &["bug", "backend", "ui"]];
+        for (i, tags) in tag_sets.iter().enumerate() {
+            storage.create_task(tagged(&format!("t{}", i), tags)).await.unwrap();
+        }
+        storage.delete_task("t1").await.unwrap();
+
+        let mut scanned: HashMap<String, usize> = HashMap::new();
+        for task in storage.query_tasks(None, None).await {
+            for tag in task.tags {
+                *scanned.entry(tag).or_default() += 1;
+            }
+        }
+        let listed = storage.list_tags("").await;
+        assert_eq!(listed.iter().cloned().collect::<HashMap<_, _>>(), scanned);
+        assert_eq!(listed[0], ("api".to_string(), 2));
+        assert_eq!(storage.list_tags("b").await, [("backend".to_string(), 2), ("bug".to_string(), 2)]);
+    }
+
+    #[tokio::test]
+    async fn a_wrong_email_index_entry_heals_on_verify() {
+        let storage = Storage::new();
+        storage.create_user(user("u1", "ann@example.com", "Ann")).await.unwrap();
+        storage.create_user(user("u2", "bob@example.com", "Bob")).await.unwrap();
+        {
+            let mut data = storage.data.write().await;
+            data.users_by_email.insert("ann@example.com".to_string(), "u2".to_string());
+            data.users_by_email.insert("gone@example.com".to_string(), "u3".to_string());
+        }
+        assert_eq!(storage.get_user_by_email("ann@example.com").await.unwrap().id, "u2");
+
+        assert_eq!(storage.verify_and_repair().await.unwrap(), 2);
+        assert_eq!(storage.get_user_by_email("ann@example.com").await.unwrap().id, "u1");
+        assert!(storage.get_user_by_email("gone@example.com").await.is_none());
+        assert_eq!(storage.verify_and_repair().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn indices_are_left_out_of_the_file_and_rebuilt_on_load() {
+        let path = temp_storage_path();
+        let storage = Storage::with_persistence(&path, false).unwrap();
+        storage.create_user(user("u1", "ann@example.com", "Ann")).await.unwrap();
+        let task = Task { id: "t1".to_string(), assigned_to: "u1".to_string(), tags: vec!["ops".to_string()], ..Default::default() };
+        storage.create_task(task).await.unwrap();
+        storage.force_save().await.unwrap();
+
+        let saved: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        let keys: Vec<&str> = saved.as_object().unwrap().keys().map(String::as_str).collect();
+        for index in ["users_by_email", "users_by_username", "user_tasks", "tasks_by_tag", "project_tasks"] {
+            assert!(!keys.contains(&index), "{} was saved", index);
+        }
+
+        let reloaded = Storage::with_persistence(&path, false).unwrap();
+        reloaded.load_from_disk().await.unwrap();
+        assert_eq!(reloaded.get_user_by_email("ann@example.com").await.unwrap().id, "u1");
+        assert_eq!(reloaded.get_tasks_by_user("u1", 10, "").await.len(), 1);
+        assert_eq!(reloaded.list_tags("").await, [("ops".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn data_round_trips_through_each_format() {
+        for format in [StorageFormat::Json, StorageFormat::MessagePack, StorageFormat::Bincode] {
+            let path = temp_storage_path();
+            let storage = Storage::with_persistence(&path, false).unwrap().with_format(format);
+            storage.create_user(user("u1", "ann@example.com", "Ann")).await.unwrap();
+            let task = Task {
+                id: "t1".to_string(),
+                title: "Round trip".to_string(),
+                assigned_to: "u1".to_string(),
+                tags: vec!["ops".to_string()],
+                due_date: Some(SerdeTimestamp(prost_types::Timestamp { seconds: 1_700_000_000, nanos: 5 })),
+                custom_fields: HashMap::from([("team".to_string(), "core".to_string())]),
+                ..Default::default()
+            };
+            let created = storage.create_task(task).await.unwrap();
+            storage.force_save().await.unwrap();
+
+            let reloaded = Storage::with_persistence(&path, false).unwrap().with_format(format);
+            reloaded.load_from_disk().await.unwrap();
+            assert_eq!(reloaded.get_task("t1").await.unwrap(), created, "{:?}", format);
+            assert_eq!(reloaded.get_user_by_email("ann@example.com").await.unwrap().full_name, "Ann", "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn auto_save_without_a_path_is_refused() {
+        let config = Config { storage_path: None, storage_auto_save: true, ..Default::default() };
+        let err = Storage::from_config(&config).unwrap_err().to_string();
+        assert!(err.contains("TASKER_STORAGE_PATH"), "{}", err);
+
+        let config = Config { storage_path: None, storage_auto_save: false, ..Default::default() };
+        assert!(Storage::from_config(&config).unwrap().persistence_path.is_none());
+    }
+
+    #[tokio::test]
+    async fn a_replica_reloads_when_another_process_saves() {
+        let path = temp_storage_path();
+        let writer = Storage::with_persistence(&path, false).unwrap();
+        writer.force_save().await.unwrap();
+        let replica = Storage::with_persistence(&path, false).unwrap();
+        replica.load_from_disk().await.unwrap();
+        replica.watch_for_changes().unwrap();
+
+        writer.create_task(Task { id: "t1".to_string(), ..Default::default() }).await.unwrap();
+        writer.force_save().await.unwrap();
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while replica.get_task("t1").await.is_none() {
+            assert!(tokio::time::Instant::now() < deadline, "replica never picked up the change");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn tasks_sort_by_comment_and_attachment_counts() {
+        let storage = Storage::new();
+        for (id, comments, attachments) in [("a", 1, 0), ("b", 3, 0), ("c", 0, 2), ("d", 1, 1)] {
+            storage.create_task(Task {
+                id: id.to_string(),
+                comments: vec![TaskComment::default(); comments],
+                attachments: vec![TaskAttachment::default(); attachments],
+                ..Default::default()
+            }).await.unwrap();
+        }
+        let sorted = |field: TaskSortField, direction: SortDirection| {
+            let storage = &storage;
+            async move {
+                let sort = TaskSort { field: field as i32, direction: direction as i32 };
+                storage.query_tasks(None, Some(&sort)).await.into_iter().map(|task| task.id).collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(sorted(TaskSortField::CommentCount, SortDirection::Desc).await, ["b", "d", "a", "c"]);
+        assert_eq!(sorted(TaskSortField::AttachmentCount, SortDirection::Asc).await, ["a", "b", "d", "c"]);
+    }
+
+
+    #[tokio::test]
+    async fn only_tasks_done_for_longer_than_the_retention_are_archived() {
+        let storage = Storage::new();
+        let hours_ago = |hours: i64| Some(SerdeTimestamp(prost_types::Timestamp { seconds: unix_now() - hours * 3600, nanos: 0 }));
+        for (id, status, updated_at) in [
+            ("old-done", TaskStatus::Done, hours_ago(48)),
+            ("new-done", TaskStatus::Done, hours_ago(1)),
+            ("old-todo", TaskStatus::Todo, hours_ago(48)),
+        ] {
+            let task = Task { id: id.to_string(), status: status as i32, updated_at, ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+
+        let archived = storage.archive_done_tasks(24 * 3600).await.unwrap();
+        assert_eq!(archived.iter().map(|task| task.id.as_str()).collect::<Vec<_>>(), ["old-done"]);
+        assert!(storage.get_task("old-done").await.is_none());
+        assert!(storage.get_task("new-done").await.is_some());
+        assert!(storage.get_task("old-todo").await.is_some());
+        assert_eq!(storage.list_archived_tasks().await.len(), 1);
+
+        // A second run finds nothing more to do
+        assert!(storage.archive_done_tasks(24 * 3600).await.unwrap().is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn overdue_tasks_escalate_once_up_to_the_cap() {
+        let storage = Storage::new();
+        let yesterday = Some(SerdeTimestamp(prost_types::Timestamp { seconds: unix_now() - 86_400, nanos: 0 }));
+        let tomorrow = Some(SerdeTimestamp(prost_types::Timestamp { seconds: unix_now() + 86_400, nanos: 0 }));
+        for (id, status, due_date) in [
+            ("overdue", TaskStatus::Todo, yesterday.clone()),
+            ("done", TaskStatus::Done, yesterday),
+            ("upcoming", TaskStatus::Todo, tomorrow),
+        ] {
+            let task = Task { id: id.to_string(), status: status as i32, priority: TaskPriority::Medium as i32, due_date, ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+
+        let escalated = storage.escalate_overdue_tasks(1, TaskPriority::High).await.unwrap();
+        assert_eq!(escalated.len(), 1);
+        assert_eq!((escalated[0].0.id.as_str(), escalated[0].1), ("overdue", TaskPriority::Medium));
+        assert_eq!(storage.get_task("overdue").await.unwrap().priority(), TaskPriority::High);
+        assert_eq!(storage.get_task("done").await.unwrap().priority(), TaskPriority::Medium);
+        assert_eq!(storage.get_task("upcoming").await.unwrap().priority(), TaskPriority::Medium);
+
+        // At the cap already, so the next run leaves it be
+        assert!(storage.escalate_overdue_tasks(1, TaskPriority::High).await.unwrap().is_empty());
+        assert_eq!(storage.get_task("overdue").await.unwrap().priority(), TaskPriority::High);
+    }
+
+
+    #[tokio::test]
+    async fn batch_creates_report_repeated_ids_instead_of_overwriting() {
+        let storage = Storage::new();
+        storage.create_task(Task { id: "stored".to_string(), title: "Original".to_string(), ..Default::default() }).await.unwrap();
+
+        let batch = [("fresh", "First"), ("fresh", "Second"), ("stored", "Clobber")]
+            .map(|(id, title)| Task { id: id.to_string(), title: title.to_string(), ..Default::default() });
+        let outcomes = storage.batch_create_tasks(batch.to_vec()).await.unwrap();
+        assert_eq!(outcomes, [BatchOutcome::Created, BatchOutcome::Duplicate, BatchOutcome::Taken]);
+        assert_eq!(storage.get_task("fresh").await.unwrap().title, "First");
+        assert_eq!(storage.get_task("stored").await.unwrap().title, "Original");
+
+        let users = vec![
+            user("u1", "ann@example.com", "Ann"),
+            user("u2", "Ann@Example.com", "Ann Again"),
+            user("u3", "cat@example.com", "Cat"),
+        ];
+        let outcomes = storage.batch_create_users(users).await.unwrap();
+        assert_eq!(outcomes, [BatchOutcome::Created, BatchOutcome::Duplicate, BatchOutcome::Created]);
+        assert!(storage.get_user("u2").await.is_none());
+    }
+
+
+    #[tokio::test]
+    async fn workload_counts_each_assignees_tasks_and_hours() {
+        let storage = Storage::new();
+        storage.create_user(user("ann", "ann@example.com", "Ann")).await.unwrap();
+        for (id, assignee, status, estimated) in [
+            ("t1", "ann", TaskStatus::Todo, 3),
+            ("t2", "ann", TaskStatus::Todo, 2),
+            ("t3", "ann", TaskStatus::Done, 5),
+            ("t4", "bob", TaskStatus::InProgress, 1),
+            ("t5", "", TaskStatus::Todo, 8),
+        ] {
+            let metrics = Some(TaskMetrics { estimated_hours: estimated, actual_hours: 1, ..Default::default() });
+            let task = Task { id: id.to_string(), assigned_to: assignee.to_string(), status: status as i32, metrics, ..Default::default() };
+            storage.create_task(task).await.unwrap();
+        }
+
+        let workloads = storage.workload(&[]).await;
+        let summary: Vec<_> = workloads.iter().map(|w| (w.user_id.as_str(), w.username.as_str(), w.total_tasks)).collect();
+        assert_eq!(summary, [("ann", "ann", 3), ("bob", "", 1)]);
+        let ann = &workloads[0];
+        assert_eq!(ann.tasks_by_status[&(TaskStatus::Todo as i32)], 2);
+        assert_eq!(ann.tasks_by_status[&(TaskStatus::Done as i32)], 1);
+        assert_eq!((ann.estimated_hours, ann.actual_hours), (10, 3));
+
+        // Asking for specific users includes idle ones and nobody else
+        let picked = storage.workload(&["bob".to_string(), "cat".to_string(), "bob".to_string()]).await;
+        let summary: Vec<_> = picked.iter().map(|w| (w.user_id.as_str(), w.total_tasks)).collect();
+        assert_eq!(summary, [("bob", 1), ("cat", 0)]);
+    }
+
+
+    #[tokio::test]
+    async fn uploads_past_the_cap_are_refused_until_a_slot_frees() {
+        let config = Config { max_concurrent_uploads: 2, storage_auto_save: false, ..Default::default() };
+        let storage = Storage::from_config(&config).unwrap();
+        let first = storage.upload_slot().await.unwrap();
+        let _second = storage.upload_slot().await.unwrap();
+        assert!(storage.upload_slot().await.is_none());
+        drop(first);
+        let _third = storage.upload_slot().await.unwrap();
+
+        // With a wait configured, a claim queues for the next free slot
+        let config = Config { max_concurrent_uploads: 1, upload_wait_secs: 5, storage_auto_save: false, ..Default::default() };
+        let storage = Storage::from_config(&config).unwrap();
+        let busy = storage.upload_slot().await.unwrap();
+        let waiting = tokio::spawn({
+            let storage = storage.clone();
+            async move { storage.upload_slot().await.is_some() }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(busy);
+        assert!(waiting.await.unwrap());
+    }
+
+
+    #[tokio::test]
+    async fn three_tasks_created_on_one_day_fill_one_bucket() {
+        let storage = Storage::new();
+        // 2024-03-05 00:00:00 UTC
+        let day = 1_709_596_800;
+        let at = |seconds: i64| Some(SerdeTimestamp(prost_types::Timestamp { seconds, nanos: 0 }));
+        for (id, created, status) in [
+            ("t1", day + 60, TaskStatus::Todo),
+            ("t2", day + 12 * 3600, TaskStatus::Todo),
+            ("t3", day + 86_399, TaskStatus::Done),
+            ("t4", day + 86_400, TaskStatus::Todo),
+        ] {
+            storage.create_task(Task {
+                id: id.to_string(),
+                status: status as i32,
+                created_at: at(created),
+                updated_at: at(day + 2 * 86_400),
+                ..Default::default()
+            }).await.unwrap();
+        }
+
+        let date = |d: u32| chrono::NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        let counts = |points: Vec<TimeseriesPoint>| -> Vec<(String, u64)> {
+            points.into_iter().map(|point| (point.date, point.count)).collect()
+        };
+        let daily = storage.task_timeseries(TimeseriesMetric::Created, date(4), date(7), 1).await;
+        assert_eq!(counts(daily), vec![
+            ("2024-03-04".to_string(), 0),
+            ("2024-03-05".to_string(), 3),
+            ("2024-03-06".to_string(), 1),
+            ("2024-03-07".to_string(), 0),
+        ]);
+
+        let completed = storage.task_timeseries(TimeseriesMetric::Completed, date(4), date(7), 1).await;
+        assert_eq!(completed.iter().map(|point| point.count).collect::<Vec<_>>(), vec![0, 0, 0, 1]);
+
+        let weekly = storage.task_timeseries(TimeseriesMetric::Created, date(4), date(17), 7).await;
+        assert_eq!(counts(weekly), vec![("2024-03-04".to_string(), 4), ("2024-03-11".to_string(), 0)]);
+    }
+
+
+    #[tokio::test]
+    async fn task_numbers_count_up_and_survive_a_reload() {
+        let path = temp_storage_path();
+        let storage = Storage::with_persistence(&path, false).unwrap();
+        let mut numbers = Vec::new();
+        for id in ["t1", "t2", "t3"] {
+            numbers.push(storage.create_task(Task { id: id.to_string(), ..Default::default() }).await.unwrap().number);
+        }
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert!(storage.delete_task("t3").await.unwrap());
+        storage.force_save().await.unwrap();
+
+        let reloaded = Storage::with_persistence(&path, false).unwrap();
+        reloaded.load_from_disk().await.unwrap();
+        assert_eq!(reloaded.get_task_by_number(2).await.unwrap().id, "t2");
+        let next = reloaded.create_task(Task { id: "t4".to_string(), ..Default::default() }).await.unwrap();
+        assert_eq!(next.number, 4);
+    }
+}
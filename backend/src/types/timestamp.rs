@@ -2,8 +2,10 @@
 use prost::{Message, DecodeError};
 use prost_types::Timestamp;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::str::FromStr;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
 
 mod timestamp_serde {
     use super::*;
@@ -30,7 +32,8 @@ mod timestamp_serde {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(value_type = String, format = DateTime)]
 pub struct SerdeTimestamp(
     #[serde(with = "timestamp_serde")]
     pub Timestamp,
@@ -69,12 +72,6 @@ impl Message for SerdeTimestamp {
     }
 }
 
-impl Default for SerdeTimestamp {
-    fn default() -> Self {
-        SerdeTimestamp(Timestamp::default())
-    }
-}
-
 impl From<Timestamp> for SerdeTimestamp {
     fn from(ts: Timestamp) -> Self {
         SerdeTimestamp(ts)
@@ -129,4 +126,69 @@ impl std::ops::DerefMut for SerdeTimestamp {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
-}
\ No newline at end of file
+}
+
+/// How timestamps are rendered in HTTP JSON responses. Serialization always
+/// produces UTC RFC 3339; other formats are applied to the finished JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampFormat {
+    #[default]
+    Rfc3339,
+    /// RFC 3339 at a fixed UTC offset, e.g. `+02:00`
+    Offset(FixedOffset),
+    /// Milliseconds since the Unix epoch, as a JSON number
+    EpochMillis,
+}
+
+impl FromStr for TimestampFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "rfc3339" | "utc" => Ok(Self::Rfc3339),
+            "epoch_millis" | "millis" => Ok(Self::EpochMillis),
+            other => other.parse()
+                .map(Self::Offset)
+                .map_err(|_| anyhow::anyhow!("unknown timestamp format: {}", other)),
+        }
+    }
+}
+
+impl TimestampFormat {
+    /// Re-render the timestamp fields of a serialized response in this format.
+    pub fn apply(self, value: &mut Value) {
+        if self == Self::Rfc3339 {
+            return;
+        }
+        match value {
+            Value::Object(fields) => {
+                for (key, field) in fields.iter_mut() {
+                    match field {
+                        Value::String(text) if is_timestamp_field(key) => {
+                            if let Ok(at) = DateTime::parse_from_rfc3339(text) {
+                                *field = self.render(at);
+                            }
+                        }
+                        _ => self.apply(field),
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.apply(item)),
+            _ => {}
+        }
+    }
+
+    fn render(self, at: DateTime<FixedOffset>) -> Value {
+        match self {
+            Self::Rfc3339 => Value::String(at.with_timezone(&Utc).to_rfc3339()),
+            Self::Offset(offset) => Value::String(at.with_timezone(&offset).to_rfc3339_opts(SecondsFormat::AutoSi, false)),
+            Self::EpochMillis => Value::from(at.timestamp_millis()),
+        }
+    }
+}
+
+// Only fields that hold timestamps are touched, so free text that happens to
+// look like a date (a title, say) is left alone
+fn is_timestamp_field(key: &str) -> bool {
+    key.ends_with("_at") || matches!(key, "due_date" | "last_login" | "timestamp")
+}
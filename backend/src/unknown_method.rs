@@ -0,0 +1,140 @@
+// src/unknown_method.rs
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::{Code, Status};
+use tower::Layer;
+
+use crate::protogen::DESCRIPTOR_SET;
+
+/// Endpoint name of an RPC, as used in `TASKER_DISABLED_ENDPOINTS`: the
+/// method name in snake_case, e.g. `/tasker.UserService/CreateUser` is `create_user`
+pub fn endpoint_name(rpc_path: &str) -> String {
+    let method = rpc_path.rsplit('/').next().unwrap_or_default();
+    let mut name = String::with_capacity(method.len() + 4);
+    for (i, c) in method.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            name.push('_');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}
+
+/// Endpoint names of every RPC the tasker services define
+pub fn known_endpoints() -> Vec<String> {
+    let Ok(descriptors) = prost_types::FileDescriptorSet::decode(DESCRIPTOR_SET) else {
+        return Vec::new();
+    };
+    descriptors.file.iter()
+        .flat_map(|file| &file.service)
+        .flat_map(|service| &service.method)
+        .filter_map(|method| method.name.as_deref())
+        .map(endpoint_name)
+        .collect()
+}
+
+/// Names the method in the otherwise empty `UNIMPLEMENTED` status tonic
+/// returns for calls to services or methods the server doesn't have, and
+/// answers `UNIMPLEMENTED` itself for endpoints disabled in config.
+#[derive(Clone, Default)]
+pub struct UnknownMethodLayer {
+    disabled: Arc<HashSet<String>>,
+}
+
+impl UnknownMethodLayer {
+    pub fn new(disabled: &[String]) -> Self {
+        Self { disabled: Arc::new(disabled.iter().cloned().collect()) }
+    }
+}
+
+impl<S> Layer<S> for UnknownMethodLayer {
+    type Service = UnknownMethodService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        UnknownMethodService { inner, disabled: self.disabled.clone() }
+    }
+}
+
+#[derive(Clone)]
+pub struct UnknownMethodService<S> {
+    inner: S,
+    disabled: Arc<HashSet<String>>,
+}
+
+impl<S, B> Service<http::Request<B>> for UnknownMethodService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let path = request.uri().path().to_string();
+        if !self.disabled.is_empty() && self.disabled.contains(&endpoint_name(&path)) {
+            let response = Status::unimplemented(format!("Method {} is disabled on this server", path)).to_http();
+            return Box::pin(async move { Ok(response) });
+        }
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            let response = response.await?;
+            let unimplemented = response.headers()
+                .get("grpc-status")
+                .is_some_and(|status| status.as_bytes() == (Code::Unimplemented as i32).to_string().as_bytes());
+            if unimplemented && !response.headers().contains_key("grpc-message") {
+                return Ok(Status::unimplemented(format!("Unknown method {}", path)).to_http());
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    /// What tonic's router answers for a path it has no service for
+    fn bare_unimplemented(_: http::Request<()>) -> std::future::Ready<Result<http::Response<BoxBody>, Status>> {
+        let response = http::Response::builder()
+            .header("grpc-status", (Code::Unimplemented as i32).to_string())
+            .body(tonic::body::empty_body())
+            .unwrap();
+        std::future::ready(Ok(response))
+    }
+
+    async fn call(layer: UnknownMethodLayer, path: &str) -> http::Response<BoxBody> {
+        let service = layer.layer(tower::service_fn(bare_unimplemented));
+        let request = http::Request::builder().uri(path).body(()).unwrap();
+        service.oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn unknown_and_disabled_methods_are_named_in_the_status() {
+        let response = call(UnknownMethodLayer::default(), "/tasker.TaskService/Teleport").await;
+        let status = Status::from_header_map(response.headers()).unwrap();
+        assert_eq!(status.code(), Code::Unimplemented);
+        assert_eq!(status.message(), "Unknown method /tasker.TaskService/Teleport");
+
+        let layer = UnknownMethodLayer::new(&["create_user".to_string()]);
+        let response = call(layer, "/tasker.UserService/CreateUser").await;
+        let status = Status::from_header_map(response.headers()).unwrap();
+        assert_eq!(status.message(), "Method /tasker.UserService/CreateUser is disabled on this server");
+    }
+
+    #[test]
+    fn endpoint_names_are_snake_case_method_names() {
+        assert_eq!(endpoint_name("/tasker.UserService/CreateUser"), "create_user");
+        assert!(known_endpoints().contains(&"stream_task_events".to_string()));
+    }
+}
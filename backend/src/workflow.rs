@@ -0,0 +1,36 @@
+// src/workflow.rs
+use crate::protogen::TaskStatus;
+
+/// Whether a task may move from `from` to `to`. Staying put is always
+/// allowed; finished and cancelled tasks can only be reopened.
+pub fn can_transition(from: TaskStatus, to: TaskStatus) -> bool {
+    use TaskStatus::*;
+
+    if from == to {
+        return to != Unspecified;
+    }
+    match from {
+        // Tasks created before statuses were enforced may carry no status at all
+        Unspecified => to != Unspecified,
+        Backlog => matches!(to, Todo | InProgress | Cancelled),
+        Todo => matches!(to, Backlog | InProgress | Done | Cancelled),
+        InProgress => matches!(to, Todo | Review | Done | Cancelled),
+        Review => matches!(to, InProgress | Done | Cancelled),
+        Done => matches!(to, Todo | InProgress),
+        Cancelled => matches!(to, Backlog | Todo),
+    }
+}
+
+/// The completion percentage a status implies, if it implies one.
+pub fn completion_for(status: TaskStatus) -> Option<f64> {
+    match status {
+        TaskStatus::Backlog | TaskStatus::Todo => Some(0.0),
+        TaskStatus::Done => Some(100.0),
+        _ => None,
+    }
+}
+
+/// Statuses a new task may start in: anything not yet finished.
+pub fn is_initial(status: TaskStatus) -> bool {
+    matches!(status, TaskStatus::Backlog | TaskStatus::Todo | TaskStatus::InProgress)
+}